@@ -71,6 +71,17 @@ fn bench_msm(c: &mut Criterion) {
             bandersnatch::multi_scalar_mul(&base_point, &r1, &psi_point, &r2)
         })
     });
+
+    let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+    let bench_str = "double-base-mul";
+    bench_group.bench_function(bench_str, move |b| {
+        let r1 = bandersnatch::Fr::rand(&mut rng);
+        let r2 = bandersnatch::Fr::rand(&mut rng);
+
+        b.iter(|| {
+            bandersnatch::double_base_mul(&r1, &base_point, &r2, &psi_point)
+        })
+    });
     bench_group.finish();
 }
 
@@ -101,6 +112,46 @@ fn bench_bandersnatch(c: &mut Criterion) {
             base_point.mul_assign(r);
         })
     });
+
+    let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+    let p = bandersnatch::EdwardsProjective::rand(&mut rng);
+    let q = bandersnatch::EdwardsProjective::rand(&mut rng);
+    let bench_str = "generic addition";
+    bench_group.bench_function(bench_str, move |b| b.iter(|| p + q));
+
+    let cached = bandersnatch::CachedPoint::from(q);
+    let bench_str = "cached addition";
+    bench_group.bench_function(bench_str, move |b| {
+        b.iter(|| {
+            let mut sum = p;
+            sum += &cached;
+            sum
+        })
+    });
+
+    let bench_str = "4 generic doublings";
+    bench_group.bench_function(bench_str, move |b| {
+        b.iter(|| {
+            let mut res = p;
+            for _ in 0..4 {
+                res.double_in_place();
+            }
+            res
+        })
+    });
+
+    let bench_str = "4 chained doublings";
+    bench_group.bench_function(bench_str, move |b| {
+        b.iter(|| bandersnatch::double_n(&p, 4))
+    });
+
+    let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+    let _ = bandersnatch::generator_table();
+    let bench_str = "comb mul by generator";
+    bench_group.bench_function(bench_str, move |b| {
+        let r = bandersnatch::Fr::rand(&mut rng);
+        b.iter(|| bandersnatch::mul_by_generator(&r))
+    });
     bench_group.finish();
 }
 
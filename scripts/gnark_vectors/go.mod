@@ -0,0 +1,5 @@
+module github.com/kevaundray/bandersnatch/scripts/gnark_vectors
+
+go 1.19
+
+require github.com/consensys/gnark-crypto v0.12.1
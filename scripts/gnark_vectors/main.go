@@ -0,0 +1,39 @@
+// Command gnark_vectors prints the gnark-crypto encodings of the test
+// vectors of `test_gnark_bytes` in `src/curves/tests.rs`: for each scalar
+// k, the `PointAffine.Bytes` of k times the base point of the bandersnatch
+// package, in hexadecimal.
+//
+// Usage: cd scripts/gnark_vectors && go mod tidy && go run .
+//
+// go.sum is not checked in yet: it is written by `go mod tidy` on the
+// first run, and should be committed together with the output of that
+// run, which replaces the vectors of the test.
+package main
+
+import (
+	"encoding/hex"
+	"fmt"
+	"math/big"
+
+	"github.com/consensys/gnark-crypto/ecc/bls12-381/bandersnatch"
+)
+
+func main() {
+	curve := bandersnatch.GetEdwardsCurve()
+	var minusOne big.Int
+	minusOne.Sub(&curve.Order, big.NewInt(1))
+	scalars := []*big.Int{
+		big.NewInt(1),
+		big.NewInt(2),
+		big.NewInt(3),
+		big.NewInt(5),
+		big.NewInt(1 << 40),
+		&minusOne,
+	}
+	for _, k := range scalars {
+		var p bandersnatch.PointAffine
+		p.ScalarMultiplication(&curve.Base, k)
+		bytes := p.Bytes()
+		fmt.Printf("%s %s\n", k.String(), hex.EncodeToString(bytes[:]))
+	}
+}
@@ -0,0 +1,307 @@
+#!/usr/bin/env python3
+"""Reference implementation of the bandersnatch hash-to-curve suites, which
+generates the test vectors of `src/hash_to_curve/tests.rs`.
+
+It only depends on the Python 3 standard library, and follows the generic
+descriptions of RFC 9380 rather than the straight-line, constant-time ones
+of the crate:
+
+- `expand_message_xmd` and `hash_to_field`: sections 5.2 and 5.3.1, with
+  L = 48 bytes per element;
+- Elligator 2: section 6.7.1, to the Montgomery model `K t^2 = s^3 + J s^2 +
+  s` with `J = 2 (a + d) / (a - d)` and `K = 4 / (a - d)`, followed by the
+  rational map of appendix D.1, with Z = 5 (appendix H.3);
+- simplified SWU: section 6.6.2, on the short Weierstrass curve
+  2-isogenous to bandersnatch, with Z = -7 (appendix H.2), followed by the
+  dual of the 2-isogeny whose kernel is `(0, -1)`;
+- `hash_to_curve` (RO) and `encode_to_curve` (NU): section 3, with the
+  cofactor cleared by multiplying by h = 4;
+- `derive_generator`, `hash_to_curve` with Elligator 2 of
+  `len(domain) || domain || index`;
+- the legacy try-and-increment map of `hash_to_curve_try_and_increment`.
+
+Points are printed as the compressed encoding of the crate: `y` in
+little-endian, with the parity of `x` in the most significant bit.
+
+Usage: python3 scripts/hash_to_curve_vectors.py
+"""
+
+import hashlib
+
+q = 0x73EDA753299D7D483339D80809A1D80553BDA402FFFE5BFEFFFFFFFF00000001
+a = q - 5
+d = (
+    138827208126141220649022263972958607803
+    * pow(171449701953573178309673572579671231137, -1, q)
+) % q
+
+MSGS = [b"", b"abc", b"abcdef0123456789", b"q128_" + b"q" * 128,
+        b"a512_" + b"a" * 512]
+
+
+def inv(x):
+    return pow(x, q - 2, q)
+
+
+def is_square(x):
+    return x % q == 0 or pow(x, (q - 1) // 2, q) == 1
+
+
+def sqrt(x):
+    """Tonelli-Shanks; returns None for non-squares."""
+    x %= q
+    if x == 0:
+        return 0
+    if not is_square(x):
+        return None
+    s, t = 0, q - 1
+    while t % 2 == 0:
+        s, t = s + 1, t // 2
+    z = 2
+    while is_square(z):
+        z += 1
+    m, c, r, u = s, pow(z, t, q), pow(x, (t + 1) // 2, q), pow(x, t, q)
+    while u != 1:
+        i, v = 0, u
+        while v != 1:
+            v, i = v * v % q, i + 1
+        b = pow(c, 1 << (m - i - 1), q)
+        m, c, r, u = i, b * b % q, r * b % q, u * b * b % q
+    return r
+
+
+def sgn0(x):
+    return x % q % 2
+
+
+# expand_message_xmd and hash_to_field, RFC 9380, section 5
+
+
+def expand_message_xmd(hash_fn, msg, dst, length):
+    h = hash_fn()
+    b_in_bytes, r_in_bytes = h.digest_size, h.block_size
+    ell = -(-length // b_in_bytes)
+    assert ell <= 255 and len(dst) <= 255
+    dst_prime = dst + bytes([len(dst)])
+    msg_prime = (bytes(r_in_bytes) + msg + length.to_bytes(2, "big")
+                 + b"\x00" + dst_prime)
+    b0 = hash_fn(msg_prime).digest()
+    bs = [hash_fn(b0 + b"\x01" + dst_prime).digest()]
+    for i in range(2, ell + 1):
+        prev = bytes(x ^ y for x, y in zip(b0, bs[-1]))
+        bs.append(hash_fn(prev + bytes([i]) + dst_prime).digest())
+    return b"".join(bs)[:length]
+
+
+def hash_to_field(msg, dst, count):
+    length = 48
+    uniform = expand_message_xmd(hashlib.sha256, msg, dst, count * length)
+    return [int.from_bytes(uniform[i * length:(i + 1) * length], "big") % q
+            for i in range(count)]
+
+
+# twisted Edwards arithmetic, a x^2 + y^2 = 1 + d x^2 y^2
+
+IDENTITY = (0, 1)
+
+
+def on_curve(p):
+    x, y = p
+    return (a * x * x + y * y - 1 - d * x * x * y * y) % q == 0
+
+
+def add(p1, p2):
+    (x1, y1), (x2, y2) = p1, p2
+    t = d * x1 * x2 * y1 * y2 % q
+    x3 = (x1 * y2 + y1 * x2) * inv(1 + t) % q
+    y3 = (y1 * y2 - a * x1 * x2) * inv(1 - t) % q
+    return (x3, y3)
+
+
+def clear_cofactor(p):
+    p = add(p, p)
+    return add(p, p)
+
+
+def compress(p):
+    x, y = p
+    encoding = bytearray(y.to_bytes(32, "little"))
+    encoding[31] |= (x % 2) << 7
+    return bytes(encoding).hex()
+
+
+def decompress(encoding):
+    odd = encoding[31] >> 7
+    y = int.from_bytes(encoding[:31] + bytes([encoding[31] & 0x7F]), "little")
+    if y >= q:
+        return None
+    # x^2 = (1 - y^2) / (a - d y^2)
+    den = (a - d * y * y) % q
+    if den == 0:
+        return None
+    x = sqrt((1 - y * y) * inv(den))
+    if x is None or (x == 0 and odd):
+        return None
+    if x % 2 != odd:
+        x = q - x
+    return (x, y)
+
+
+# Elligator 2, RFC 9380, section 6.7.1 and appendix D.1
+
+J = 2 * (a + d) * inv(a - d) % q
+K = 4 * inv(a - d) % q
+ELL2_Z = 5
+
+
+def montgomery_to_edwards(s, t):
+    # (v, w) = (s / t, (s - 1) / (s + 1)), exceptional cases to the identity
+    if t == 0 or (s + 1) % q == 0:
+        return IDENTITY
+    return (s * inv(t) % q, (s - 1) * inv(s + 1) % q)
+
+
+def map_to_curve_elligator2(u):
+    big_a = J * inv(K) % q
+    big_b = inv(K * K)
+    x1 = -big_a * inv(1 + ELL2_Z * u * u) % q
+    if x1 == 0:
+        x1 = -big_a % q
+    gx1 = (x1 ** 3 + big_a * x1 * x1 + big_b * x1) % q
+    x2 = (-x1 - big_a) % q
+    gx2 = (x2 ** 3 + big_a * x2 * x2 + big_b * x2) % q
+    if is_square(gx1):
+        x, y = x1, sqrt(gx1)
+        if sgn0(y) != 1:
+            y = q - y
+    else:
+        x, y = x2, sqrt(gx2)
+        if sgn0(y) != 0:
+            y = q - y
+    p = montgomery_to_edwards(x * K % q, y * K % q)
+    assert on_curve(p)
+    return p
+
+
+# simplified SWU, RFC 9380, section 6.6.2, through the 2-isogenous curve
+
+# bandersnatch as y^2 = x (x^2 + m_a x + m_b), with x = K s and y = K^2 t
+m_a = J * K % q
+m_b = K * K % q
+# the curve 2-isogenous through the kernel (0, 0),
+# Y^2 = X (X^2 - 2 m_a X + (m_a^2 - 4 m_b)), in short Weierstrass form
+# y^2 = x^3 + A' x + B' with X = x + 2 m_a / 3
+SHIFT = 2 * m_a * inv(3) % q
+ISO_A4 = (m_a * m_a - 4 * m_b) % q
+ISO_A = (ISO_A4 - 4 * m_a * m_a * inv(3)) % q
+ISO_B = (2 * pow(-2 * m_a, 3, q) * inv(27)
+         + 2 * m_a * ISO_A4 * inv(3)) % q
+SSWU_Z = q - 7
+
+
+def map_to_isogenous_sswu(u):
+    tv1 = (SSWU_Z * SSWU_Z * pow(u, 4, q) + SSWU_Z * u * u) % q
+    if tv1 == 0:
+        x1 = ISO_B * inv(SSWU_Z * ISO_A) % q
+    else:
+        x1 = -ISO_B * inv(ISO_A) * (1 + inv(tv1)) % q
+    gx1 = (x1 ** 3 + ISO_A * x1 + ISO_B) % q
+    x2 = SSWU_Z * u * u * x1 % q
+    gx2 = (x2 ** 3 + ISO_A * x2 + ISO_B) % q
+    if is_square(gx1):
+        x, y = x1, sqrt(gx1)
+    else:
+        x, y = x2, sqrt(gx2)
+    if sgn0(u) != sgn0(y):
+        y = q - y
+    return (x, y)
+
+
+def dual_isogeny(point):
+    x, y = point
+    big_x, big_y = (x + SHIFT) % q, y
+    if big_x == 0:
+        return IDENTITY
+    # the 2-isogeny with kernel (0, 0) of the isogenous curve lands on
+    # y^2 = x (x^2 + 4 m_a x + 16 m_b), i.e., 4 and 8 times the coordinates
+    # of bandersnatch
+    xm = big_y * big_y * inv(4 * big_x * big_x) % q
+    ym = big_y * (ISO_A4 - big_x * big_x) * inv(8 * big_x * big_x) % q
+    p = montgomery_to_edwards(xm * inv(K) % q, ym * inv(K * K) % q)
+    assert on_curve(p)
+    return p
+
+
+def map_to_curve_sswu(u):
+    return dual_isogeny(map_to_isogenous_sswu(u))
+
+
+# hash_to_curve and encode_to_curve, RFC 9380, section 3
+
+
+def hash_to_curve(map_to_curve, msg, dst):
+    u0, u1 = hash_to_field(msg, dst, 2)
+    return clear_cofactor(add(map_to_curve(u0), map_to_curve(u1)))
+
+
+def encode_to_curve(map_to_curve, msg, dst):
+    (u,) = hash_to_field(msg, dst, 1)
+    return clear_cofactor(map_to_curve(u))
+
+
+# derive_generator
+
+
+def derive_generator(domain, index):
+    dst = (b"BANDERSNATCH-V01-GENERATOR-with-"
+           b"bandersnatch_XMD:SHA-256_ELL2_RO_")
+    msg = len(domain).to_bytes(8, "big") + domain + index.to_bytes(8, "big")
+    return hash_to_curve(map_to_curve_elligator2, msg, dst)
+
+
+# the legacy try-and-increment map
+
+
+def try_and_increment(hash_fn, dst, msg):
+    for counter in range(256):
+        candidate = hash_fn(dst + msg + bytes([counter])).digest()[:32]
+        p = decompress(candidate)
+        if p is not None:
+            p = clear_cofactor(p)
+            if p != IDENTITY:
+                return p
+    return None
+
+
+def main():
+    suites = [
+        ("ELL2_RO_", hash_to_curve, map_to_curve_elligator2),
+        ("ELL2_NU_", encode_to_curve, map_to_curve_elligator2),
+        ("SSWU_RO_", hash_to_curve, map_to_curve_sswu),
+        ("SSWU_NU_", encode_to_curve, map_to_curve_sswu),
+    ]
+    for suite, encoding, map_to_curve in suites:
+        dst = b"QUUX-V01-CS02-with-bandersnatch_XMD:SHA-256_" + suite.encode()
+        print(dst.decode())
+        for msg in MSGS:
+            print("  %r: %s" % (msg[:16], compress(
+                encoding(map_to_curve, msg, dst))))
+
+    print("derive_generator")
+    for domain, index in [(b"", 0), (b"pedersen", 0), (b"pedersen", 1),
+                          (b"bandersnatch", 42)]:
+        print("  %r, %d: %s" % (domain, index,
+                                compress(derive_generator(domain, index))))
+
+    dst = b"BANDERSNATCH-LEGACY-TEST"
+    print(dst.decode(), "(try-and-increment, SHA-256 and SHA-512)")
+    for msg in MSGS[:3]:
+        print("  %r: %s %s" % (
+            msg,
+            compress(try_and_increment(hashlib.sha256, dst, msg)),
+            compress(try_and_increment(hashlib.sha512, dst, msg)),
+        ))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,138 @@
+//! Conversions between the types of this crate and those of
+//! [`ark-ed-on-bls12-381-bandersnatch`](ark_ed_on_bls12_381_bandersnatch),
+//! the bandersnatch curve of arkworks 0.4, behind the
+//! `ark-ed-on-bls12-381-bandersnatch` feature.
+//!
+//! Both crates use the same twisted Edwards model of the curve, so points
+//! and field elements convert coordinate-wise, without inversion, and the
+//! conversions commute with the group and field operations. The prime
+//! subgroup generators differ: a protocol that fixes a generator must
+//! convert it rather than use the other crate's. Projects built on the upstream curve can then use the GLV
+//! multiplication, the MSM or Banderwagon of this crate on their own
+//! values.
+//!
+//! This crate is built on arkworks 0.3, so its curve and field types are
+//! foreign types parameterized by local parameters, for which the orphan
+//! rule forbids `From` implementations with the upstream types; the
+//! conversions are the methods of the [`ArkworksConversion`] extension
+//! trait instead. [`Element`] converts with `From` and `TryFrom`.
+//!
+//! ```
+//! use ark_ec::ProjectiveCurve;
+//! use bandersnatch::{arkworks::ArkworksConversion, EdwardsProjective, Fr};
+//!
+//! let p = EdwardsProjective::prime_subgroup_generator().mul([42]);
+//! let upstream = p.to_arkworks();
+//! assert_eq!(EdwardsProjective::from_arkworks(&upstream), p);
+//! assert_eq!(Fr::from_arkworks(&Fr::from(7u64).to_arkworks()), Fr::from(7u64));
+//! ```
+
+use crate::{
+    banderwagon::Element, EdwardsAffine, EdwardsProjective, Fq, Fr, PointError,
+};
+use ark_ed_on_bls12_381_bandersnatch as upstream;
+use ark_ff::{BigInteger256, PrimeField};
+use ark_ff_04::BigInt;
+use ark_std::convert::TryFrom;
+
+#[cfg(test)]
+mod tests;
+
+/// Conversion to and from the matching type of
+/// `ark-ed-on-bls12-381-bandersnatch`.
+pub trait ArkworksConversion: Sized {
+    /// The upstream type.
+    type Upstream;
+
+    /// Convert to the upstream type.
+    fn to_arkworks(&self) -> Self::Upstream;
+
+    /// Convert from the upstream type.
+    fn from_arkworks(value: &Self::Upstream) -> Self;
+}
+
+impl ArkworksConversion for Fq {
+    type Upstream = upstream::Fq;
+
+    fn to_arkworks(&self) -> upstream::Fq {
+        upstream::Fq::new(BigInt(self.into_repr().0))
+    }
+
+    fn from_arkworks(value: &upstream::Fq) -> Self {
+        let limbs = BigInt::from(*value).0;
+        // upstream elements are reduced
+        Fq::from_repr(BigInteger256(limbs)).unwrap()
+    }
+}
+
+impl ArkworksConversion for Fr {
+    type Upstream = upstream::Fr;
+
+    fn to_arkworks(&self) -> upstream::Fr {
+        upstream::Fr::new(BigInt(self.into_repr().0))
+    }
+
+    fn from_arkworks(value: &upstream::Fr) -> Self {
+        let limbs = BigInt::from(*value).0;
+        // upstream elements are reduced
+        Fr::from_repr(BigInteger256(limbs)).unwrap()
+    }
+}
+
+impl ArkworksConversion for EdwardsAffine {
+    type Upstream = upstream::EdwardsAffine;
+
+    fn to_arkworks(&self) -> upstream::EdwardsAffine {
+        upstream::EdwardsAffine::new_unchecked(
+            self.x.to_arkworks(),
+            self.y.to_arkworks(),
+        )
+    }
+
+    fn from_arkworks(value: &upstream::EdwardsAffine) -> Self {
+        EdwardsAffine::new(
+            Fq::from_arkworks(&value.x),
+            Fq::from_arkworks(&value.y),
+        )
+    }
+}
+
+/// Both crates use extended coordinates `(X : Y : T : Z)`, which are
+/// converted as they are.
+impl ArkworksConversion for EdwardsProjective {
+    type Upstream = upstream::EdwardsProjective;
+
+    fn to_arkworks(&self) -> upstream::EdwardsProjective {
+        upstream::EdwardsProjective::new_unchecked(
+            self.x.to_arkworks(),
+            self.y.to_arkworks(),
+            self.t.to_arkworks(),
+            self.z.to_arkworks(),
+        )
+    }
+
+    fn from_arkworks(value: &upstream::EdwardsProjective) -> Self {
+        EdwardsProjective::new(
+            Fq::from_arkworks(&value.x),
+            Fq::from_arkworks(&value.y),
+            Fq::from_arkworks(&value.t),
+            Fq::from_arkworks(&value.z),
+        )
+    }
+}
+
+/// The representative of the element, see [`Element::to_edwards`].
+impl From<Element> for upstream::EdwardsProjective {
+    fn from(element: Element) -> Self {
+        element.to_edwards().to_arkworks()
+    }
+}
+
+/// See [`Element::from_edwards`].
+impl TryFrom<upstream::EdwardsAffine> for Element {
+    type Error = PointError;
+
+    fn try_from(point: upstream::EdwardsAffine) -> Result<Self, PointError> {
+        Element::from_edwards(&EdwardsAffine::from_arkworks(&point))
+    }
+}
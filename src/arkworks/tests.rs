@@ -0,0 +1,76 @@
+use super::*;
+use crate::Validate;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ec_04::{AffineRepr, CurveGroup};
+use ark_ff::{Field, One, UniformRand, Zero};
+use ark_std::test_rng;
+
+#[test]
+fn test_field_conversions() {
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let (a, b) = (Fq::rand(&mut rng), Fq::rand(&mut rng));
+        assert_eq!(Fq::from_arkworks(&a.to_arkworks()), a);
+        assert_eq!((a * b).to_arkworks(), a.to_arkworks() * b.to_arkworks());
+        assert_eq!((a + b).to_arkworks(), a.to_arkworks() + b.to_arkworks());
+
+        let (a, b) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+        assert_eq!(Fr::from_arkworks(&a.to_arkworks()), a);
+        assert_eq!((a * b).to_arkworks(), a.to_arkworks() * b.to_arkworks());
+        assert_eq!(
+            a.inverse().unwrap().to_arkworks(),
+            ark_ff_04::Field::inverse(&a.to_arkworks()).unwrap()
+        );
+    }
+    assert_eq!(Fq::from(5u64).to_arkworks(), upstream::Fq::from(5u64));
+    assert_eq!(Fr::from(5u64).to_arkworks(), upstream::Fr::from(5u64));
+}
+
+#[test]
+fn test_point_conversions() {
+    // the generators differ, but generate the same subgroup
+    let g = EdwardsAffine::prime_subgroup_generator().to_arkworks();
+    assert!(g.is_on_curve() && g.is_in_correct_subgroup_assuming_on_curve());
+    let h = EdwardsAffine::from_arkworks(&upstream::EdwardsAffine::generator());
+    assert!(h.validate().is_ok());
+    // the upstream generator is the base point of gnark-crypto
+    assert_eq!(h, crate::gnark_base_point());
+    assert_eq!(
+        EdwardsAffine::zero().to_arkworks(),
+        upstream::EdwardsAffine::zero()
+    );
+
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let p = EdwardsProjective::rand(&mut rng);
+        let q = EdwardsProjective::rand(&mut rng);
+        let k = Fr::rand(&mut rng);
+        assert_eq!(EdwardsProjective::from_arkworks(&p.to_arkworks()), p);
+        assert_eq!((p + q).to_arkworks(), p.to_arkworks() + q.to_arkworks());
+        assert_eq!(
+            p.mul(k.into_repr()).to_arkworks(),
+            p.to_arkworks() * k.to_arkworks()
+        );
+
+        let a = p.into_affine();
+        assert_eq!(EdwardsAffine::from_arkworks(&a.to_arkworks()), a);
+        assert_eq!(a.to_arkworks(), p.to_arkworks().into_affine());
+        assert!(a.to_arkworks().is_on_curve());
+        assert!(a.to_arkworks().is_in_correct_subgroup_assuming_on_curve());
+    }
+}
+
+#[test]
+fn test_element_conversions() {
+    let mut rng = test_rng();
+    let p = EdwardsProjective::rand(&mut rng).into_affine();
+    let element = Element::from_edwards(&p).unwrap();
+    let upstream: upstream::EdwardsProjective = element.into();
+    assert_eq!(Element::try_from(upstream.into_affine()).unwrap(), element);
+
+    let off_curve = EdwardsAffine::new(p.x, p.y + Fq::one());
+    assert_eq!(
+        Element::try_from(off_curve.to_arkworks()),
+        Err(PointError::NotOnCurve)
+    );
+}
@@ -0,0 +1,653 @@
+//! Banderwagon, the prime order group used by Verkle trees, built as a
+//! quotient of bandersnatch.
+//!
+//! The points of bandersnatch whose torsion component is either the
+//! identity or the point `T = (0, -1)` of order 2 form a subgroup of order
+//! `2r`; Banderwagon is its quotient by `{O, T}`, which has prime order
+//! `r`. As `P + T = (-x, -y)`, an [`Element`] is represented by either of
+//! the points `(x, y)` and `(-x, -y)`, and two elements are equal when
+//! `x1 y2 = x2 y1`.
+//!
+//! Unlike the points of bandersnatch, elements have no small order
+//! component to clear or check, which makes them safe to use in protocols
+//! that assume a prime order group.
+//!
+//! The generator and the 32-byte encoding are the ones of go-ipa and
+//! go-verkle: an element is encoded as the big-endian `x` coordinate of
+//! its representative whose `y` coordinate is lexicographically largest,
+//! i.e., greater than `(q - 1) / 2`.
+//!
+//! With the `verkle-crs` feature, the 256 points of the Verkle CRS are
+//! available from `verkle_crs`, and with the `serde` feature, elements
+//! implement `Serialize` and `Deserialize`.
+
+use crate::{
+    batch_inverse, batch_legendre, batch_normalize, batch_sqrt,
+    fq_from_le_bytes, from_montgomery,
+    hash_to_curve::{
+        elligator_squared::{decode_montgomery, encode_montgomery},
+        ELLIGATOR_SQUARED_SIZE,
+    },
+    is_torsion_free, ladder_mul, mul_by_generator, split_batch,
+    sqrt_with_tables, to_montgomery, torsion_component, torsion_points, wipe,
+    BatchDecodingError, EdwardsAffine, EdwardsParameters, EdwardsProjective,
+    Fq, Fr, GLVParameters, HexEncoding, HexError, PointError, PrimeOrderGroup,
+    TuningProfile,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve, TEModelParameters};
+use ark_ff::{
+    field_new, BigInteger, Field, FpParameters, LegendreSymbol, One,
+    PrimeField, SquareRootField, Zero,
+};
+use ark_std::{
+    cfg_iter, fmt,
+    hash::{Hash, Hasher},
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+    str::FromStr,
+    vec::Vec,
+    UniformRand,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(feature = "verkle-crs")]
+mod crs;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "verkle-crs")]
+pub use crs::{verkle_crs, VERKLE_CRS_HEX, VERKLE_CRS_SEED, VERKLE_CRS_SIZE};
+
+/// The size of an encoded element, in bytes.
+pub const ENCODED_SIZE: usize = 32;
+
+/// The `x` coordinate of the generator of the Verkle specification.
+const GENERATOR_X: Fq = field_new!(
+    Fq,
+    "18886178867200960497001835917649091219057080094937609519140440539760939937304"
+);
+
+/// The `y` coordinate of the generator of the Verkle specification.
+const GENERATOR_Y: Fq = field_new!(
+    Fq,
+    "19188667384257783945677642223292697773471335439753913231509108946878080696678"
+);
+
+/// An element of Banderwagon.
+#[derive(Clone, Copy, Debug)]
+pub struct Element(EdwardsProjective);
+
+impl Element {
+    /// The neutral element.
+    pub fn identity() -> Self {
+        Self(EdwardsProjective::zero())
+    }
+
+    /// The generator of the Verkle specification.
+    ///
+    /// This is not the class of the generator of the prime order subgroup
+    /// of this crate, so that the comb tables of the latter do not apply.
+    pub fn generator() -> Self {
+        Self(EdwardsProjective::new(
+            GENERATOR_X,
+            GENERATOR_Y,
+            GENERATOR_X * GENERATOR_Y,
+            Fq::one(),
+        ))
+    }
+
+    /// Whether this is the neutral element, i.e., whether it is
+    /// represented by `(0, 1)` or `(0, -1)`.
+    pub fn is_identity(&self) -> bool {
+        self.0.x.is_zero()
+    }
+
+    /// Compute `scalar * G`, where `G` is the generator.
+    pub fn mul_by_generator(scalar: &Fr) -> Self {
+        Self::generator() * *scalar
+    }
+
+    /// The class of a point of bandersnatch, checking that the point is on
+    /// the curve and that its torsion component is `O` or `T`.
+    pub fn from_edwards(point: &EdwardsAffine) -> Result<Self, PointError> {
+        if !point.is_on_curve() {
+            return Err(PointError::NotOnCurve);
+        }
+        if !in_subgroup(&point.x) {
+            return Err(PointError::NotInPrimeSubgroup);
+        }
+        Ok(Self(point.into_projective()))
+    }
+
+    /// A point of bandersnatch that represents this element; the other
+    /// representative is its sum with `T = (0, -1)`.
+    pub fn to_edwards(&self) -> EdwardsProjective {
+        self.0
+    }
+
+    /// Compute `sum_i scalars[i] * elements[i]` with the MSM algorithm
+    /// suited to the number of terms, see
+    /// [`TuningProfile`](crate::TuningProfile), on the representatives of
+    /// the elements.
+    ///
+    /// If the two slices have different lengths, the longer one is
+    /// truncated.
+    pub fn msm(elements: &[Self], scalars: &[Fr]) -> Self {
+        let points: Vec<EdwardsProjective> =
+            elements.iter().map(|e| e.0).collect();
+        let bases = batch_normalize(&points);
+        Self(TuningProfile::default().msm(&bases, scalars))
+    }
+
+    /// GLV multiplication; the endomorphism maps `T` to `O`, so the product
+    /// of either representative is in the class of the product.
+    fn glv_mul(&self, scalar: &Fr) -> Self {
+        Self(EdwardsParameters::glv_mul(&self.0.into_affine(), scalar))
+    }
+
+    /// Encode the element as the big-endian `x` coordinate of its
+    /// representative with the lexicographically largest `y` coordinate.
+    pub fn to_bytes(&self) -> [u8; ENCODED_SIZE] {
+        let p = self.0.into_affine();
+        let x = if is_lexicographically_largest(&p.y) {
+            p.x
+        } else {
+            -p.x
+        };
+        let mut bytes = [0u8; ENCODED_SIZE];
+        bytes.copy_from_slice(&x.into_repr().to_bytes_be());
+        bytes
+    }
+
+    /// Map the element to `Fr`, as Verkle trees do to commit to a child
+    /// commitment: `x / y`, which is the same for both representatives, is
+    /// reduced from `Fq` modulo `r`.
+    ///
+    /// The identity maps to zero; `y` never vanishes on the curve, as `a`
+    /// is not a square.
+    pub fn map_to_scalar_field(&self) -> Fr {
+        let y_inv = self.0.y.inverse().unwrap_or_else(Fq::zero);
+        scalar_from_base_field(&(self.0.x * y_inv))
+    }
+
+    /// Map many elements to `Fr` with [`Element::map_to_scalar_field`],
+    /// sharing a single field inversion among all of them.
+    pub fn batch_map_to_scalar_field(elements: &[Self]) -> Vec<Fr> {
+        let mut y_invs: Vec<Fq> = elements.iter().map(|e| e.0.y).collect();
+        batch_inverse(&mut y_invs);
+        elements
+            .iter()
+            .zip(&y_invs)
+            .map(|(e, y_inv)| scalar_from_base_field(&(e.0.x * y_inv)))
+            .collect()
+    }
+
+    /// Decode an element encoded with [`Element::to_bytes`].
+    ///
+    /// Fails if `x` is not smaller than `q`, if there is no point with
+    /// this `x` coordinate, or if the point is not in the subgroup of order
+    /// `2r`.
+    pub fn from_bytes(bytes: &[u8; ENCODED_SIZE]) -> Result<Self, PointError> {
+        let x = x_from_bytes(bytes)?;
+        let den_inv = curve_denominator(&x)
+            .inverse()
+            .ok_or(PointError::NotOnCurve)?;
+        Self::from_x(&x, &den_inv)
+    }
+
+    /// Decode many elements encoded with [`Element::to_bytes`], as
+    /// [`Element::from_bytes`] does, sharing a single field inversion among
+    /// all of them.
+    ///
+    /// The square roots are computed with
+    /// [`batch_sqrt`](crate::batch_sqrt), so that invalid encodings skip
+    /// them, and the Legendre symbols of the subgroup checks with
+    /// [`batch_legendre`](crate::batch_legendre). With the `parallel`
+    /// feature, these are computed by the rayon thread pool.
+    pub fn batch_from_bytes(
+        bytes: &[[u8; ENCODED_SIZE]],
+    ) -> Vec<Result<Self, PointError>> {
+        let xs: Vec<_> = bytes.iter().map(x_from_bytes).collect();
+        let mut den_invs: Vec<Fq> = xs
+            .iter()
+            .map(|x| x.as_ref().map_or(Fq::one(), curve_denominator))
+            .collect();
+        batch_inverse(&mut den_invs);
+        let numerators: Vec<Fq> = xs
+            .iter()
+            .map(|x| x.as_ref().map_or(Fq::one(), curve_numerator))
+            .collect();
+        let y_squares: Vec<Fq> = numerators
+            .iter()
+            .zip(&den_invs)
+            .map(|(n, d)| *n * d)
+            .collect();
+        let ys = batch_sqrt(&y_squares);
+        let symbols = batch_legendre(&numerators);
+        cfg_iter!(xs)
+            .zip(ys)
+            .zip(symbols)
+            .map(|((x, y), symbol)| {
+                Self::from_x_and_root(&(*x)?, y, symbol != -1)
+            })
+            .collect()
+    }
+
+    /// Encode `elements` as their number (`u32`, little-endian) followed by
+    /// their encodings, as [`serialize_batch`](crate::serialize_batch)
+    /// does for points.
+    pub fn serialize_batch(elements: &[Self]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + ENCODED_SIZE * elements.len());
+        bytes.extend_from_slice(&(elements.len() as u32).to_le_bytes());
+        for element in elements {
+            bytes.extend_from_slice(&element.to_bytes());
+        }
+        bytes
+    }
+
+    /// Decode elements encoded with [`Element::serialize_batch`], with
+    /// [`Element::batch_from_bytes`], rejecting trailing bytes.
+    pub fn deserialize_batch(
+        bytes: &[u8],
+    ) -> Result<Vec<Self>, BatchDecodingError> {
+        let encodings: Vec<[u8; ENCODED_SIZE]> =
+            split_batch(bytes)?.into_iter().copied().collect();
+        Self::batch_from_bytes(&encodings)
+            .into_iter()
+            .enumerate()
+            .map(|(index, element)| {
+                element.map_err(|error| BatchDecodingError::InvalidPoint {
+                    index,
+                    error,
+                })
+            })
+            .collect()
+    }
+
+    /// Encode the element as [`ELLIGATOR_SQUARED_SIZE`] bytes which are
+    /// uniformly random if the element is, with Elligator Squared, see
+    /// [`elligator_squared_encode`](crate::hash_to_curve::elligator_squared_encode).
+    ///
+    /// The encoded point is a representative plus a random torsion point,
+    /// so that it is uniformly distributed over the whole curve.
+    pub fn to_uniform_bytes<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> [u8; ELLIGATOR_SQUARED_SIZE] {
+        let torsion = torsion_points()[rng.gen_range(0..4)];
+        let p = to_montgomery(&self.0.into_affine()) + torsion;
+        encode_montgomery(&p, rng)
+    }
+
+    /// Decode bytes produced by [`Element::to_uniform_bytes`], or any
+    /// bytes, into an element: the decoded point of the curve, minus its
+    /// torsion component unless the latter is `O` or `T`.
+    pub fn from_uniform_bytes(bytes: &[u8; ELLIGATOR_SQUARED_SIZE]) -> Self {
+        let p = decode_montgomery(bytes);
+        let point = match from_montgomery(&p) {
+            Some(point) => point,
+            // a point of order 2 at infinity of the twisted Edwards model
+            None => return Self::identity(),
+        };
+        let torsion = torsion_component(&point);
+        if torsion.is_zero() || torsion.u.is_zero() {
+            return Self(point.into_projective());
+        }
+        let point = from_montgomery(&(p + torsion))
+            .expect("points of odd order are affine in the Edwards model");
+        Self(point.into_projective())
+    }
+
+    /// The element with coordinate `x`, given the inverse of
+    /// `1 - d x^2`, with the lexicographically largest `y`.
+    fn from_x(x: &Fq, den_inv: &Fq) -> Result<Self, PointError> {
+        let num = curve_numerator(x);
+        let root = sqrt_with_tables(&(num * den_inv));
+        Self::from_x_and_root(x, root, in_subgroup(x))
+    }
+
+    /// The element with coordinate `x`, given a square root of
+    /// `(1 - a x^2) / (1 - d x^2)`, if any, and whether `1 - a x^2` is a
+    /// square, with the lexicographically largest `y`.
+    fn from_x_and_root(
+        x: &Fq,
+        root: Option<Fq>,
+        in_subgroup: bool,
+    ) -> Result<Self, PointError> {
+        let mut y = root.ok_or(PointError::NotOnCurve)?;
+        if !is_lexicographically_largest(&y) {
+            y = -y;
+        }
+        if !in_subgroup {
+            return Err(PointError::NotInPrimeSubgroup);
+        }
+        Ok(Self(EdwardsProjective::new(*x, y, *x * y, Fq::one())))
+    }
+}
+
+/// Reduce an element of `Fq`, i.e., its canonical integer, modulo `r`.
+fn scalar_from_base_field(x: &Fq) -> Fr {
+    Fr::from_le_bytes_mod_order(&x.into_repr().to_bytes_le())
+}
+
+/// Read the big-endian `x` coordinate of an encoded element.
+fn x_from_bytes(bytes: &[u8; ENCODED_SIZE]) -> Result<Fq, PointError> {
+    let mut le = *bytes;
+    le.reverse();
+    fq_from_le_bytes(&le).ok_or(PointError::NonCanonicalEncoding)
+}
+
+/// The numerator `1 - a x^2` of `y^2 = (1 - a x^2) / (1 - d x^2)`.
+fn curve_numerator(x: &Fq) -> Fq {
+    Fq::one() - EdwardsParameters::mul_by_a(&x.square())
+}
+
+/// The denominator `1 - d x^2` of `y^2 = (1 - a x^2) / (1 - d x^2)`, which
+/// never vanishes since `d` is not a square.
+fn curve_denominator(x: &Fq) -> Fq {
+    Fq::one() - EdwardsParameters::COEFF_D * x.square()
+}
+
+/// Whether `y > (q - 1) / 2`.
+fn is_lexicographically_largest(y: &Fq) -> bool {
+    y.into_repr()
+        > <<Fq as PrimeField>::Params as FpParameters>::MODULUS_MINUS_ONE_DIV_TWO
+}
+
+/// Whether `bytes` is the encoding of an element, i.e., whether
+/// [`Element::from_bytes`] would succeed, without computing `y`.
+///
+/// This costs two Legendre symbols: the one of `1 - a x^2` for the
+/// subgroup, and the one of `1 - d x^2` for the existence of `y`, given
+/// the former. This is much cheaper than the square root of a full
+/// decompression, to filter malformed inputs early.
+pub fn is_valid_banderwagon_encoding(bytes: &[u8; ENCODED_SIZE]) -> bool {
+    match x_from_bytes(bytes) {
+        Ok(x) => {
+            in_subgroup(&x)
+                && curve_denominator(&x).legendre()
+                    == LegendreSymbol::QuadraticResidue
+        }
+        Err(_) => false,
+    }
+}
+
+/// Whether `x` is the coordinate of a point of the subgroup of order `2r`,
+/// i.e., whether `1 - a x^2` is a square.
+fn in_subgroup(x: &Fq) -> bool {
+    curve_numerator(x).legendre() != LegendreSymbol::QuadraticNonResidue
+}
+
+impl HexEncoding for Element {
+    fn hex_bytes(&self) -> [u8; ENCODED_SIZE] {
+        self.to_bytes()
+    }
+
+    fn from_hex_bytes(bytes: &[u8; ENCODED_SIZE]) -> Option<Self> {
+        Self::from_bytes(bytes).ok()
+    }
+}
+
+/// `0x` followed by the hexadecimal encoding, see [`Element::to_bytes`].
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.hex(), f)
+    }
+}
+
+/// The hexadecimal encoding, with a `0x` prefix if the `#` flag is set.
+impl fmt::LowerHex for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.hex(), f)
+    }
+}
+
+/// Parse the hexadecimal encoding, with an optional `0x` prefix.
+impl FromStr for Element {
+    type Err = HexError;
+
+    fn from_str(s: &str) -> Result<Self, HexError> {
+        Self::from_hex(s)
+    }
+}
+
+impl Default for Element {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Equality in the quotient, i.e., `x1 y2 = x2 y1`, without normalizing
+/// either element.
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.x * other.0.y == other.0.x * self.0.y
+    }
+}
+
+impl Eq for Element {}
+
+/// Hash the canonical encoding, which is the same for both
+/// representatives.
+impl Hash for Element {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bytes().hash(state);
+    }
+}
+
+impl Add for Element {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl<'a> Add<&'a Element> for Element {
+    type Output = Self;
+
+    fn add(self, other: &'a Element) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl<'b> Add<&'b Element> for &Element {
+    type Output = Element;
+
+    fn add(self, other: &'b Element) -> Element {
+        Element(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Element {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl<'a> AddAssign<&'a Element> for Element {
+    fn add_assign(&mut self, other: &'a Element) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Element {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl<'a> Sub<&'a Element> for Element {
+    type Output = Self;
+
+    fn sub(self, other: &'a Element) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl<'b> Sub<&'b Element> for &Element {
+    type Output = Element;
+
+    fn sub(self, other: &'b Element) -> Element {
+        Element(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Element {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl<'a> SubAssign<&'a Element> for Element {
+    fn sub_assign(&mut self, other: &'a Element) {
+        self.0 -= other.0;
+    }
+}
+
+impl Neg for Element {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Neg for &Element {
+    type Output = Element;
+
+    fn neg(self) -> Element {
+        Element(-self.0)
+    }
+}
+
+impl Mul<&Fr> for &Element {
+    type Output = Element;
+
+    fn mul(self, scalar: &Fr) -> Element {
+        self.glv_mul(scalar)
+    }
+}
+
+impl Mul<Fr> for Element {
+    type Output = Self;
+
+    fn mul(self, scalar: Fr) -> Self {
+        self.glv_mul(&scalar)
+    }
+}
+
+impl Mul<&Fr> for Element {
+    type Output = Self;
+
+    fn mul(self, scalar: &Fr) -> Self {
+        self.glv_mul(scalar)
+    }
+}
+
+impl Mul<Fr> for &Element {
+    type Output = Element;
+
+    fn mul(self, scalar: Fr) -> Element {
+        self.glv_mul(&scalar)
+    }
+}
+
+impl MulAssign<Fr> for Element {
+    fn mul_assign(&mut self, scalar: Fr) {
+        *self = self.glv_mul(&scalar);
+    }
+}
+
+impl MulAssign<&Fr> for Element {
+    fn mul_assign(&mut self, scalar: &Fr) {
+        *self = self.glv_mul(scalar);
+    }
+}
+
+impl Sum for Element {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::identity(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Element> for Element {
+    fn sum<I: Iterator<Item = &'a Element>>(iter: I) -> Self {
+        iter.fold(Self::identity(), Add::add)
+    }
+}
+
+/// Banderwagon with the encoding of go-ipa, and the GLV multiplication.
+impl PrimeOrderGroup for Element {
+    type Scalar = Fr;
+
+    const ENCODED_SIZE: usize = ENCODED_SIZE;
+
+    fn identity() -> Self {
+        Element::identity()
+    }
+
+    fn generator() -> Self {
+        Element::generator()
+    }
+
+    fn is_identity(&self) -> bool {
+        Element::is_identity(self)
+    }
+
+    fn mul(&self, scalar: &Fr) -> Self {
+        *self * *scalar
+    }
+
+    /// The Montgomery ladder needs a base in the prime order subgroup,
+    /// which one of the two representatives is.
+    fn mul_secret(&self, scalar: &Fr) -> Self {
+        let point = self.0.into_affine();
+        let base = if is_torsion_free(&point) {
+            point
+        } else {
+            EdwardsAffine::new(-point.x, -point.y)
+        };
+        Self(ladder_mul(&base, scalar))
+    }
+
+    fn wipe_scalar(scalar: &mut Fr) {
+        wipe(scalar);
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        Element::to_bytes(self).to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut array = [0u8; ENCODED_SIZE];
+        if bytes.len() != ENCODED_SIZE {
+            return None;
+        }
+        array.copy_from_slice(bytes);
+        Element::from_bytes(&array).ok()
+    }
+}
+
+/// Uniform sampling, which also provides `UniformRand` for [`Element`].
+///
+/// An element is the class of `k B` for a uniform scalar `k`, where `B` is
+/// the generator of bandersnatch: as `B` generates the prime order
+/// subgroup, the class is uniform in Banderwagon, with no rejection on
+/// points, and `k B` uses the precomputed comb table of
+/// [`mul_by_generator`].
+impl Distribution<Element> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Element {
+        Element(mul_by_generator(&Fr::rand(rng)))
+    }
+}
@@ -0,0 +1,31 @@
+//! `Serialize` and `Deserialize` for [`Element`], behind the `serde`
+//! feature.
+//!
+//! An element is serialized as its 32-byte encoding, see
+//! [`Element::to_bytes`]: as a lowercase hexadecimal string in
+//! human-readable formats, e.g., JSON, and as bytes otherwise.
+
+use super::Element;
+use crate::serialization::{deserialize_bytes, serialize_bytes};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for Element {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Element {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        deserialize_bytes(
+            deserializer,
+            "a Banderwagon element encoding",
+            Element::from_bytes,
+        )
+    }
+}
@@ -0,0 +1,529 @@
+use super::*;
+use crate::PointFromCoordinate;
+use ark_ff::PrimeField;
+use ark_std::{string::ToString, test_rng, vec::Vec, UniformRand};
+
+/// The point `T = (0, -1)` of order 2.
+fn t() -> EdwardsAffine {
+    EdwardsAffine::new(Fq::zero(), -Fq::one())
+}
+
+#[test]
+fn test_group_laws() {
+    let mut rng = test_rng();
+    for _ in 0..16 {
+        let a: Element = rng.gen();
+        let b: Element = rng.gen();
+        let c: Element = rng.gen();
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!(a + b, b + a);
+        assert_eq!(a + Element::identity(), a);
+        assert!((a - a).is_identity());
+        assert_eq!(a + -b, a - b);
+
+        let mut d = a;
+        d += b;
+        d -= c;
+        assert_eq!(d, a + b - c);
+        assert_eq!([a, b, c].iter().copied().sum::<Element>(), a + b + c);
+    }
+    assert_ne!(Element::generator(), Element::identity());
+}
+
+#[test]
+fn test_quotient() {
+    let mut rng = test_rng();
+    let p: EdwardsAffine = rng.gen();
+    let q = p + t();
+    assert_ne!(p, q);
+
+    let a = Element::from_edwards(&p).unwrap();
+    let b = Element::from_edwards(&q).unwrap();
+    assert_eq!(a, b);
+    assert!(Element::from_edwards(&t()).unwrap().is_identity());
+    assert_ne!(a, -a);
+    assert!((a - b).is_identity());
+}
+
+#[test]
+fn test_scalar_mul() {
+    let mut rng = test_rng();
+    let p: EdwardsAffine = rng.gen();
+    let a = Element::from_edwards(&p).unwrap();
+    let b = Element::from_edwards(&(p + t())).unwrap();
+    for _ in 0..16 {
+        let k = Fr::rand(&mut rng);
+        let expected = Element(p.mul(k.into_repr()));
+        assert_eq!(a * k, expected);
+        assert_eq!(b * k, expected);
+        assert_eq!(Element::mul_by_generator(&k), Element::generator() * k);
+    }
+    let r_minus_one = -Fr::one();
+    assert!((b * r_minus_one + b).is_identity());
+}
+
+#[test]
+fn test_from_edwards() {
+    let mut rng = test_rng();
+    let p: EdwardsAffine = rng.gen();
+    assert_eq!(
+        Element::from_edwards(&EdwardsAffine::new(p.x, p.y + Fq::one())),
+        Err(PointError::NotOnCurve)
+    );
+
+    // the points outside of the subgroup of order 2r are rejected
+    let outside: Vec<EdwardsAffine> = (1u64..)
+        .filter_map(|x| EdwardsAffine::from_x_with_sign(Fq::from(x), false))
+        .filter(|p| !in_subgroup(&p.x))
+        .take(4)
+        .collect();
+    for p in outside {
+        assert_eq!(
+            Element::from_edwards(&p),
+            Err(PointError::NotInPrimeSubgroup)
+        );
+        assert!(!p.mul_by_cofactor().is_zero());
+    }
+}
+
+fn from_hex(s: &str) -> [u8; ENCODED_SIZE] {
+    let mut bytes = [0u8; ENCODED_SIZE];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+    }
+    bytes
+}
+
+#[test]
+fn test_generator() {
+    let g = Element::generator().to_edwards().into_affine();
+    assert!(g.is_on_curve());
+    assert!(crate::is_torsion_free(&g));
+}
+
+// The encodings of the generator doubled i times, from go-ipa
+#[test]
+fn test_serialization_vectors() {
+    let vectors = [
+        "4a2c7486fd924882bf02c6908de395122843e3e05264d7991e18e7985dad51e9",
+        "43aa74ef706605705989e8fd38df46873b7eae5921fbed115ac9d937399ce4d5",
+        "5e5f550494159f38aa54d2ed7f11a7e93e4968617990445cc93ac8e59808c126",
+        "0e7e3748db7c5c999a7bcd93d71d671f1f40090423792266f94cb27ca43fce5c",
+        "14ddaa48820cb6523b9ae5fe9fe257cbbd1f3d598a28e670a40da5d1159d864a",
+        "6989d1c82b2d05c74b62fb0fbdf8843adae62ff720d370e209a7b84e14548a7d",
+        "26b8df6fa414bf348a3dc780ea53b70303ce49f3369212dec6fbe4b349b832bf",
+        "37e46072db18f038f2cc7d3d5b5d1374c0eb86ca46f869d6a95fc2fb092c0d35",
+        "2c1ce64f26e1c772282a6633fac7ca73067ae820637ce348bb2c8477d228dc7d",
+        "297ab0f5a8336a7a4e2657ad7a33a66e360fb6e50812d4be3326fab73d6cee07",
+        "5b285811efa7a965bd6ef5632151ebf399115fcc8f5b9b8083415ce533cc39ce",
+        "1f939fa2fd457b3effb82b25d3fe8ab965f54015f108f8c09d67e696294ab626",
+        "3088dcb4d3f4bacd706487648b239e0be3072ed2059d981fe04ce6525af6f1b8",
+        "35fbc386a16d0227ff8673bc3760ad6b11009f749bb82d4facaea67f58fc60ed",
+        "00f29b4f3255e318438f0a31e058e4c081085426adb0479f14c64985d0b956e0",
+        "3fa4384b2fa0ecc3c0582223602921daaa893a97b64bdf94dcaa504e8b7b9e5f",
+    ];
+    let mut point = Element::generator();
+    for vector in vectors.iter() {
+        let bytes = from_hex(vector);
+        assert_eq!(point.to_bytes(), bytes);
+        assert_eq!(Element::from_bytes(&bytes), Ok(point));
+        point = point + point;
+    }
+}
+
+#[test]
+fn test_serialization() {
+    let mut rng = test_rng();
+    for _ in 0..16 {
+        let p: EdwardsAffine = rng.gen();
+        let a = Element::from_edwards(&p).unwrap();
+        let b = Element::from_edwards(&(p + t())).unwrap();
+        assert_eq!(a.to_bytes(), b.to_bytes());
+        assert_eq!(Element::from_bytes(&a.to_bytes()), Ok(a));
+        assert_eq!(
+            <Element as PrimeOrderGroup>::from_bytes(&a.to_bytes()[..]),
+            Some(a)
+        );
+    }
+    assert_eq!(Element::identity().to_bytes(), [0u8; ENCODED_SIZE]);
+    assert!(Element::from_bytes(&[0u8; ENCODED_SIZE])
+        .unwrap()
+        .is_identity());
+}
+
+#[test]
+fn test_invalid_encodings() {
+    let q = from_hex(
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+    );
+    assert_eq!(
+        Element::from_bytes(&q),
+        Err(PointError::NonCanonicalEncoding)
+    );
+
+    let (mut off_curve, mut outside) = (0, 0);
+    for x in 1u64..64 {
+        let mut bytes = [0u8; ENCODED_SIZE];
+        bytes[24..].copy_from_slice(&x.to_be_bytes());
+        match Element::from_bytes(&bytes) {
+            Err(PointError::NotOnCurve) => off_curve += 1,
+            Err(PointError::NotInPrimeSubgroup) => outside += 1,
+            Ok(a) => assert_eq!(a.to_bytes(), bytes),
+            Err(e) => panic!("unexpected error {:?}", e),
+        }
+    }
+    assert!(off_curve > 0 && outside > 0);
+}
+
+#[test]
+fn test_generic_schnorr() {
+    use crate::schnorr::generic;
+
+    let secret = Fr::from(42u64);
+    let key = generic::public_key::<Element>(&secret);
+    let sig = generic::sign::<Element>(&secret, b"verkle").unwrap();
+    assert_eq!(generic::verify(&key, b"verkle", &sig), Ok(()));
+    assert!(generic::verify(&key, b"merkle", &sig).is_err());
+}
+
+#[test]
+fn test_mul_secret() {
+    let mut rng = test_rng();
+    for _ in 0..8 {
+        let p: EdwardsAffine = rng.gen();
+        let scalar: Fr = rng.gen();
+        // either representative of the element
+        let a = Element::from_edwards(&p).unwrap();
+        let b = Element::from_edwards(&(p + t())).unwrap();
+        assert_eq!(a.mul_secret(&scalar), a * scalar);
+        assert_eq!(b.mul_secret(&scalar), a * scalar);
+    }
+    let scalar: Fr = rng.gen();
+    assert_eq!(
+        Element::generator().mul_secret(&scalar),
+        Element::mul_by_generator(&scalar)
+    );
+    assert!(Element::identity().mul_secret(&scalar).is_identity());
+}
+
+#[test]
+fn test_batch_from_bytes() {
+    let mut rng = test_rng();
+    let mut bytes: Vec<[u8; ENCODED_SIZE]> =
+        (0..32).map(|_| rng.gen::<Element>().to_bytes()).collect();
+    bytes[0] = [0u8; ENCODED_SIZE];
+    bytes[1] = [0xff; ENCODED_SIZE];
+    for x in 1u8..8 {
+        let mut invalid = [0u8; ENCODED_SIZE];
+        invalid[ENCODED_SIZE - 1] = x;
+        bytes.push(invalid);
+    }
+
+    let decoded = Element::batch_from_bytes(&bytes);
+    assert_eq!(decoded.len(), bytes.len());
+    for (b, d) in bytes.iter().zip(decoded) {
+        assert_eq!(d, Element::from_bytes(b));
+    }
+    assert!(Element::batch_from_bytes(&[]).is_empty());
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::HashSet;
+
+    let mut rng = test_rng();
+    let p: EdwardsAffine = rng.gen();
+    let a = Element::from_edwards(&p).unwrap();
+    let b = Element::from_edwards(&(p + t())).unwrap();
+    // a non-normalized representative of a
+    let c = Element(a.0.double() - a.0);
+
+    let mut set = HashSet::new();
+    set.insert(a);
+    assert!(!set.insert(b));
+    assert!(!set.insert(c));
+    assert!(set.insert(-a));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_map_to_scalar_field() {
+    use ark_std::str::FromStr;
+
+    assert!(Element::identity().map_to_scalar_field().is_zero());
+    assert_eq!(
+        Element::generator().map_to_scalar_field(),
+        Fr::from_str(
+            "9126587937592991869275727798163013998085002024340661226695318996315065477073"
+        )
+        .unwrap()
+    );
+
+    let mut rng = test_rng();
+    let p: EdwardsAffine = rng.gen();
+    let a = Element::from_edwards(&p).unwrap();
+    let b = Element::from_edwards(&(p + t())).unwrap();
+    assert_eq!(a.map_to_scalar_field(), b.map_to_scalar_field());
+    assert_eq!(
+        Element(a.0.double() - a.0).map_to_scalar_field(),
+        a.map_to_scalar_field()
+    );
+}
+
+#[test]
+fn test_batch_map_to_scalar_field() {
+    let mut rng = test_rng();
+    let mut elements: Vec<Element> = (0..256).map(|_| rng.gen()).collect();
+    elements[7] = Element::identity();
+    let scalars = Element::batch_map_to_scalar_field(&elements);
+    assert_eq!(scalars.len(), elements.len());
+    for (e, s) in elements.iter().zip(scalars) {
+        assert_eq!(e.map_to_scalar_field(), s);
+    }
+    assert!(Element::batch_map_to_scalar_field(&[]).is_empty());
+}
+
+#[test]
+fn test_msm() {
+    let mut rng = test_rng();
+    for size in [0, 1, 5, 64, 300].iter() {
+        let elements: Vec<Element> = (0..*size)
+            .map(|i| {
+                let p: EdwardsAffine = rng.gen();
+                // mix both representatives
+                let p = if i % 2 == 0 { p } else { p + t() };
+                Element::from_edwards(&p).unwrap()
+            })
+            .collect();
+        let scalars: Vec<Fr> = (0..*size).map(|_| Fr::rand(&mut rng)).collect();
+        let expected: Element =
+            elements.iter().zip(&scalars).map(|(e, k)| *e * *k).sum();
+        assert_eq!(Element::msm(&elements, &scalars), expected);
+    }
+}
+
+#[test]
+fn test_is_valid_banderwagon_encoding() {
+    let mut rng = test_rng();
+    let mut inputs: Vec<[u8; ENCODED_SIZE]> =
+        (0..16).map(|_| rng.gen::<Element>().to_bytes()).collect();
+    inputs.push([0u8; ENCODED_SIZE]);
+    inputs.push([0xff; ENCODED_SIZE]);
+    for x in 1u64..64 {
+        let mut bytes = [0u8; ENCODED_SIZE];
+        bytes[24..].copy_from_slice(&x.to_be_bytes());
+        inputs.push(bytes);
+    }
+    for bytes in inputs.iter() {
+        assert_eq!(
+            is_valid_banderwagon_encoding(bytes),
+            Element::from_bytes(bytes).is_ok()
+        );
+    }
+}
+
+#[cfg(feature = "verkle-crs")]
+#[test]
+fn test_verkle_crs() {
+    use sha2::{Digest, Sha256};
+
+    let crs = verkle_crs();
+    assert_eq!(crs.len(), VERKLE_CRS_SIZE);
+    assert_eq!(
+        crs[0].to_bytes(),
+        from_hex(
+            "01587ad1336675eb912550ec2a28eb8923b824b490dd2ba82e48f14590a298a0"
+        )
+    );
+    assert_eq!(
+        crs[255].to_bytes(),
+        from_hex(
+            "3de2be346b539395b0c0de56a5ccca54a317f1b5c80107b0802af9a62276a4d8"
+        )
+    );
+    let mut hasher = Sha256::new();
+    for point in crs {
+        hasher.update(point.to_bytes());
+    }
+    assert_eq!(
+        hasher.finalize()[..],
+        from_hex(
+            "1fcaea10bf24f750200e06fa473c76ff0468007291fa548e2d99f09ba9256fdb"
+        )
+    );
+
+    // derive the points from the seed
+    let derived: Vec<Element> = (0u64..)
+        .filter_map(|i| {
+            let digest = Sha256::new()
+                .chain_update(VERKLE_CRS_SEED)
+                .chain_update(i.to_be_bytes())
+                .finalize();
+            let x = Fq::from_be_bytes_mod_order(&digest);
+            let mut bytes = [0u8; ENCODED_SIZE];
+            bytes.copy_from_slice(&x.into_repr().to_bytes_be());
+            Element::from_bytes(&bytes).ok()
+        })
+        .take(VERKLE_CRS_SIZE)
+        .collect();
+    assert_eq!(derived, crs);
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn test_reference_ops() {
+    let mut rng = test_rng();
+    let a: Element = rng.gen();
+    let b: Element = rng.gen();
+    let k = Fr::rand(&mut rng);
+
+    assert_eq!(&a + &b, a + b);
+    assert_eq!(a + &b, a + b);
+    assert_eq!(&a - &b, a - b);
+    assert_eq!(a - &b, a - b);
+    assert_eq!(-&a, -a);
+    assert_eq!(&a * &k, a * k);
+    assert_eq!(&a * k, a * k);
+    assert_eq!(a * &k, a * k);
+
+    let mut c = a;
+    c += &b;
+    c -= &a;
+    assert_eq!(c, b);
+    c *= k;
+    assert_eq!(c, b * k);
+    c *= &k;
+    assert_eq!(c, b * (k * k));
+
+    let elements = [a, b, c];
+    assert_eq!(elements.iter().sum::<Element>(), a + b + c);
+}
+
+#[test]
+fn test_rand() {
+    let mut rng = test_rng();
+    let elements: Vec<Element> =
+        (0..64).map(|_| Element::rand(&mut rng)).collect();
+    for (i, a) in elements.iter().enumerate() {
+        assert!(!a.is_identity());
+        assert!(crate::is_torsion_free(&a.to_edwards().into_affine()));
+        assert!(elements[..i].iter().all(|b| b != a));
+    }
+
+    // the parity of the encoded x coordinate is roughly balanced
+    let odd = (0..1024)
+        .filter(|_| rng.gen::<Element>().to_bytes()[ENCODED_SIZE - 1] & 1 == 1)
+        .count();
+    assert!((412..612).contains(&odd));
+}
+
+#[test]
+fn test_hex() {
+    let g =
+        "0x4a2c7486fd924882bf02c6908de395122843e3e05264d7991e18e7985dad51e9";
+    assert_eq!(Element::generator().to_string(), g);
+    assert_eq!(format!("{:x}", Element::generator()), g[2..]);
+    assert_eq!(format!("{:#x}", Element::generator()), g);
+    assert_eq!(g.parse::<Element>(), Ok(Element::generator()));
+    assert_eq!(g[2..].parse::<Element>(), Ok(Element::generator()));
+
+    let mut rng = test_rng();
+    let a: Element = rng.gen();
+    assert_eq!(a.to_string().parse::<Element>(), Ok(a));
+    assert_eq!("0x4a2c".parse::<Element>(), Err(HexError::InvalidLength));
+    assert_eq!(
+        "0x0000000000000000000000000000000000000000000000000000000000000002"
+            .parse::<Element>(),
+        Err(HexError::InvalidEncoding)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let mut rng = test_rng();
+    let a: Element = rng.gen();
+
+    let json = serde_json::to_string(&a).unwrap();
+    assert_eq!(json.len(), 2 * ENCODED_SIZE + 2);
+    assert_eq!(serde_json::from_str::<Element>(&json).unwrap(), a);
+    let g =
+        "\"4a2c7486fd924882bf02c6908de395122843e3e05264d7991e18e7985dad51e9\"";
+    assert_eq!(serde_json::to_string(&Element::generator()).unwrap(), g);
+    assert_eq!(
+        serde_json::from_str::<Element>(&g.to_uppercase()).unwrap(),
+        Element::generator()
+    );
+
+    let cbor = serde_cbor::to_vec(&a).unwrap();
+    assert_eq!(serde_cbor::from_slice::<Element>(&cbor).unwrap(), a);
+
+    // wrong length, invalid digit, non-canonical, off the curve and outside
+    // of the subgroup
+    for invalid in [
+        "\"4a2c\"",
+        "\"+a2c7486fd924882bf02c6908de395122843e3e05264d7991e18e7985dad51e9\"",
+        "\"73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001\"",
+        "\"0000000000000000000000000000000000000000000000000000000000000002\"",
+        "\"0000000000000000000000000000000000000000000000000000000000000007\"",
+    ]
+    .iter()
+    {
+        assert!(serde_json::from_str::<Element>(invalid).is_err());
+    }
+}
+
+#[test]
+fn test_uniform_bytes() {
+    let mut rng = test_rng();
+    let elements = [
+        Element::identity(),
+        Element::generator(),
+        Element::from_edwards(
+            &(Element::generator().to_edwards().into_affine() + t()),
+        )
+        .unwrap(),
+        rng.gen(),
+        rng.gen(),
+    ];
+    for e in elements.iter() {
+        let bytes = e.to_uniform_bytes(&mut rng);
+        assert_eq!(Element::from_uniform_bytes(&bytes), *e);
+        // the encoding is randomized
+        assert_ne!(e.to_uniform_bytes(&mut rng)[..], bytes[..]);
+    }
+    // any bytes decode to an element
+    for _ in 0..10 {
+        let mut bytes = [0u8; crate::hash_to_curve::ELLIGATOR_SQUARED_SIZE];
+        bytes.iter_mut().for_each(|b| *b = rng.gen());
+        let e = Element::from_uniform_bytes(&bytes);
+        assert_eq!(Element::from_bytes(&e.to_bytes()), Ok(e));
+    }
+}
+
+#[test]
+fn test_batch_serialization() {
+    let mut rng = test_rng();
+    let elements: Vec<Element> = (0..16).map(|_| rng.gen()).collect();
+    let bytes = Element::serialize_batch(&elements);
+    assert_eq!(bytes.len(), 4 + ENCODED_SIZE * elements.len());
+    assert_eq!(Element::deserialize_batch(&bytes), Ok(elements));
+
+    let mut invalid = bytes.clone();
+    invalid[4 + ENCODED_SIZE * 3..4 + ENCODED_SIZE * 4].fill(0xff);
+    assert_eq!(
+        Element::deserialize_batch(&invalid),
+        Err(BatchDecodingError::InvalidPoint {
+            index: 3,
+            error: Element::from_bytes(&[0xff; ENCODED_SIZE]).unwrap_err(),
+        })
+    );
+    assert_eq!(
+        Element::deserialize_batch(&bytes[1..]),
+        Err(BatchDecodingError::InvalidLength)
+    );
+    assert_eq!(
+        Element::deserialize_batch(&Element::serialize_batch(&[])),
+        Ok(Vec::new())
+    );
+}
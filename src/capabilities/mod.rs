@@ -0,0 +1,108 @@
+//! A report of the code paths compiled into the library and active at
+//! runtime, to be included in bug reports and logs.
+
+use crate::generator_table_is_initialized;
+use ark_std::fmt;
+
+#[cfg(test)]
+mod tests;
+
+/// The features compiled into the library and the code paths active at
+/// runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The `std` feature is enabled.
+    pub std: bool,
+    /// The `asm` feature is enabled on an x86-64 target. This is only the
+    /// feature flag: ark-ff only uses its assembly when it is also built
+    /// with a nightly toolchain for a target with the `bmi2` and `adx`
+    /// features, which cannot be detected here.
+    pub asm_feature: bool,
+    /// The MSMs and batch operations run on the rayon thread pool (the
+    /// `parallel` feature).
+    pub parallel: bool,
+    /// The number of threads of the rayon thread pool, 1 without the
+    /// `parallel` feature.
+    pub num_threads: usize,
+    /// The SHAKE128 expander is available (the `sha3` feature).
+    pub sha3: bool,
+    /// The BLAKE3 expander is available (the `blake3` feature).
+    pub blake3: bool,
+    /// Secrets are erased once used and on drop (the `zeroize` feature).
+    pub zeroize: bool,
+    /// The `serde` feature is enabled.
+    pub serde: bool,
+    /// The `ssz` feature is enabled.
+    pub ssz: bool,
+    /// The C bindings are compiled in (the `ffi` feature).
+    pub ffi: bool,
+    /// The WebAssembly bindings are compiled in (the `wasm` feature).
+    pub wasm: bool,
+    /// The Python bindings are compiled in (the `python` feature).
+    pub python: bool,
+    /// The Verkle CRS is available (the `verkle-crs` feature).
+    pub verkle_crs: bool,
+    /// The comb table of the generator has been built, so that
+    /// multiplications by the generator use it without building it first.
+    pub generator_table: bool,
+}
+
+/// Report the features compiled into the library and the code paths that
+/// are active at the time of the call.
+pub fn capabilities() -> Capabilities {
+    Capabilities {
+        std: cfg!(feature = "std"),
+        asm_feature: cfg!(all(target_arch = "x86_64", feature = "asm")),
+        parallel: cfg!(feature = "parallel"),
+        num_threads: num_threads(),
+        sha3: cfg!(feature = "sha3"),
+        blake3: cfg!(feature = "blake3"),
+        zeroize: cfg!(feature = "zeroize"),
+        serde: cfg!(feature = "serde"),
+        ssz: cfg!(feature = "ssz"),
+        ffi: cfg!(feature = "ffi"),
+        wasm: cfg!(feature = "wasm"),
+        python: cfg!(feature = "python"),
+        verkle_crs: cfg!(feature = "verkle-crs"),
+        generator_table: generator_table_is_initialized(),
+    }
+}
+
+#[cfg(feature = "parallel")]
+fn num_threads() -> usize {
+    rayon::current_num_threads()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn num_threads() -> usize {
+    1
+}
+
+impl fmt::Display for Capabilities {
+    /// A single line such as
+    /// `bandersnatch 0.1.0: std=yes asm_feature=no parallel=yes(8) ...`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let yes_no = |b: bool| if b { "yes" } else { "no" };
+        write!(
+            f,
+            "bandersnatch {}: std={} asm_feature={} parallel={}({}) sha3={} \
+             blake3={} zeroize={} serde={} ssz={} ffi={} wasm={} python={} \
+             verkle_crs={} generator_table={}",
+            env!("CARGO_PKG_VERSION"),
+            yes_no(self.std),
+            yes_no(self.asm_feature),
+            yes_no(self.parallel),
+            self.num_threads,
+            yes_no(self.sha3),
+            yes_no(self.blake3),
+            yes_no(self.zeroize),
+            yes_no(self.serde),
+            yes_no(self.ssz),
+            yes_no(self.ffi),
+            yes_no(self.wasm),
+            yes_no(self.python),
+            yes_no(self.verkle_crs),
+            yes_no(self.generator_table),
+        )
+    }
+}
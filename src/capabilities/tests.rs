@@ -0,0 +1,25 @@
+use super::*;
+use crate::generator_table;
+
+#[test]
+fn test_capabilities() {
+    let caps = capabilities();
+    assert_eq!(caps.parallel, cfg!(feature = "parallel"));
+    assert_eq!(caps.sha3, cfg!(feature = "sha3"));
+    assert_eq!(caps.blake3, cfg!(feature = "blake3"));
+    assert_eq!(caps.zeroize, cfg!(feature = "zeroize"));
+    assert_eq!(caps.ffi, cfg!(feature = "ffi"));
+    assert_eq!(caps.verkle_crs, cfg!(feature = "verkle-crs"));
+    assert!(caps.num_threads >= 1);
+    if !caps.parallel {
+        assert_eq!(caps.num_threads, 1);
+    }
+
+    generator_table();
+    assert!(capabilities().generator_table);
+
+    let report = format!("{}", capabilities());
+    assert!(report.starts_with("bandersnatch "));
+    assert!(report.contains("generator_table=yes"));
+    assert!(report.contains(" asm_feature="));
+}
@@ -0,0 +1,201 @@
+//! Commit-then-reveal: a party commits to a value, e.g., a bid or a
+//! lottery ticket, and reveals it later; the reveal is checked against the
+//! commitment and, optionally, against an expiry bound into it.
+//!
+//! Two kinds of commitments are provided:
+//! - [`Commitment`], a SHA-256 hash of the value and a random [`Salt`],
+//!   for arbitrary byte strings;
+//! - [`PedersenCommitment`], `v G + r H`, for values in `Fr`, which is
+//!   additively homomorphic and perfectly hiding; [`pedersen_commit`]
+//!   computes the same commitments in any [`PrimeOrderGroup`].
+//!
+//! The expiry is an opaque `u64`, e.g., a block height or a UNIX time: a
+//! reveal at time `now` is accepted if `now <= expiry`. Since the expiry
+//! is bound into the commitment, it cannot be extended at reveal time.
+
+use crate::{
+    ipa::generate_points, EdwardsAffine, Fr, PrimeOrderGroup, ValidatedPoint,
+};
+use ark_ff::{One, Zero};
+use ark_std::{fmt, rand::Rng, UniformRand};
+use sha2::{Digest, Sha256};
+
+#[cfg(test)]
+mod tests;
+
+/// Domain separation tag of the hash commitments.
+const COMMIT_DST: &[u8] = b"BANDERSNATCH-COMMIT-REVEAL-V01";
+
+/// The seed of the generators `H` and `K` of the Pedersen commitments.
+const PEDERSEN_SEED: &[u8] = b"bandersnatch_commit_reveal_v01";
+
+/// The size of a salt, in bytes.
+pub const SALT_SIZE: usize = 32;
+
+/// Errors that can occur while checking a reveal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RevealError {
+    /// The revealed value, salt or expiry do not match the commitment.
+    InvalidOpening,
+    /// The reveal happens after the expiry.
+    Expired,
+}
+
+impl fmt::Display for RevealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RevealError::InvalidOpening => write!(f, "invalid opening"),
+            RevealError::Expired => write!(f, "commitment expired"),
+        }
+    }
+}
+
+/// The random salt of a hash commitment, which hides the value.
+///
+/// The salt is `Copy`: `Zeroize`, with the `zeroize` feature, only erases
+/// the value it is called on, not the copies made along the way, and
+/// nothing is erased on drop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Salt(pub [u8; SALT_SIZE]);
+
+/// Erase the salt, e.g., once the commitment is revealed or abandoned.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Salt {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
+impl Salt {
+    /// Sample a fresh salt.
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut salt = [0u8; SALT_SIZE];
+        rng.fill_bytes(&mut salt);
+        Self(salt)
+    }
+}
+
+/// A hash commitment
+/// `SHA-256(DST || len(value) || value || salt || expiry)`, where the
+/// expiry is encoded as a zero byte if there is none, and as a one byte
+/// followed by the big-endian `u64` otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Commitment(pub [u8; 32]);
+
+impl Commitment {
+    /// Commit to `value` with `salt`, to be revealed until `expiry`, if
+    /// any.
+    pub fn commit(value: &[u8], salt: &Salt, expiry: Option<u64>) -> Self {
+        let mut hasher = Sha256::new()
+            .chain_update(COMMIT_DST)
+            .chain_update((value.len() as u64).to_be_bytes())
+            .chain_update(value)
+            .chain_update(salt.0);
+        hasher = match expiry {
+            Some(expiry) => {
+                hasher.chain_update([1]).chain_update(expiry.to_be_bytes())
+            }
+            None => hasher.chain_update([0]),
+        };
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&hasher.finalize());
+        Self(digest)
+    }
+
+    /// Check that `value`, `salt` and `expiry` open the commitment, and
+    /// that `now` is not past the expiry.
+    pub fn verify_reveal(
+        &self,
+        value: &[u8],
+        salt: &Salt,
+        expiry: Option<u64>,
+        now: u64,
+    ) -> Result<(), RevealError> {
+        if Self::commit(value, salt, expiry) != *self {
+            return Err(RevealError::InvalidOpening);
+        }
+        check_expiry(expiry, now)
+    }
+}
+
+/// A Pedersen commitment `v G + r H + e K` to a value `v` with the
+/// blinding factor `r`, where `e` is `0` without expiry and `expiry + 1`
+/// otherwise.
+///
+/// `H` and `K` are derived by hashing a seed, so that nobody knows their
+/// discrete logarithms. Commitments without expiry add up to a commitment
+/// to the sum of the values, with the sum of the blinding factors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PedersenCommitment(pub EdwardsAffine);
+
+impl PedersenCommitment {
+    /// Commit to `value` with `blinding`, to be revealed until `expiry`,
+    /// if any.
+    pub fn commit(value: &Fr, blinding: &Fr, expiry: Option<u64>) -> Self {
+        Self(pedersen(value, blinding, expiry).into_affine())
+    }
+
+    /// Commit to `value` with a fresh blinding factor, which is returned
+    /// alongside the commitment.
+    pub fn commit_random<R: Rng + ?Sized>(
+        value: &Fr,
+        expiry: Option<u64>,
+        rng: &mut R,
+    ) -> (Self, Fr) {
+        let blinding = Fr::rand(rng);
+        (Self::commit(value, &blinding, expiry), blinding)
+    }
+
+    /// Check that `value`, `blinding` and `expiry` open the commitment,
+    /// and that `now` is not past the expiry.
+    pub fn verify_reveal(
+        &self,
+        value: &Fr,
+        blinding: &Fr,
+        expiry: Option<u64>,
+        now: u64,
+    ) -> Result<(), RevealError> {
+        if pedersen(value, blinding, expiry).into_affine() != self.0 {
+            return Err(RevealError::InvalidOpening);
+        }
+        check_expiry(expiry, now)
+    }
+}
+
+/// `v G + r H + e K` in bandersnatch.
+fn pedersen(value: &Fr, blinding: &Fr, expiry: Option<u64>) -> ValidatedPoint {
+    let generators = generate_points(2, PEDERSEN_SEED);
+    // the generators are derived with the cofactor cleared
+    let generators = [
+        ValidatedPoint::new_unchecked(generators[0]),
+        ValidatedPoint::new_unchecked(generators[1]),
+    ];
+    pedersen_commit(value, blinding, expiry, &generators)
+}
+
+/// The Pedersen commitment `v G + r H + e K` to `value` with `blinding`
+/// in the group `G`, where `[H, K]` are `generators` and `e` is `0`
+/// without expiry and `expiry + 1` otherwise.
+///
+/// The value and the blinding factor are multiplied in constant time, with
+/// [`PrimeOrderGroup::mul_secret`].
+pub fn pedersen_commit<G: PrimeOrderGroup>(
+    value: &G::Scalar,
+    blinding: &G::Scalar,
+    expiry: Option<u64>,
+    generators: &[G; 2],
+) -> G {
+    let e = expiry.map_or(G::Scalar::zero(), |expiry| {
+        G::Scalar::from(expiry) + G::Scalar::one()
+    });
+    G::generator().mul_secret(value)
+        + generators[0].mul_secret(blinding)
+        + generators[1].mul(&e)
+}
+
+fn check_expiry(expiry: Option<u64>, now: u64) -> Result<(), RevealError> {
+    match expiry {
+        Some(expiry) if now > expiry => Err(RevealError::Expired),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,117 @@
+use super::*;
+use ark_ff::Field;
+use ark_std::{rand::Rng, test_rng};
+
+#[test]
+fn test_hash_commitment() {
+    let mut rng = test_rng();
+    let salt = Salt::generate(&mut rng);
+    let bid = b"100 tokens";
+
+    let commitment = Commitment::commit(bid, &salt, None);
+    assert_eq!(commitment.verify_reveal(bid, &salt, None, u64::MAX), Ok(()));
+    assert_eq!(
+        commitment.verify_reveal(b"101 tokens", &salt, None, 0),
+        Err(RevealError::InvalidOpening)
+    );
+    let other_salt = Salt::generate(&mut rng);
+    assert_eq!(
+        commitment.verify_reveal(bid, &other_salt, None, 0),
+        Err(RevealError::InvalidOpening)
+    );
+
+    // the expiry is bound into the commitment
+    let commitment = Commitment::commit(bid, &salt, Some(1000));
+    assert_eq!(
+        commitment.verify_reveal(bid, &salt, Some(1000), 999),
+        Ok(())
+    );
+    assert_eq!(
+        commitment.verify_reveal(bid, &salt, Some(1000), 1000),
+        Ok(())
+    );
+    assert_eq!(
+        commitment.verify_reveal(bid, &salt, Some(1000), 1001),
+        Err(RevealError::Expired)
+    );
+    assert_eq!(
+        commitment.verify_reveal(bid, &salt, Some(2000), 1001),
+        Err(RevealError::InvalidOpening)
+    );
+    assert_eq!(
+        commitment.verify_reveal(bid, &salt, None, 1001),
+        Err(RevealError::InvalidOpening)
+    );
+    assert_ne!(
+        Commitment::commit(bid, &salt, Some(0)),
+        Commitment::commit(bid, &salt, None)
+    );
+}
+
+#[test]
+fn test_pedersen_commitment() {
+    let mut rng = test_rng();
+    let value = Fr::from(42u64);
+
+    let (commitment, blinding) =
+        PedersenCommitment::commit_random(&value, Some(10), &mut rng);
+    assert_eq!(
+        commitment.verify_reveal(&value, &blinding, Some(10), 10),
+        Ok(())
+    );
+    assert_eq!(
+        commitment.verify_reveal(&value, &blinding, Some(10), 11),
+        Err(RevealError::Expired)
+    );
+    assert_eq!(
+        commitment.verify_reveal(&Fr::from(43u64), &blinding, Some(10), 0),
+        Err(RevealError::InvalidOpening)
+    );
+    assert_eq!(
+        commitment.verify_reveal(&value, &blinding, Some(11), 0),
+        Err(RevealError::InvalidOpening)
+    );
+    assert_ne!(
+        PedersenCommitment::commit(&value, &blinding, Some(0)),
+        PedersenCommitment::commit(&value, &blinding, None)
+    );
+
+    // homomorphic without expiry
+    let (c1, r1) = PedersenCommitment::commit_random(&value, None, &mut rng);
+    let (c2, r2) = PedersenCommitment::commit_random(&value, None, &mut rng);
+    let sum = PedersenCommitment(c1.0 + c2.0);
+    assert_eq!(
+        sum.verify_reveal(&value.double(), &(r1 + r2), None, 0),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_generic_pedersen_commitment() {
+    use crate::banderwagon::Element;
+
+    let mut rng = test_rng();
+    let generators = [rng.gen::<Element>(), rng.gen::<Element>()];
+    let (v1, r1) = (Fr::from(1u64), rng.gen::<Fr>());
+    let (v2, r2) = (Fr::from(2u64), rng.gen::<Fr>());
+    let c1 = pedersen_commit(&v1, &r1, None, &generators);
+    let c2 = pedersen_commit(&v2, &r2, None, &generators);
+    assert_eq!(
+        c1 + c2,
+        pedersen_commit(&(v1 + v2), &(r1 + r2), None, &generators)
+    );
+    assert_ne!(
+        pedersen_commit(&v1, &r1, Some(0), &generators),
+        pedersen_commit(&v1, &r1, None, &generators)
+    );
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize_salt() {
+    use zeroize::Zeroize;
+
+    let mut salt = Salt([0xab; SALT_SIZE]);
+    salt.zeroize();
+    assert_eq!(salt, Salt([0; SALT_SIZE]));
+}
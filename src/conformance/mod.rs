@@ -0,0 +1,268 @@
+//! A suite of algebraic laws that downstream wrappers and bindings can run
+//! against their integration layer, e.g., a type that wraps the points of
+//! bandersnatch behind an FFI or a serialization format of its own.
+//!
+//! An integration implements [`Integration`], which converts between its
+//! points and the ones of this crate and exposes its operations; each
+//! `check_*` function then evaluates a law on `cases` random inputs drawn
+//! from the given RNG, and reports the first input on which it fails. The
+//! inputs are reproducible from the seed of the RNG.
+//!
+//! The suite is behind the `conformance` feature.
+
+use crate::{
+    EdwardsAffine, EdwardsParameters, EdwardsProjective, Fr, GLVParameters,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
+use ark_std::{fmt, rand::Rng, vec::Vec, UniformRand};
+
+#[cfg(test)]
+mod tests;
+
+/// The operations of an integration layer under test.
+pub trait Integration {
+    /// The point type of the integration.
+    type Point: Clone;
+
+    /// Convert a point of this crate to the integration.
+    fn from_native(p: &EdwardsProjective) -> Self::Point;
+
+    /// Convert a point of the integration to this crate.
+    fn to_native(p: &Self::Point) -> EdwardsProjective;
+
+    /// Compute `a + b`.
+    fn add(a: &Self::Point, b: &Self::Point) -> Self::Point;
+
+    /// Compute `-a`.
+    fn neg(a: &Self::Point) -> Self::Point;
+
+    /// Compute `k a`.
+    fn mul(a: &Self::Point, k: &Fr) -> Self::Point;
+
+    /// Serialize a point.
+    fn to_bytes(a: &Self::Point) -> Vec<u8>;
+
+    /// Deserialize a point serialized with [`Integration::to_bytes`].
+    fn from_bytes(bytes: &[u8]) -> Option<Self::Point>;
+}
+
+/// The points of this crate, as a reference integration.
+#[derive(Clone, Copy, Debug)]
+pub struct Native;
+
+impl Integration for Native {
+    type Point = EdwardsProjective;
+
+    fn from_native(p: &EdwardsProjective) -> Self::Point {
+        *p
+    }
+
+    fn to_native(p: &Self::Point) -> EdwardsProjective {
+        *p
+    }
+
+    fn add(a: &Self::Point, b: &Self::Point) -> Self::Point {
+        *a + b
+    }
+
+    fn neg(a: &Self::Point) -> Self::Point {
+        -*a
+    }
+
+    fn mul(a: &Self::Point, k: &Fr) -> Self::Point {
+        a.mul(k.into_repr())
+    }
+
+    fn to_bytes(a: &Self::Point) -> Vec<u8> {
+        crate::to_compressed_bytes(&a.into_affine()).to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self::Point> {
+        let mut array = [0u8; crate::COMPRESSED_SIZE];
+        if bytes.len() != array.len() {
+            return None;
+        }
+        array.copy_from_slice(bytes);
+        crate::from_compressed_bytes(&array).map(|p| p.into_projective())
+    }
+}
+
+/// A law that does not hold, with the index of the first failing case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConformanceFailure {
+    /// The name of the law.
+    pub law: &'static str,
+    /// The index of the random case on which the law fails.
+    pub case: usize,
+}
+
+impl fmt::Display for ConformanceFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "law `{}` fails on case {}", self.law, self.case)
+    }
+}
+
+/// Check that conversions round-trip, and that the operations of the
+/// integration agree with the ones of this crate.
+pub fn check_conversions<I: Integration, R: Rng + ?Sized>(
+    rng: &mut R,
+    cases: usize,
+) -> Result<(), ConformanceFailure> {
+    for case in 0..cases {
+        let a = EdwardsProjective::rand(rng);
+        let b = EdwardsProjective::rand(rng);
+        let k = Fr::rand(rng);
+        let (ia, ib) = (I::from_native(&a), I::from_native(&b));
+        check("conversion round-trip", case, I::to_native(&ia) == a)?;
+        check("addition", case, I::to_native(&I::add(&ia, &ib)) == a + b)?;
+        check("negation", case, I::to_native(&I::neg(&ia)) == -a)?;
+        let expected = a.mul(k.into_repr());
+        check("scalar multiplication", case, {
+            I::to_native(&I::mul(&ia, &k)) == expected
+        })?;
+    }
+    Ok(())
+}
+
+/// Check the group laws: associativity, commutativity, identity and
+/// inverses.
+pub fn check_group_laws<I: Integration, R: Rng + ?Sized>(
+    rng: &mut R,
+    cases: usize,
+) -> Result<(), ConformanceFailure> {
+    let zero = I::from_native(&EdwardsProjective::zero());
+    for case in 0..cases {
+        let a = I::from_native(&EdwardsProjective::rand(rng));
+        let b = I::from_native(&EdwardsProjective::rand(rng));
+        let c = I::from_native(&EdwardsProjective::rand(rng));
+        let eq =
+            |x: &I::Point, y: &I::Point| I::to_native(x) == I::to_native(y);
+        check(
+            "associativity",
+            case,
+            eq(&I::add(&I::add(&a, &b), &c), &I::add(&a, &I::add(&b, &c))),
+        )?;
+        check("commutativity", case, eq(&I::add(&a, &b), &I::add(&b, &a)))?;
+        check("identity", case, eq(&I::add(&a, &zero), &a))?;
+        check("inverse", case, eq(&I::add(&a, &I::neg(&a)), &zero))?;
+        check("doubling", case, {
+            I::to_native(&I::add(&a, &a)) == I::to_native(&a).double()
+        })?;
+    }
+    Ok(())
+}
+
+/// Check that scalar multiplication distributes over the addition of
+/// points and of scalars, and is compatible with the multiplication of
+/// scalars.
+pub fn check_scalar_mul<I: Integration, R: Rng + ?Sized>(
+    rng: &mut R,
+    cases: usize,
+) -> Result<(), ConformanceFailure> {
+    for case in 0..cases {
+        let a = I::from_native(&EdwardsProjective::rand(rng));
+        let b = I::from_native(&EdwardsProjective::rand(rng));
+        let (k, l) = (Fr::rand(rng), Fr::rand(rng));
+        let eq =
+            |x: &I::Point, y: &I::Point| I::to_native(x) == I::to_native(y);
+        check(
+            "distributivity over points",
+            case,
+            eq(
+                &I::mul(&I::add(&a, &b), &k),
+                &I::add(&I::mul(&a, &k), &I::mul(&b, &k)),
+            ),
+        )?;
+        check(
+            "distributivity over scalars",
+            case,
+            eq(
+                &I::mul(&a, &(k + l)),
+                &I::add(&I::mul(&a, &k), &I::mul(&a, &l)),
+            ),
+        )?;
+        check(
+            "compatibility",
+            case,
+            eq(&I::mul(&I::mul(&a, &k), &l), &I::mul(&a, &(k * l))),
+        )?;
+        check("prime order", case, {
+            I::to_native(&I::mul(&a, &k))
+                .into_affine()
+                .is_in_correct_subgroup_assuming_on_curve()
+        })?;
+    }
+    Ok(())
+}
+
+/// Check that serialization round-trips, and that deserialization rejects
+/// truncated inputs.
+pub fn check_serialization<I: Integration, R: Rng + ?Sized>(
+    rng: &mut R,
+    cases: usize,
+) -> Result<(), ConformanceFailure> {
+    for case in 0..cases {
+        let a = EdwardsProjective::rand(rng);
+        let bytes = I::to_bytes(&I::from_native(&a));
+        let decoded = I::from_bytes(&bytes).map(|p| I::to_native(&p));
+        check("serialization round-trip", case, decoded == Some(a))?;
+        check(
+            "truncated input",
+            case,
+            bytes.is_empty() || I::from_bytes(&bytes[1..]).is_none(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Check that the GLV multiplication of this crate agrees with the naive
+/// double-and-add one.
+pub fn check_glv<R: Rng + ?Sized>(
+    rng: &mut R,
+    cases: usize,
+) -> Result<(), ConformanceFailure> {
+    for case in 0..cases {
+        let a = EdwardsAffine::rand(rng);
+        let k = Fr::rand(rng);
+        let glv = EdwardsParameters::glv_mul(&a, &k);
+        check("GLV multiplication", case, glv == naive_mul(&a, &k))?;
+    }
+    Ok(())
+}
+
+/// Run all the checks on `cases` random inputs each.
+pub fn check_all<I: Integration, R: Rng + ?Sized>(
+    rng: &mut R,
+    cases: usize,
+) -> Result<(), ConformanceFailure> {
+    check_conversions::<I, R>(rng, cases)?;
+    check_group_laws::<I, R>(rng, cases)?;
+    check_scalar_mul::<I, R>(rng, cases)?;
+    check_serialization::<I, R>(rng, cases)?;
+    check_glv(rng, cases)
+}
+
+fn check(
+    law: &'static str,
+    case: usize,
+    holds: bool,
+) -> Result<(), ConformanceFailure> {
+    if holds {
+        Ok(())
+    } else {
+        Err(ConformanceFailure { law, case })
+    }
+}
+
+/// Double-and-add, from the most significant bit.
+fn naive_mul(a: &EdwardsAffine, k: &Fr) -> EdwardsProjective {
+    let mut res = EdwardsProjective::zero();
+    for bit in ark_ff::BitIteratorBE::new(k.into_repr()) {
+        res.double_in_place();
+        if bit {
+            res.add_assign_mixed(a);
+        }
+    }
+    res
+}
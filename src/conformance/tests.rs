@@ -0,0 +1,56 @@
+use super::*;
+use ark_std::{string::ToString, test_rng};
+
+#[test]
+fn test_native_conformance() {
+    assert_eq!(check_all::<Native, _>(&mut test_rng(), 8), Ok(()));
+}
+
+/// An integration whose negation is off by the point of order 2.
+struct Broken;
+
+impl Integration for Broken {
+    type Point = EdwardsProjective;
+
+    fn from_native(p: &EdwardsProjective) -> Self::Point {
+        *p
+    }
+
+    fn to_native(p: &Self::Point) -> EdwardsProjective {
+        *p
+    }
+
+    fn add(a: &Self::Point, b: &Self::Point) -> Self::Point {
+        *a + b
+    }
+
+    fn neg(a: &Self::Point) -> Self::Point {
+        let t = EdwardsAffine::new(crate::Fq::zero(), -crate::Fq::from(1u64));
+        -*a + t.into_projective()
+    }
+
+    fn mul(a: &Self::Point, k: &Fr) -> Self::Point {
+        Native::mul(a, k)
+    }
+
+    fn to_bytes(a: &Self::Point) -> Vec<u8> {
+        Native::to_bytes(a)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self::Point> {
+        Native::from_bytes(bytes)
+    }
+}
+
+#[test]
+fn test_broken_integration() {
+    let failure = check_all::<Broken, _>(&mut test_rng(), 8).unwrap_err();
+    assert_eq!(
+        failure,
+        ConformanceFailure {
+            law: "negation",
+            case: 0
+        }
+    );
+    assert_eq!(failure.to_string(), "law `negation` fails on case 0");
+}
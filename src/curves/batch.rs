@@ -0,0 +1,191 @@
+use crate::{
+    batch_inverse, EdwardsAffine, EdwardsParameters, EdwardsProjective, Fq, Fr,
+    GLVParameters,
+};
+use ark_ec::{AffineCurve, TEModelParameters};
+use ark_ff::{Field, One, Zero};
+use ark_std::{cfg_iter, vec::Vec};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Compute `scalars[i] * bases[i]` for every `i` with GLV multiplication.
+///
+/// If the two slices have different lengths, the longer one is truncated.
+/// With the `parallel` feature, the products are computed by the rayon
+/// thread pool; the output order always matches the input order.
+pub fn batch_glv_mul(
+    bases: &[EdwardsAffine],
+    scalars: &[Fr],
+) -> Vec<EdwardsProjective> {
+    let mut out = Vec::new();
+    batch_glv_mul_into(bases, scalars, &mut out);
+    out
+}
+
+/// Same as [`batch_glv_mul`], writing the products to `out`.
+pub(crate) fn batch_glv_mul_into(
+    bases: &[EdwardsAffine],
+    scalars: &[Fr],
+    out: &mut Vec<EdwardsProjective>,
+) {
+    out.clear();
+    let products = cfg_iter!(bases)
+        .zip(scalars)
+        .map(|(base, scalar)| EdwardsParameters::glv_mul(base, scalar));
+    #[cfg(feature = "parallel")]
+    out.par_extend(products);
+    #[cfg(not(feature = "parallel"))]
+    out.extend(products);
+}
+
+/// Convert projective points to affine form, sharing a single field
+/// inversion among all of them (Montgomery's trick).
+pub fn batch_normalize(points: &[EdwardsProjective]) -> Vec<EdwardsAffine> {
+    let mut out = Vec::new();
+    batch_normalize_into(points, &mut Vec::new(), &mut out);
+    out
+}
+
+/// Same as [`batch_normalize`], with the inverses of the `z` coordinates
+/// stored in `z_inv`, and the affine points written to `out`.
+pub(crate) fn batch_normalize_into(
+    points: &[EdwardsProjective],
+    z_inv: &mut Vec<Fq>,
+    out: &mut Vec<EdwardsAffine>,
+) {
+    z_inv.clear();
+    z_inv.extend(points.iter().map(|p| p.z));
+    batch_inverse(z_inv);
+    out.clear();
+    let affine = cfg_iter!(points)
+        .zip(&z_inv[..])
+        .map(|(p, z_inv)| EdwardsAffine::new(p.x * z_inv, p.y * z_inv));
+    #[cfg(feature = "parallel")]
+    out.par_extend(affine);
+    #[cfg(not(feature = "parallel"))]
+    out.extend(affine);
+}
+
+/// Compute `p + q` in affine coordinates with the affine formulas
+/// `x3 = (x1 y2 + y1 x2) / (1 + d x1 x2 y1 y2)` and
+/// `y3 = (y1 y2 - a x1 x2) / (1 - d x1 x2 y1 y2)`, and a single field
+/// inversion.
+///
+/// To share the inversion among many additions, invert the
+/// [`add_affine_denominator`]s in a batch, e.g., with
+/// [`batch_inverse`](crate::batch_inverse), and call [`add_affine_with_inverse`], as
+/// [`batch_add_affine`] does.
+pub fn add_affine(p: &EdwardsAffine, q: &EdwardsAffine) -> EdwardsAffine {
+    match add_affine_denominator(p, q).inverse() {
+        Some(inv) => add_affine_with_inverse(p, q, &inv),
+        None => *p + *q,
+    }
+}
+
+/// The product `(1 + d x1 x2 y1 y2) (1 - d x1 x2 y1 y2)` of the two
+/// denominators of the affine addition of `p` and `q`.
+///
+/// It only vanishes for pairs of points outside of the prime order
+/// subgroup, whose sum must be computed in projective coordinates.
+pub fn add_affine_denominator(p: &EdwardsAffine, q: &EdwardsAffine) -> Fq {
+    let t = EdwardsParameters::COEFF_D * p.x * q.x * p.y * q.y;
+    Fq::one() - t.square()
+}
+
+/// Compute `p + q` given `inv`, the inverse of
+/// [`add_affine_denominator`]`(p, q)`, in about 10 multiplications.
+pub fn add_affine_with_inverse(
+    p: &EdwardsAffine,
+    q: &EdwardsAffine,
+    inv: &Fq,
+) -> EdwardsAffine {
+    let xx = p.x * q.x;
+    let yy = p.y * q.y;
+    let t = EdwardsParameters::COEFF_D * xx * yy;
+    let x = (p.x * q.y + p.y * q.x) * (Fq::one() - t) * inv;
+    let y = (yy - EdwardsParameters::mul_by_a(&xx)) * (Fq::one() + t) * inv;
+    EdwardsAffine::new(x, y)
+}
+
+/// Compute `lhs[i] + rhs[i]` for every `i`, in affine coordinates, sharing
+/// a single field inversion among all the additions.
+///
+/// Each addition is an [`add_affine_with_inverse`], which costs about 10
+/// multiplications once the inversion is amortized. The rare pairs for
+/// which a denominator vanishes (possible only outside the prime order
+/// subgroup) are added separately.
+///
+/// If the two slices have different lengths, the longer one is truncated.
+pub fn batch_add_affine(
+    lhs: &[EdwardsAffine],
+    rhs: &[EdwardsAffine],
+) -> Vec<EdwardsAffine> {
+    let mut denominators: Vec<Fq> = lhs
+        .iter()
+        .zip(rhs)
+        .map(|(p, q)| add_affine_denominator(p, q))
+        .collect();
+    batch_inverse(&mut denominators);
+
+    lhs.iter()
+        .zip(rhs)
+        .zip(&denominators)
+        .map(|((p, q), inv)| {
+            if inv.is_zero() {
+                return *p + *q;
+            }
+            add_affine_with_inverse(p, q, inv)
+        })
+        .collect()
+}
+
+/// Compute the sum of the `bases[i]` for which `bits[i]` is set, i.e., an
+/// MSM with scalars 0 and 1.
+///
+/// The selected points are summed pairwise, level by level, with
+/// [`batch_add_affine`], so that each level costs a single inversion. This
+/// is much cheaper than a Pippenger MSM, whose windows are all empty but
+/// the lowest one. If the two slices have different lengths, the longer
+/// one is truncated.
+pub fn sum_where(bases: &[EdwardsAffine], bits: &[bool]) -> EdwardsProjective {
+    let selected = bases
+        .iter()
+        .zip(bits)
+        .filter(|(_, bit)| **bit)
+        .map(|(base, _)| *base)
+        .collect();
+    sum_affine(selected)
+}
+
+/// Compute `sum_i (-1)^negative[i] bases[i]`, i.e., an MSM with scalars
+/// 1 and -1, as in [`sum_where`].
+///
+/// If the two slices have different lengths, the longer one is truncated.
+pub fn sum_signed(
+    bases: &[EdwardsAffine],
+    negative: &[bool],
+) -> EdwardsProjective {
+    let signed = bases
+        .iter()
+        .zip(negative)
+        .map(|(base, negative)| if *negative { -*base } else { *base })
+        .collect();
+    sum_affine(signed)
+}
+
+/// Sum the points with a tree of batched affine additions.
+fn sum_affine(mut points: Vec<EdwardsAffine>) -> EdwardsProjective {
+    while points.len() > 1 {
+        let half = points.len() / 2;
+        let mut sums =
+            batch_add_affine(&points[..half], &points[half..2 * half]);
+        if points.len() % 2 == 1 {
+            sums.push(points[2 * half]);
+        }
+        points = sums;
+    }
+    points
+        .first()
+        .map_or(EdwardsProjective::zero(), |p| p.into_projective())
+}
@@ -0,0 +1,137 @@
+//! Densely packed encodings of lists of points, e.g., for CRS files and
+//! proof transcripts: the number of points (`u32`, little-endian) followed
+//! by their 32-byte compressed encodings, see [`to_compressed_bytes`].
+//!
+//! Decoding validates every point, sharing the work among them: a single
+//! field inversion for the `x` coordinates, the square roots with
+//! [`batch_sqrt`], and the subgroup checks with
+//! [`batch_legendre`](crate::batch_legendre).
+
+use super::torsion::batch_is_torsion_free;
+use crate::{
+    batch_inverse, batch_sqrt, fq_from_le_bytes, to_compressed_bytes,
+    EdwardsAffine, EdwardsParameters, Fq, PointError, COMPRESSED_SIZE,
+};
+use ark_ec::models::TEModelParameters;
+use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
+use ark_std::{convert::TryInto, fmt, vec::Vec};
+
+/// An error while decoding a list of points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchDecodingError {
+    /// The bytes are not a length prefix followed by as many encodings.
+    InvalidLength,
+    /// The point at `index` is invalid.
+    InvalidPoint {
+        /// The index of the first invalid point.
+        index: usize,
+        /// Why it is invalid.
+        error: PointError,
+    },
+}
+
+impl fmt::Display for BatchDecodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchDecodingError::InvalidLength => {
+                write!(f, "the length prefix does not match the encoding")
+            }
+            BatchDecodingError::InvalidPoint { index, error } => {
+                write!(f, "point {}: {}", index, error)
+            }
+        }
+    }
+}
+
+/// Encode `points` as their number followed by their compressed
+/// encodings.
+pub fn serialize_batch(points: &[EdwardsAffine]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(4 + COMPRESSED_SIZE * points.len());
+    bytes.extend_from_slice(&(points.len() as u32).to_le_bytes());
+    for p in points {
+        bytes.extend_from_slice(&to_compressed_bytes(p));
+    }
+    bytes
+}
+
+/// Decode points encoded with [`serialize_batch`], checking that each
+/// one is canonically encoded, on the curve and in the prime order
+/// subgroup, as
+/// [`ValidatedPoint::from_compressed_bytes`](crate::ValidatedPoint::from_compressed_bytes)
+/// does, and that there are no trailing bytes.
+pub fn deserialize_batch(
+    bytes: &[u8],
+) -> Result<Vec<EdwardsAffine>, BatchDecodingError> {
+    let encodings = split_batch(bytes)?;
+    let invalid =
+        |index, error| BatchDecodingError::InvalidPoint { index, error };
+
+    // y, and the parity of x
+    let mut ys = Vec::with_capacity(encodings.len());
+    for (index, encoding) in encodings.iter().enumerate() {
+        let odd = encoding[COMPRESSED_SIZE - 1] >> 7 == 1;
+        let mut y = **encoding;
+        y[COMPRESSED_SIZE - 1] &= 0x7f;
+        let y = fq_from_le_bytes(&y)
+            .ok_or(invalid(index, PointError::NonCanonicalEncoding))?;
+        ys.push((y, odd));
+    }
+
+    // x^2 = (1 - y^2) / (a - d y^2)
+    let mut den_invs: Vec<Fq> = ys
+        .iter()
+        .map(|(y, _)| {
+            EdwardsParameters::COEFF_A - EdwardsParameters::COEFF_D * y.square()
+        })
+        .collect();
+    if let Some(index) = den_invs.iter().position(Zero::is_zero) {
+        return Err(invalid(index, PointError::NotOnCurve));
+    }
+    batch_inverse(&mut den_invs);
+    let x_squares: Vec<Fq> = ys
+        .iter()
+        .zip(&den_invs)
+        .map(|((y, _), den_inv)| (Fq::one() - y.square()) * den_inv)
+        .collect();
+
+    let mut points = Vec::with_capacity(ys.len());
+    for (index, ((y, odd), root)) in
+        ys.into_iter().zip(batch_sqrt(&x_squares)).enumerate()
+    {
+        let root = root.ok_or(invalid(index, PointError::NotOnCurve))?;
+        if root.is_zero() && odd {
+            return Err(invalid(index, PointError::NonCanonicalEncoding));
+        }
+        let x = if root.into_repr().is_odd() == odd {
+            root
+        } else {
+            -root
+        };
+        points.push(EdwardsAffine::new(x, y));
+    }
+
+    if let Some(index) =
+        batch_is_torsion_free(&points).iter().position(|ok| !ok)
+    {
+        return Err(invalid(index, PointError::NotInPrimeSubgroup));
+    }
+    Ok(points)
+}
+
+/// Split a length-prefixed list into its encodings.
+pub(crate) fn split_batch(
+    bytes: &[u8],
+) -> Result<Vec<&[u8; COMPRESSED_SIZE]>, BatchDecodingError> {
+    if bytes.len() < 4 {
+        return Err(BatchDecodingError::InvalidLength);
+    }
+    let (len, encodings) = bytes.split_at(4);
+    let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+    if len.checked_mul(COMPRESSED_SIZE) != Some(encodings.len()) {
+        return Err(BatchDecodingError::InvalidLength);
+    }
+    Ok(encodings
+        .chunks(COMPRESSED_SIZE)
+        .map(|chunk| chunk.try_into().unwrap())
+        .collect())
+}
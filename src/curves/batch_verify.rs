@@ -0,0 +1,91 @@
+use crate::{msm, EdwardsAffine, Fr};
+use ark_ff::{One, Zero};
+use ark_std::{rand::Rng, vec::Vec};
+
+/// Verify many claimed relations `sum_i c_i P_i == Q` at once: each claim
+/// is weighted by a random 128-bit scalar `w`, and the single MSM
+/// `sum_claims w (sum_i c_i P_i - Q)` is checked to be zero.
+///
+/// If one of the claims is false, the batch is accepted with probability
+/// at most `2^-128`, provided all the points are in the prime order
+/// subgroup. Outside of it, a claim that only fails by a point of small
+/// order can be accepted; callers that need to reject those must check
+/// subgroup membership first.
+#[derive(Clone, Debug, Default)]
+pub struct BatchVerifier {
+    bases: Vec<EdwardsAffine>,
+    scalars: Vec<Fr>,
+    /// The end of the terms of each claim in `bases`, and its right-hand
+    /// side.
+    claims: Vec<(usize, EdwardsAffine)>,
+}
+
+impl BatchVerifier {
+    /// An empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue the claim `sum_i scalars[i] bases[i] == rhs`. If the two
+    /// slices have different lengths, the longer one is truncated.
+    pub fn queue(
+        &mut self,
+        bases: &[EdwardsAffine],
+        scalars: &[Fr],
+        rhs: EdwardsAffine,
+    ) {
+        self.queue_iter(bases.iter().copied().zip(scalars.iter().copied()), rhs)
+    }
+
+    /// Queue the claim `sum_i s_i P_i == rhs` over the terms `(P_i, s_i)`.
+    pub fn queue_iter<I>(&mut self, terms: I, rhs: EdwardsAffine)
+    where
+        I: IntoIterator<Item = (EdwardsAffine, Fr)>,
+    {
+        for (base, scalar) in terms {
+            self.bases.push(base);
+            self.scalars.push(scalar);
+        }
+        self.claims.push((self.bases.len(), rhs));
+    }
+
+    /// The number of queued claims.
+    pub fn len(&self) -> usize {
+        self.claims.len()
+    }
+
+    /// Whether no claim was queued.
+    pub fn is_empty(&self) -> bool {
+        self.claims.is_empty()
+    }
+
+    /// Check all the queued claims with a single MSM; an empty batch is
+    /// accepted.
+    pub fn verify<R: Rng + ?Sized>(self, rng: &mut R) -> bool {
+        let Self {
+            mut bases,
+            mut scalars,
+            claims,
+        } = self;
+        if claims.len() == 1 {
+            // a single claim needs no randomization
+            bases.push(-claims[0].1);
+            scalars.push(Fr::one());
+            return msm(&bases, &scalars).is_zero();
+        }
+
+        bases.reserve(claims.len());
+        scalars.reserve(claims.len());
+        let mut start = 0;
+        for &(end, rhs) in &claims {
+            let weight = Fr::from(rng.gen::<u128>());
+            for scalar in &mut scalars[start..end] {
+                *scalar *= weight;
+            }
+            bases.push(rhs);
+            scalars.push(-weight);
+            start = end;
+        }
+        msm(&bases, &scalars).is_zero()
+    }
+}
@@ -0,0 +1,153 @@
+//! Canonical encodings of the points of bandersnatch, defined here rather
+//! than delegated to the arkworks serializer, so that they do not change
+//! across arkworks versions.
+//!
+//! The 32-byte compressed encoding follows the convention of RFC 8032:
+//! the affine coordinate `y` in little-endian, with the most significant
+//! bit of the last byte, which is always zero since `q < 2^255`, set to
+//! the parity of `x`, i.e., `x mod 2` for the canonical representative of
+//! `x` in `[0, q)`.
+//!
+//! The 64-byte uncompressed encoding is `x || y`, with each coordinate in
+//! little- or big-endian, see [`Endianness`]; big-endian is the usual
+//! choice of EVM and Go implementations.
+
+use crate::{
+    sqrt_with_tables, EdwardsAffine, EdwardsParameters, FieldBytes, Fq,
+};
+use ark_ec::models::TEModelParameters;
+use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
+
+/// Reconstruct a point from one of its coordinates and the parity of the
+/// other, by solving the curve equation.
+pub trait PointFromCoordinate: Sized {
+    /// The point with this `y` coordinate and an `x` coordinate of parity
+    /// `x_is_odd`, or `None` if there is none.
+    fn from_y_with_sign(y: Fq, x_is_odd: bool) -> Option<Self>;
+
+    /// The point with this `x` coordinate and a `y` coordinate of parity
+    /// `y_is_odd`, or `None` if there is none.
+    fn from_x_with_sign(x: Fq, y_is_odd: bool) -> Option<Self>;
+}
+
+impl PointFromCoordinate for EdwardsAffine {
+    fn from_y_with_sign(y: Fq, x_is_odd: bool) -> Option<Self> {
+        // x^2 = (1 - y^2) / (a - d y^2)
+        let y2 = y.square();
+        let den = EdwardsParameters::COEFF_A - EdwardsParameters::COEFF_D * y2;
+        let x = root_with_parity((Fq::one() - y2) * den.inverse()?, x_is_odd)?;
+        Some(Self::new(x, y))
+    }
+
+    fn from_x_with_sign(x: Fq, y_is_odd: bool) -> Option<Self> {
+        // y^2 = (1 - a x^2) / (1 - d x^2)
+        let x2 = x.square();
+        let num = Fq::one() - EdwardsParameters::mul_by_a(&x2);
+        let den = Fq::one() - EdwardsParameters::COEFF_D * x2;
+        let y = root_with_parity(num * den.inverse()?, y_is_odd)?;
+        Some(Self::new(x, y))
+    }
+}
+
+/// The square root of `square` with the given parity, or `None` if there
+/// is none, including if `square = 0` and `odd` is set.
+fn root_with_parity(square: Fq, odd: bool) -> Option<Fq> {
+    let root = sqrt_with_tables(&square)?;
+    if root.is_zero() && odd {
+        return None;
+    }
+    if root.into_repr().is_odd() != odd {
+        Some(-root)
+    } else {
+        Some(root)
+    }
+}
+
+/// The size of a compressed point, in bytes.
+pub const COMPRESSED_SIZE: usize = 32;
+
+/// The size of an uncompressed point, in bytes.
+pub const UNCOMPRESSED_SIZE: usize = 64;
+
+/// The byte order of the coordinates of an uncompressed point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+/// Encode a point as its `y` coordinate and the parity of its `x`
+/// coordinate.
+pub fn to_compressed_bytes(p: &EdwardsAffine) -> [u8; COMPRESSED_SIZE] {
+    let mut bytes = [0u8; COMPRESSED_SIZE];
+    bytes.copy_from_slice(&p.y.into_repr().to_bytes_le());
+    if p.x.into_repr().is_odd() {
+        bytes[COMPRESSED_SIZE - 1] |= 0x80;
+    }
+    bytes
+}
+
+/// Decode a point encoded with [`to_compressed_bytes`].
+///
+/// Returns `None` if `y` is not canonical, i.e., not smaller than `q`, or
+/// if [`PointFromCoordinate::from_y_with_sign`] finds no point. Subgroup
+/// membership is not checked.
+pub fn from_compressed_bytes(
+    bytes: &[u8; COMPRESSED_SIZE],
+) -> Option<EdwardsAffine> {
+    let odd = bytes[COMPRESSED_SIZE - 1] >> 7 == 1;
+    let mut y = *bytes;
+    y[COMPRESSED_SIZE - 1] &= 0x7f;
+    let y = fq_from_le_bytes(&y)?;
+
+    EdwardsAffine::from_y_with_sign(y, odd)
+}
+
+/// Encode a point as `x || y`, each coordinate in `endianness` order.
+pub fn to_bytes_uncompressed(
+    p: &EdwardsAffine,
+    endianness: Endianness,
+) -> [u8; UNCOMPRESSED_SIZE] {
+    let mut bytes = [0u8; UNCOMPRESSED_SIZE];
+    let (x, y) = bytes.split_at_mut(32);
+    x.copy_from_slice(&p.x.into_repr().to_bytes_le());
+    y.copy_from_slice(&p.y.into_repr().to_bytes_le());
+    if endianness == Endianness::Big {
+        x.reverse();
+        y.reverse();
+    }
+    bytes
+}
+
+/// Decode a point encoded with [`to_bytes_uncompressed`] in the same
+/// byte order.
+///
+/// Returns `None` if a coordinate is not canonical, i.e., not smaller than
+/// `q`, or if the point is not on the curve. Subgroup membership is not
+/// checked.
+pub fn from_bytes_uncompressed(
+    bytes: &[u8; UNCOMPRESSED_SIZE],
+    endianness: Endianness,
+) -> Option<EdwardsAffine> {
+    let mut x = [0u8; 32];
+    let mut y = [0u8; 32];
+    x.copy_from_slice(&bytes[..32]);
+    y.copy_from_slice(&bytes[32..]);
+    if endianness == Endianness::Big {
+        x.reverse();
+        y.reverse();
+    }
+    let p = EdwardsAffine::new(fq_from_le_bytes(&x)?, fq_from_le_bytes(&y)?);
+    if p.is_on_curve() {
+        Some(p)
+    } else {
+        None
+    }
+}
+
+/// Read a canonical little-endian field element.
+pub(crate) fn fq_from_le_bytes(bytes: &[u8; 32]) -> Option<Fq> {
+    Fq::from_bytes_le(bytes)
+}
@@ -0,0 +1,250 @@
+//! Point arithmetic in extended coordinates `(X, Y, T, Z)`, with
+//! `x = X / Z`, `y = Y / Z` and `T = X Y / Z`, specialized to the
+//! constants of bandersnatch.
+//!
+//! The generic arkworks formulas recompute `d T` of the second operand at
+//! every addition, and `d x y` at every mixed addition. A point that is
+//! added many times, e.g., an entry of a precomputed table, can instead be
+//! converted once into a [`CachedPoint`] or [`CachedAffine`], which carry
+//! `X + Y` and `d T`: an addition then costs 8 multiplications, and a
+//! mixed addition 7, instead of 9 and 9. The multiplication by `a = -5` is
+//! replaced by additions.
+//!
+//! Doubling does not use its input `T`, so [`double_n`] only computes it
+//! after the last of a chain of doublings, saving a multiplication per
+//! intermediate doubling.
+//!
+//! Since `d / a` is a square, the curve is not complete: it has two points
+//! at infinity of order 2, and the unified formulas behind `+` fail on the
+//! pairs of points whose difference is one of them, returning `Z = 0`.
+//! [`add_complete`] falls back to the dedicated formulas on those inputs.
+
+use crate::{
+    conditional_assign, ct_eq, EdwardsAffine, EdwardsParameters,
+    EdwardsProjective, Fq,
+};
+use ark_ec::models::TEModelParameters;
+use ark_ff::{Field, Zero};
+use ark_std::ops::{AddAssign, Neg, SubAssign};
+
+/// A point prepared for repeated additions, `(X, Y, X + Y, d T, Z)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CachedPoint {
+    x: Fq,
+    y: Fq,
+    x_plus_y: Fq,
+    t_d: Fq,
+    z: Fq,
+}
+
+/// An affine point prepared for repeated mixed additions,
+/// `(x, y, x + y, d x y)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CachedAffine {
+    x: Fq,
+    y: Fq,
+    x_plus_y: Fq,
+    xy_d: Fq,
+}
+
+impl From<EdwardsProjective> for CachedPoint {
+    fn from(p: EdwardsProjective) -> Self {
+        Self {
+            x: p.x,
+            y: p.y,
+            x_plus_y: p.x + p.y,
+            t_d: EdwardsParameters::COEFF_D * p.t,
+            z: p.z,
+        }
+    }
+}
+
+impl From<EdwardsAffine> for CachedAffine {
+    fn from(p: EdwardsAffine) -> Self {
+        Self {
+            x: p.x,
+            y: p.y,
+            x_plus_y: p.x + p.y,
+            xy_d: EdwardsParameters::COEFF_D * p.x * p.y,
+        }
+    }
+}
+
+impl Neg for CachedPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: self.y,
+            x_plus_y: self.y - self.x,
+            t_d: -self.t_d,
+            z: self.z,
+        }
+    }
+}
+
+impl Neg for CachedAffine {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: self.y,
+            x_plus_y: self.y - self.x,
+            xy_d: -self.xy_d,
+        }
+    }
+}
+
+impl<'a> AddAssign<&'a CachedPoint> for EdwardsProjective {
+    fn add_assign(&mut self, other: &'a CachedPoint) {
+        // add-2008-hwcd, with d T2 and X2 + Y2 precomputed
+        add_with(
+            self,
+            other.x,
+            other.y,
+            other.x_plus_y,
+            other.t_d,
+            Some(other.z),
+        );
+    }
+}
+
+impl<'a> SubAssign<&'a CachedPoint> for EdwardsProjective {
+    fn sub_assign(&mut self, other: &'a CachedPoint) {
+        *self += &-*other;
+    }
+}
+
+impl<'a> AddAssign<&'a CachedAffine> for EdwardsProjective {
+    fn add_assign(&mut self, other: &'a CachedAffine) {
+        // madd-2008-hwcd, with d x2 y2 and x2 + y2 precomputed
+        add_with(self, other.x, other.y, other.x_plus_y, other.xy_d, None);
+    }
+}
+
+impl<'a> SubAssign<&'a CachedAffine> for EdwardsProjective {
+    fn sub_assign(&mut self, other: &'a CachedAffine) {
+        *self += &-*other;
+    }
+}
+
+/// The addition formulas, given `d T2` and `Z2` (`None` for an affine
+/// point).
+#[inline]
+fn add_with(
+    p: &mut EdwardsProjective,
+    x2: Fq,
+    y2: Fq,
+    x2_plus_y2: Fq,
+    t2_d: Fq,
+    z2: Option<Fq>,
+) {
+    let c = p.t * t2_d;
+    let d = z2.map_or(p.z, |z2| p.z * z2);
+    let a = p.x * x2;
+    let b = p.y * y2;
+    let e = (p.x + p.y) * x2_plus_y2 - a - b;
+    let f = d - c;
+    let g = d + c;
+    // H = B - a A = B + 5 A
+    let h = b + mul_by_5(a);
+    p.x = e * f;
+    p.y = g * h;
+    p.t = e * h;
+    p.z = f * g;
+}
+
+/// Multiplication by a power of two with repeated doublings, without the
+/// overhead of a generic scalar multiplication.
+pub trait MulByPow2 {
+    /// Compute `2^k P`.
+    fn mul_by_pow_2(&self, k: usize) -> Self;
+}
+
+impl MulByPow2 for EdwardsProjective {
+    fn mul_by_pow_2(&self, k: usize) -> Self {
+        double_n(self, k)
+    }
+}
+
+/// Compute `2^n p` with `n` doublings; `T` is only computed by the last
+/// one.
+pub fn double_n(p: &EdwardsProjective, n: usize) -> EdwardsProjective {
+    let mut res = *p;
+    for i in 0..n {
+        // dbl-2008-hwcd
+        let a = res.x.square();
+        let b = res.y.square();
+        let c = res.z.square().double();
+        // D = a A = -5 A
+        let d = -mul_by_5(a);
+        let e = (res.x + res.y).square() - a - b;
+        let g = d + b;
+        let f = g - c;
+        let h = d - b;
+        res.x = e * f;
+        res.y = g * h;
+        res.z = f * g;
+        if i + 1 == n {
+            res.t = e * h;
+        }
+    }
+    res
+}
+
+/// Compute `p + q` for any two points whose sum is not at infinity,
+/// including the exceptional inputs of the unified formulas, and `None`
+/// if the sum is at infinity.
+///
+/// Both the unified (add-2008-hwcd) and the dedicated (add-2008-hwcd-2)
+/// formulas are evaluated, and the dedicated result is selected in
+/// constant time when the unified one fails. The unified formulas are
+/// correct for doublings and for any two points of the prime order
+/// subgroup, the dedicated ones for any two distinct points whose sum is
+/// affine, so at least one of them succeeds unless the sum is at infinity.
+///
+/// Points decoded from untrusted input without a subgroup check can
+/// trigger the exceptional case of `+`; within the subgroup, `+` is
+/// already complete and is cheaper.
+pub fn add_complete(
+    p: &EdwardsProjective,
+    q: &EdwardsProjective,
+) -> Option<EdwardsProjective> {
+    let mut res = *p + q;
+    let dedicated = add_dedicated(p, q);
+    let choice = ct_eq(&res.z, &Fq::zero());
+    conditional_assign(&mut res.x, &dedicated.x, choice);
+    conditional_assign(&mut res.y, &dedicated.y, choice);
+    conditional_assign(&mut res.t, &dedicated.t, choice);
+    conditional_assign(&mut res.z, &dedicated.z, choice);
+    if res.z.is_zero() {
+        None
+    } else {
+        Some(res)
+    }
+}
+
+/// The dedicated addition formulas add-2008-hwcd-2, which fail for
+/// doublings.
+fn add_dedicated(
+    p: &EdwardsProjective,
+    q: &EdwardsProjective,
+) -> EdwardsProjective {
+    let a = p.x * q.x;
+    let b = p.y * q.y;
+    let c = p.z * q.t;
+    let d = p.t * q.z;
+    let e = d + c;
+    let f = (p.x - p.y) * (q.x + q.y) + b - a;
+    // G = B + a A = B - 5 A
+    let g = b - mul_by_5(a);
+    let h = d - c;
+    EdwardsProjective::new(e * f, g * h, e * h, f * g)
+}
+
+#[inline]
+fn mul_by_5(a: Fq) -> Fq {
+    a.double().double() + a
+}
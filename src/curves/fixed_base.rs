@@ -0,0 +1,405 @@
+use crate::{
+    batch_normalize, EdwardsAffine, EdwardsProjective, Fr, FrParameters,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, FpParameters, PrimeField, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Read, SerializationError, Write,
+};
+use ark_std::{boxed::Box, vec::Vec};
+use once_cell::race::OnceBox;
+
+/// The number of teeth of the comb used for the generator.
+pub const GENERATOR_COMB_TEETH: usize = 8;
+
+/// A precomputed table for fixed-base scalar multiplication with the
+/// (Lim-Lee) comb method.
+///
+/// The bits of a scalar are arranged in a `teeth x spacing` matrix, where
+/// the `i`-th row holds the bits `i * spacing .. (i + 1) * spacing`. The
+/// table stores `sum_{i in J} 2^{i * spacing} * base` for every non-empty
+/// subset `J` of the rows, so that a multiplication reads one column at a
+/// time and costs `spacing` doublings and at most `spacing` additions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CombTable {
+    base: EdwardsAffine,
+    teeth: usize,
+    spacing: usize,
+    table: Vec<EdwardsAffine>,
+}
+
+impl CombTable {
+    /// Build a comb table for `base` with `teeth` teeth, i.e.,
+    /// `2^teeth - 1` precomputed points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `teeth` is not within `1..=16`.
+    pub fn new(base: &EdwardsAffine, teeth: usize) -> Self {
+        assert!(
+            (1..=16).contains(&teeth),
+            "the number of teeth must be within 1..=16"
+        );
+        let num_bits = <FrParameters as FpParameters>::MODULUS_BITS as usize;
+        let spacing = num_bits.div_ceil(teeth);
+
+        // rows[i] = 2^{i * spacing} * base
+        let mut rows = Vec::with_capacity(teeth);
+        let mut row = base.into_projective();
+        for _ in 0..teeth {
+            rows.push(row);
+            for _ in 0..spacing {
+                row.double_in_place();
+            }
+        }
+
+        // table[j - 1] = sum_{i: bit i of j is set} rows[i]
+        let mut table = Vec::with_capacity((1 << teeth) - 1);
+        for j in 1usize..(1 << teeth) {
+            let high = ark_std::mem::size_of::<usize>() * 8
+                - 1
+                - j.leading_zeros() as usize;
+            let rest = j ^ (1 << high);
+            let entry = if rest == 0 {
+                rows[high]
+            } else {
+                table[rest - 1] + rows[high]
+            };
+            table.push(entry);
+        }
+        let table = batch_normalize(&table);
+
+        Self {
+            base: *base,
+            teeth,
+            spacing,
+            table,
+        }
+    }
+
+    /// The base point of this table.
+    pub fn base(&self) -> &EdwardsAffine {
+        &self.base
+    }
+
+    /// The number of teeth of the comb.
+    pub fn teeth(&self) -> usize {
+        self.teeth
+    }
+
+    /// Compute `scalar * base`.
+    pub fn mul(&self, scalar: &Fr) -> EdwardsProjective {
+        let scalar = scalar.into_repr();
+        let mut res = EdwardsProjective::zero();
+        for col in (0..self.spacing).rev() {
+            res.double_in_place();
+            let mut index = 0;
+            for row in 0..self.teeth {
+                if scalar.get_bit(row * self.spacing + col) {
+                    index |= 1 << row;
+                }
+            }
+            if index != 0 {
+                res.add_assign_mixed(&self.table[index - 1]);
+            }
+        }
+        res
+    }
+
+    /// Read a table, reading each point with `read_point`.
+    fn read<R: Read>(
+        mut reader: R,
+        read_point: fn(&mut R) -> Result<EdwardsAffine, SerializationError>,
+    ) -> Result<Self, SerializationError> {
+        let teeth = u8::deserialize(&mut reader)? as usize;
+        if !(1..=16).contains(&teeth) {
+            return Err(SerializationError::InvalidData);
+        }
+        let num_bits = <FrParameters as FpParameters>::MODULUS_BITS as usize;
+        let len = (1 << teeth) - 1;
+        let mut table = Vec::with_capacity(len);
+        for _ in 0..len {
+            table.push(read_point(&mut reader)?);
+        }
+        let base = table[0];
+        Ok(Self {
+            base,
+            teeth,
+            spacing: num_bits.div_ceil(teeth),
+            table,
+        })
+    }
+}
+
+/// The table is serialized as the number of teeth (one byte) followed by
+/// the points, the first of which is the base.
+impl CanonicalSerialize for CombTable {
+    fn serialize<W: Write>(
+        &self,
+        mut writer: W,
+    ) -> Result<(), SerializationError> {
+        (self.teeth as u8).serialize(&mut writer)?;
+        for point in &self.table {
+            point.serialize(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self) -> usize {
+        1 + self.table.len() * self.base.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(
+        &self,
+        mut writer: W,
+    ) -> Result<(), SerializationError> {
+        (self.teeth as u8).serialize(&mut writer)?;
+        for point in &self.table {
+            point.serialize_uncompressed(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    fn serialize_unchecked<W: Write>(
+        &self,
+        writer: W,
+    ) -> Result<(), SerializationError> {
+        self.serialize_uncompressed(writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        1 + self.table.len() * self.base.uncompressed_size()
+    }
+}
+
+/// As for [`PrecomputedTable`], deserialization checks that the points are
+/// in the prime order subgroup, but not that they are the combinations of
+/// the multiples of the base.
+impl CanonicalDeserialize for CombTable {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::read(reader, |r| EdwardsAffine::deserialize(r))
+    }
+
+    fn deserialize_uncompressed<R: Read>(
+        reader: R,
+    ) -> Result<Self, SerializationError> {
+        Self::read(reader, |r| {
+            let point = EdwardsAffine::deserialize_uncompressed(r)?;
+            if !point.is_on_curve() {
+                return Err(SerializationError::InvalidData);
+            }
+            Ok(point)
+        })
+    }
+
+    fn deserialize_unchecked<R: Read>(
+        reader: R,
+    ) -> Result<Self, SerializationError> {
+        Self::read(reader, |r| EdwardsAffine::deserialize_unchecked(r))
+    }
+}
+
+/// A precomputed table for fixed-base scalar multiplication with a fixed
+/// window.
+///
+/// A scalar is split into `window`-bit digits `k_i`, and the table stores
+/// `j * 2^{i * window} * base` for every window `i` and every non-zero
+/// digit `j`, so that a multiplication costs one addition per window and no
+/// doubling. The table holds `ceil(253 / window) * (2^window - 1)` points.
+///
+/// The table can be built offline and serialized with
+/// [`CanonicalSerialize`]. Deserialization checks that all the points are
+/// in the prime order subgroup and that the table has the expected shape,
+/// but not that the points are the multiples of the base: a table must
+/// come from a trusted source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecomputedTable {
+    base: EdwardsAffine,
+    window: usize,
+    table: Vec<EdwardsAffine>,
+}
+
+impl PrecomputedTable {
+    /// The largest supported window.
+    pub const MAX_WINDOW: usize = 16;
+
+    /// Build a table for `base` with `window`-bit digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not within `1..=MAX_WINDOW`.
+    pub fn new(base: &EdwardsAffine, window: usize) -> Self {
+        assert!(
+            (1..=Self::MAX_WINDOW).contains(&window),
+            "the window must be within 1..=16"
+        );
+        let digits = (1 << window) - 1;
+        let mut table = Vec::with_capacity(Self::num_windows(window) * digits);
+        let mut row = base.into_projective();
+        for _ in 0..Self::num_windows(window) {
+            let mut entry = row;
+            for _ in 0..digits {
+                table.push(entry);
+                entry += row;
+            }
+            // entry = 2^window * row
+            row = entry;
+        }
+        let table = batch_normalize(&table);
+
+        Self {
+            base: *base,
+            window,
+            table,
+        }
+    }
+
+    /// The base point of this table.
+    pub fn base(&self) -> &EdwardsAffine {
+        &self.base
+    }
+
+    /// The number of bits of the digits.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Compute `scalar * base`.
+    pub fn mul(&self, scalar: &Fr) -> EdwardsProjective {
+        let scalar = scalar.into_repr();
+        let digits = (1 << self.window) - 1;
+        let mut res = EdwardsProjective::zero();
+        for i in 0..Self::num_windows(self.window) {
+            let mut digit = 0;
+            for bit in (0..self.window).rev() {
+                digit <<= 1;
+                if scalar.get_bit(i * self.window + bit) {
+                    digit |= 1;
+                }
+            }
+            if digit != 0 {
+                res.add_assign_mixed(&self.table[i * digits + digit - 1]);
+            }
+        }
+        res
+    }
+
+    fn num_windows(window: usize) -> usize {
+        let num_bits = <FrParameters as FpParameters>::MODULUS_BITS as usize;
+        num_bits.div_ceil(window)
+    }
+
+    fn table_len(window: usize) -> usize {
+        Self::num_windows(window) * ((1 << window) - 1)
+    }
+
+    /// Read a table, reading each point with `read_point`.
+    fn read<R: Read>(
+        mut reader: R,
+        read_point: fn(&mut R) -> Result<EdwardsAffine, SerializationError>,
+    ) -> Result<Self, SerializationError> {
+        let window = u8::deserialize(&mut reader)? as usize;
+        if !(1..=Self::MAX_WINDOW).contains(&window) {
+            return Err(SerializationError::InvalidData);
+        }
+        let len = Self::table_len(window);
+        let mut table = Vec::with_capacity(len);
+        for _ in 0..len {
+            table.push(read_point(&mut reader)?);
+        }
+        let base = table[0];
+        Ok(Self {
+            base,
+            window,
+            table,
+        })
+    }
+}
+
+/// The table is serialized as the window (one byte) followed by the points,
+/// the first of which is the base.
+impl CanonicalSerialize for PrecomputedTable {
+    fn serialize<W: Write>(
+        &self,
+        mut writer: W,
+    ) -> Result<(), SerializationError> {
+        (self.window as u8).serialize(&mut writer)?;
+        for point in &self.table {
+            point.serialize(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self) -> usize {
+        1 + self.table.len() * self.base.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(
+        &self,
+        mut writer: W,
+    ) -> Result<(), SerializationError> {
+        (self.window as u8).serialize(&mut writer)?;
+        for point in &self.table {
+            point.serialize_uncompressed(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    fn serialize_unchecked<W: Write>(
+        &self,
+        writer: W,
+    ) -> Result<(), SerializationError> {
+        self.serialize_uncompressed(writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        1 + self.table.len() * self.base.uncompressed_size()
+    }
+}
+
+impl CanonicalDeserialize for PrecomputedTable {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::read(reader, |r| EdwardsAffine::deserialize(r))
+    }
+
+    fn deserialize_uncompressed<R: Read>(
+        reader: R,
+    ) -> Result<Self, SerializationError> {
+        Self::read(reader, |r| {
+            let point = EdwardsAffine::deserialize_uncompressed(r)?;
+            if !point.is_on_curve() {
+                return Err(SerializationError::InvalidData);
+            }
+            Ok(point)
+        })
+    }
+
+    fn deserialize_unchecked<R: Read>(
+        reader: R,
+    ) -> Result<Self, SerializationError> {
+        Self::read(reader, |r| EdwardsAffine::deserialize_unchecked(r))
+    }
+}
+
+static GENERATOR_TABLE: OnceBox<CombTable> = OnceBox::new();
+
+/// The comb table of the prime subgroup generator, built on first use.
+pub fn generator_table() -> &'static CombTable {
+    GENERATOR_TABLE.get_or_init(|| {
+        Box::new(CombTable::new(
+            &EdwardsAffine::prime_subgroup_generator(),
+            GENERATOR_COMB_TEETH,
+        ))
+    })
+}
+
+/// Whether the comb table of the generator has been built.
+pub(crate) fn generator_table_is_initialized() -> bool {
+    GENERATOR_TABLE.get().is_some()
+}
+
+/// Compute `scalar * G` where `G` is the prime subgroup generator, using a
+/// precomputed comb table.
+pub fn mul_by_generator(scalar: &Fr) -> EdwardsProjective {
+    generator_table().mul(scalar)
+}
@@ -1,7 +1,9 @@
-use crate::{EdwardsParameters, Fq, Fr, FrParameters};
+use crate::{wipe, wipe_limbs, EdwardsParameters, Fq, Fr, FrParameters};
 use ark_ec::{AffineCurve, ModelParameters, ProjectiveCurve};
-use ark_ff::{field_new, BigInteger, BigInteger256, FpParameters, One};
-use ark_std::{cmp::max, Zero};
+use ark_ff::{
+    field_new, BigInteger, BigInteger256, FpParameters, One, PrimeField,
+};
+use ark_std::{cmp::max, vec::Vec, Zero};
 use num_bigint::BigUint;
 
 /// The GLV parameters that are useful to compute the endomorphism
@@ -41,6 +43,59 @@ pub trait GLVParameters: Send + Sync + 'static + ModelParameters {
         base: &Self::CurveAffine,
         scalar: &Self::ScalarField,
     ) -> Self::CurveProjective;
+
+    /// decompose a scalar s, given by at most four little-endian u64 limbs
+    /// (e.g., a `BigInteger256` or a `[u64; 4]`) and reduced modulo the
+    /// group order, into k1, k2, s.t. s = k1 + lambda k2
+    fn scalar_decomposition_limbs<S: AsRef<[u64]> + ?Sized>(
+        k: &S,
+    ) -> (Self::ScalarField, Self::ScalarField) {
+        Self::scalar_decomposition(&scalar_from_limbs(k.as_ref()))
+    }
+
+    /// perform GLV multiplication with a scalar given by its little-endian
+    /// u64 limbs
+    fn glv_mul_limbs<S: AsRef<[u64]> + ?Sized>(
+        base: &Self::CurveAffine,
+        scalar: &S,
+    ) -> Self::CurveProjective {
+        Self::glv_mul(base, &scalar_from_limbs(scalar.as_ref()))
+    }
+}
+
+/// The little-endian u64 limbs as bytes.
+fn limbs_to_bytes(limbs: &[u64]) -> Vec<u8> {
+    limbs.iter().flat_map(|limb| limb.to_le_bytes()).collect()
+}
+
+/// The scalar represented by the little-endian u64 limbs, reduced modulo
+/// the group order.
+fn scalar_from_limbs<F: PrimeField>(limbs: &[u64]) -> F {
+    F::from_le_bytes_mod_order(&limbs_to_bytes(limbs))
+}
+
+/// The eigenvalue of the endomorphism on the prime order subgroup,
+/// `phi(P) = LAMBDA * P`, a square root of -2 modulo r.
+pub(crate) const LAMBDA: Fr = field_new!(
+    Fr,
+    "8913659658109529928382530854484400854125314752504019737736543920008458395397"
+);
+
+/// The eigenvalue `lambda` of the endomorphism on the prime order
+/// subgroup: `EdwardsParameters::endomorphism(P) = lambda * P`.
+pub fn lambda() -> Fr {
+    LAMBDA
+}
+
+/// `lambda^2 = -2`, the eigenvalue of the endomorphism applied twice.
+pub fn lambda_squared() -> Fr {
+    -Fr::from(2u64)
+}
+
+/// `lambda * scalar`, the scalar such that
+/// `apply_lambda(s) * P = endomorphism(s * P)` on the prime order subgroup.
+pub fn apply_lambda(scalar: &Fr) -> Fr {
+    LAMBDA * scalar
 }
 
 impl GLVParameters for EdwardsParameters {
@@ -134,41 +189,137 @@ impl GLVParameters for EdwardsParameters {
     ) -> (Self::ScalarField, Self::ScalarField) {
         let tmp: BigInteger256 = (*scalar).into();
         let scalar_z: BigUint = tmp.into();
+        babai_decomposition(scalar, scalar_z)
+    }
+
+    /// Decompose a scalar s, given by its little-endian u64 limbs, into
+    /// k1, k2, s.t. s = k1 + lambda k2. The integer is reduced in place and
+    /// rounded as is: s is never converted to the Montgomery form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than four limbs.
+    fn scalar_decomposition_limbs<S: AsRef<[u64]> + ?Sized>(
+        scalar: &S,
+    ) -> (Self::ScalarField, Self::ScalarField) {
+        let mut limbs = reduce_limbs(scalar.as_ref());
+        let scalar_z: BigUint = limbs.into();
+        wipe_limbs(&mut limbs.0);
+        babai_decomposition_integer(&scalar_z)
+    }
+
+    /// perform GLV multiplication
+    fn glv_mul(
+        base: &Self::CurveAffine,
+        scalar: &Self::ScalarField,
+    ) -> Self::CurveProjective {
+        let psi_base = Self::endomorphism(base);
+        let (mut k1, mut k2) = Self::scalar_decomposition(scalar);
+        let result = multi_scalar_mul(base, &k1, &psi_base, &k2);
+        wipe(&mut k1);
+        wipe(&mut k2);
+        result
+    }
 
-        let tmp: BigInteger256 = Self::COEFF_N11.into();
-        let n11: BigUint = tmp.into();
+    /// perform GLV multiplication with a scalar given by its little-endian
+    /// u64 limbs
+    fn glv_mul_limbs<S: AsRef<[u64]> + ?Sized>(
+        base: &Self::CurveAffine,
+        scalar: &S,
+    ) -> Self::CurveProjective {
+        let psi_base = Self::endomorphism(base);
+        let (mut k1, mut k2) = Self::scalar_decomposition_limbs(scalar);
+        let result = multi_scalar_mul(base, &k1, &psi_base, &k2);
+        wipe(&mut k1);
+        wipe(&mut k2);
+        result
+    }
+}
 
-        let tmp: BigInteger256 = Self::COEFF_N12.into();
-        let n12: BigUint = tmp.into();
+/// The integer of at most four little-endian u64 limbs, reduced modulo r.
+///
+/// As `2^256 < 16 r`, the reduction is four conditional subtractions of
+/// `8 r`, `4 r`, `2 r` and `r`.
+fn reduce_limbs(limbs: &[u64]) -> BigInteger256 {
+    assert!(limbs.len() <= 4, "the scalar must have at most four limbs");
+    let mut scalar = BigInteger256::default();
+    scalar.0[..limbs.len()].copy_from_slice(limbs);
+    for shift in (0..4).rev() {
+        let mut multiple = <FrParameters as FpParameters>::MODULUS;
+        multiple.muln(shift);
+        if scalar >= multiple {
+            scalar.sub_noborrow(&multiple);
+        }
+    }
+    scalar
+}
 
-        let r: BigUint = <FrParameters as FpParameters>::MODULUS.into();
+/// The integers `beta_1 = floor(scalar_z n11 / r)` and
+/// `beta_2 = floor(scalar_z n12 / r)` of Babai's rounding, given an integer
+/// `scalar_z` smaller than r.
+fn babai_rounding(scalar_z: &BigUint) -> (BigUint, BigUint) {
+    type P = EdwardsParameters;
+    let r: BigUint = <FrParameters as FpParameters>::MODULUS.into();
 
-        // beta = vector([n,0]) * self.curve.N_inv
-        let beta_1 = scalar_z.clone() * n11;
-        let beta_2 = scalar_z * n12;
+    let tmp: BigInteger256 = P::COEFF_N11.into();
+    let n11: BigUint = tmp.into();
 
-        let beta_1 = beta_1 / r.clone();
-        let beta_2 = beta_2 / r;
+    let tmp: BigInteger256 = P::COEFF_N12.into();
+    let n12: BigUint = tmp.into();
 
-        // b = vector([int(beta[0]), int(beta[1])]) * self.curve.N
-        let beta_1 = Fr::from(beta_1);
-        let beta_2 = Fr::from(beta_2);
-        let b1 = beta_1 * Self::COEFF_N11 + beta_2 * Self::COEFF_N21;
-        let b2 = beta_1 * Self::COEFF_N12 + beta_2 * Self::COEFF_N22;
+    // beta = vector([n,0]) * self.curve.N_inv
+    let beta_1 = scalar_z * n11 / &r;
+    let beta_2 = scalar_z * n12 / &r;
+    (beta_1, beta_2)
+}
 
-        let k1 = (*scalar) - b1;
-        let k2 = -b2;
-        (k1, k2)
+/// The decomposition of `scalar = k1 + lambda k2`, given `scalar_z`, the
+/// same scalar as an integer smaller than r.
+fn babai_decomposition(scalar: &Fr, scalar_z: BigUint) -> (Fr, Fr) {
+    type P = EdwardsParameters;
+    let (beta_1, beta_2) = babai_rounding(&scalar_z);
+
+    // b = vector([int(beta[0]), int(beta[1])]) * self.curve.N
+    let mut beta_1 = Fr::from(beta_1);
+    let mut beta_2 = Fr::from(beta_2);
+    let mut b1 = beta_1 * P::COEFF_N11 + beta_2 * P::COEFF_N21;
+    let mut b2 = beta_1 * P::COEFF_N12 + beta_2 * P::COEFF_N22;
+
+    let k1 = (*scalar) - b1;
+    let k2 = -b2;
+    for x in [&mut beta_1, &mut beta_2, &mut b1, &mut b2] {
+        wipe(x);
     }
+    (k1, k2)
+}
 
-    /// perform GLV multiplication
-    fn glv_mul(
-        base: &Self::CurveAffine,
-        scalar: &Self::ScalarField,
-    ) -> Self::CurveProjective {
-        let psi_base = Self::endomorphism(&base);
-        let (k1, k2) = Self::scalar_decomposition(scalar);
-        multi_scalar_mul(&base, &k1, &psi_base, &k2)
+/// The decomposition of `scalar_z = k1 + lambda k2` for an integer smaller
+/// than r, computed on integers: with `N22 = -N11`,
+/// `k1 = scalar_z - beta_1 N11 - beta_2 N21` and
+/// `k2 = beta_2 N11 - beta_1 N12`, both of about 128 bits.
+fn babai_decomposition_integer(scalar_z: &BigUint) -> (Fr, Fr) {
+    type P = EdwardsParameters;
+    let (beta_1, beta_2) = babai_rounding(scalar_z);
+
+    let tmp: BigInteger256 = P::COEFF_N11.into();
+    let n11: BigUint = tmp.into();
+    let tmp: BigInteger256 = P::COEFF_N12.into();
+    let n12: BigUint = tmp.into();
+    let tmp: BigInteger256 = P::COEFF_N21.into();
+    let n21: BigUint = tmp.into();
+
+    let b1 = &beta_1 * &n11 + &beta_2 * n21;
+    let k1 = signed_difference(scalar_z, &b1);
+    let k2 = signed_difference(&(beta_2 * n11), &(beta_1 * n12));
+    (k1, k2)
+}
+
+/// `a - b` as an element of `Fr`, for integers smaller than r.
+fn signed_difference(a: &BigUint, b: &BigUint) -> Fr {
+    if a >= b {
+        Fr::from(a - b)
+    } else {
+        -Fr::from(b - a)
     }
 }
 
@@ -224,6 +375,78 @@ pub fn multi_scalar_mul(
     res
 }
 
+/// Compute `a * p + b * q` in a single pass of doublings.
+///
+/// Both scalars are GLV-decomposed, so that the four half-size scalars of
+/// `p`, `phi(p)`, `q` and `phi(q)` are processed together (Shamir's trick)
+/// with a table of the 15 non-trivial subset sums of these points. This
+/// costs about 128 doublings and 128 additions, compared to two separate
+/// GLV multiplications and an addition.
+pub fn double_base_mul(
+    a: &Fr,
+    p: &crate::EdwardsAffine,
+    b: &Fr,
+    q: &crate::EdwardsAffine,
+) -> crate::EdwardsProjective {
+    let (a1, a2) = EdwardsParameters::scalar_decomposition(a);
+    let (b1, b2) = EdwardsParameters::scalar_decomposition(b);
+    let bases = [
+        *p,
+        EdwardsParameters::endomorphism(p),
+        *q,
+        EdwardsParameters::endomorphism(q),
+    ];
+
+    let r_over_2: Fr =
+        <FrParameters as FpParameters>::MODULUS_MINUS_ONE_DIV_TWO.into();
+
+    let mut points = [crate::EdwardsProjective::zero(); 4];
+    let mut scalars = [BigInteger256::default(); 4];
+    for (i, (base, scalar)) in bases.iter().zip([a1, a2, b1, b2]).enumerate() {
+        if scalar > r_over_2 {
+            points[i] = -base.into_projective();
+            scalars[i] = (-scalar).into();
+        } else {
+            points[i] = base.into_projective();
+            scalars[i] = scalar.into();
+        }
+    }
+
+    // table[j] = sum of the points[i] for the bits i set in j
+    let mut table = [crate::EdwardsProjective::zero(); 16];
+    for j in 1usize..16 {
+        let i = j.trailing_zeros() as usize;
+        table[j] = table[j & (j - 1)] + points[i];
+    }
+
+    let len = scalars.iter().map(|s| s.num_bits()).max().unwrap() as usize;
+    let mut res = crate::EdwardsProjective::zero();
+    for bit in (0..len).rev() {
+        res.double_in_place();
+        let index = scalars
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.get_bit(bit))
+            .fold(0, |acc, (i, _)| acc | (1 << i));
+        if index != 0 {
+            res += &table[index];
+        }
+    }
+    res
+}
+
+/// Compute `scalar * base` with GLV multiplication, with the scalar given
+/// as a canonical 32-byte little-endian encoding.
+///
+/// Returns `None` if the scalar is not below the group order.
+pub fn mul_bytes(
+    base: &crate::EdwardsAffine,
+    scalar: &[u8; 32],
+) -> Option<crate::EdwardsProjective> {
+    let scalar = super::msm::bigint_from_bytes(scalar)?;
+    Some(EdwardsParameters::glv_mul_limbs(base, &scalar))
+}
+
 /// return the highest non-zero bits of a bit string.
 fn get_bits(a: &[bool]) -> u16 {
     let mut res = 256;
@@ -0,0 +1,78 @@
+//! The compressed encoding of points of gnark-crypto, the
+//! `PointAffine.Bytes` and `PointAffine.SetBytes` of its `bandersnatch`
+//! package, for protocols that mix gnark circuits and this crate.
+//!
+//! As in [`to_compressed_bytes`](crate::to_compressed_bytes), the encoding
+//! is the `y` coordinate in little-endian, with a flag in the most
+//! significant bit of the last byte; but the flag is set when `x` is
+//! lexicographically largest, i.e., `x > (q - 1) / 2`, rather than when
+//! `x` is odd. Field elements, e.g., the scalars of gnark's EdDSA, are
+//! big-endian in gnark-crypto, see
+//! [`FieldBytes::to_bytes_be`](crate::FieldBytes::to_bytes_be).
+//!
+//! The base point of gnark-crypto, [`gnark_base_point`], is not the
+//! generator of this crate, but both generate the prime order subgroup.
+
+use crate::{
+    fq_from_le_bytes, EdwardsAffine, Fq, FqParameters, PointFromCoordinate,
+    COMPRESSED_SIZE,
+};
+use ark_ff::{field_new, BigInteger, FpParameters, PrimeField, Zero};
+
+/// The `x` coordinate of the base point of gnark-crypto.
+const GNARK_BASE_X: Fq = field_new!(
+    Fq,
+    "18886178867200960497001835917649091219057080094937609519140440539760939937304"
+);
+
+/// The `y` coordinate of the base point of gnark-crypto.
+const GNARK_BASE_Y: Fq = field_new!(
+    Fq,
+    "19188667384257783945677642223292697773471335439753913231509108946878080696678"
+);
+
+/// The base point of the `bandersnatch` package of gnark-crypto, a
+/// generator of the prime order subgroup.
+pub fn gnark_base_point() -> EdwardsAffine {
+    EdwardsAffine::new(GNARK_BASE_X, GNARK_BASE_Y)
+}
+
+/// Encode a point as gnark-crypto does: `y` in little-endian, with the
+/// most significant bit set if `x` is lexicographically largest.
+pub fn to_gnark_bytes(p: &EdwardsAffine) -> [u8; COMPRESSED_SIZE] {
+    let mut bytes = [0u8; COMPRESSED_SIZE];
+    bytes.copy_from_slice(&p.y.into_repr().to_bytes_le());
+    if is_lexicographically_largest(&p.x) {
+        bytes[COMPRESSED_SIZE - 1] |= 0x80;
+    }
+    bytes
+}
+
+/// Decode a point encoded with [`to_gnark_bytes`].
+///
+/// Returns `None` if `y` is not canonical, if there is no point with this
+/// `y` coordinate, or if the flag is set while `x = 0`, which gnark-crypto
+/// never encodes. Subgroup membership is not checked.
+pub fn from_gnark_bytes(
+    bytes: &[u8; COMPRESSED_SIZE],
+) -> Option<EdwardsAffine> {
+    let largest = bytes[COMPRESSED_SIZE - 1] >> 7 == 1;
+    let mut y = *bytes;
+    y[COMPRESSED_SIZE - 1] &= 0x7f;
+    let y = fq_from_le_bytes(&y)?;
+
+    let p = EdwardsAffine::from_y_with_sign(y, false)?;
+    if p.x.is_zero() && largest {
+        return None;
+    }
+    if is_lexicographically_largest(&p.x) == largest {
+        Some(p)
+    } else {
+        Some(EdwardsAffine::new(-p.x, y))
+    }
+}
+
+/// Whether `x > (q - 1) / 2`.
+fn is_lexicographically_largest(x: &Fq) -> bool {
+    x.into_repr() > FqParameters::MODULUS_MINUS_ONE_DIV_TWO
+}
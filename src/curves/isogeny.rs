@@ -0,0 +1,135 @@
+//! The 2-isogeny whose kernel is the point `(0, -1)` of order 2, and its
+//! dual.
+//!
+//! On the Montgomery model, with `x = B u` and `y = B^2 v`, bandersnatch
+//! reads `y^2 = x (x^2 + a x + b)` with `a = A B` and `b = B^2`. The
+//! isogeny with kernel `(0, 0)` maps it to
+//! `Y^2 = X (X^2 - 2a X + (a^2 - 4b))` with
+//! `(X, Y) = (y^2 / x^2, y (b - x^2) / x^2)`, and the dual isogeny maps it
+//! back; the composition of the two maps is the multiplication by 2. The
+//! isogenous curve is exposed in short Weierstrass form
+//! `y^2 = x^3 + a' x + b'`, obtained with the change of variable
+//! `x = X - 2a / 3`.
+
+use crate::{batch_inverse, EdwardsAffine, EdwardsParameters, Fq, Fr};
+use ark_ec::models::{
+    short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    ModelParameters, MontgomeryModelParameters, SWModelParameters,
+};
+use ark_ff::{field_new, Field, One, Zero};
+
+pub type IsogenousAffine = GroupAffine<IsogenousParameters>;
+pub type IsogenousProjective = GroupProjective<IsogenousParameters>;
+
+/// The curve 2-isogenous to bandersnatch, in short Weierstrass form. It
+/// has the same number of points as bandersnatch, `4r`.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct IsogenousParameters;
+
+impl ModelParameters for IsogenousParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for IsogenousParameters {
+    /// COEFF_A = (a^2 - 4b) - (2a)^2 / 3
+    const COEFF_A: Fq = field_new!(
+        Fq,
+        "20458464300492761864748011029773420162838463150047692746127067939202202154161"
+    );
+
+    /// COEFF_B = 2 (-2a)^3 / 27 + 2a (a^2 - 4b) / 3
+    const COEFF_B: Fq = field_new!(
+        Fq,
+        "21812157786162427268359121069288457355023264523764677751658771437715213709173"
+    );
+
+    /// COFACTOR = 4
+    const COFACTOR: &'static [u64] = &[4];
+
+    /// COFACTOR^(-1) mod r =
+    /// 9831726595336160714896451345284868594481866920080427688839802480047265754601
+    const COFACTOR_INV: Fr = field_new!(
+        Fr,
+        "9831726595336160714896451345284868594481866920080427688839802480047265754601"
+    );
+
+    /// AFFINE_GENERATOR_COEFFS: the image of the bandersnatch generator
+    const AFFINE_GENERATOR_COEFFS: (Fq, Fq) = (GENERATOR_X, GENERATOR_Y);
+}
+
+const GENERATOR_X: Fq = field_new!(
+    Fq,
+    "42942312772663881042047365880018138865410512957746010822138410006236569909046"
+);
+
+const GENERATOR_Y: Fq = field_new!(
+    Fq,
+    "1948406318299250493252026631357972773885884050458468194832196591591411726579"
+);
+
+/// The shift `-2a / 3` from the `X` coordinate to the short Weierstrass
+/// `x` coordinate.
+const SHIFT: Fq = field_new!(
+    Fq,
+    "14985802742008515884267964437361265197071762671023671312132968850175905582190"
+);
+
+/// The coefficient `a^2 - 4b` of the isogenous curve.
+const ISO_A4: Fq = field_new!(
+    Fq,
+    "50647971629855771456488481991959106907525585110604549739448149676404976128919"
+);
+
+/// Map a bandersnatch point through the 2-isogeny with kernel `(0, -1)`.
+pub fn isogeny(p: &EdwardsAffine) -> IsogenousAffine {
+    if p.x.is_zero() {
+        // the identity, or the kernel (0, -1)
+        return IsogenousAffine::zero();
+    }
+    // with the Montgomery map u = (1 + y) / (1 - y), v = u / x:
+    // X = B^2 / x^2, Y = -4 B^2 y / (x (1 - y^2))
+    let b2 = <EdwardsParameters as MontgomeryModelParameters>::COEFF_B.square();
+    let one_minus_y2 = Fq::one() - p.y.square();
+    let inv = (p.x * one_minus_y2).inverse().unwrap();
+    let x_inv = inv * one_minus_y2;
+    let x = b2 * x_inv.square() + SHIFT;
+    let y = -(b2 * p.y * inv).double().double();
+    IsogenousAffine::new(x, y, false)
+}
+
+/// Map a point of the isogenous curve through the dual isogeny, so that
+/// `dual_isogeny(isogeny(p)) = 2p`.
+///
+/// Returns `None` if the image is one of the two points of order 2 that
+/// have no affine twisted Edwards representation.
+pub fn dual_isogeny(q: &IsogenousAffine) -> Option<EdwardsAffine> {
+    if q.infinity {
+        return Some(EdwardsAffine::zero());
+    }
+    let x = q.x - SHIFT;
+    if x.is_zero() {
+        // the kernel of the dual isogeny
+        return Some(EdwardsAffine::zero());
+    }
+    if q.y.is_zero() {
+        return Some(EdwardsAffine::new(Fq::zero(), -Fq::one()));
+    }
+    // with x_m = Y^2 / (4 X^2) and y_m = Y (a^2 - 4b - X^2) / (8 X^2),
+    // u = x_m / B and v = y_m / B^2:
+    // x_te = 2 B Y / (a^2 - 4b - X^2),
+    // y_te = (Y^2 - 4 B X^2) / (Y^2 + 4 B X^2)
+    let b = <EdwardsParameters as MontgomeryModelParameters>::COEFF_B;
+    let x2 = x.square();
+    let y2 = q.y.square();
+    let b4x2 = (b * x2).double().double();
+    let mut denominators = [ISO_A4 - x2, y2 + b4x2];
+    if denominators.iter().any(|d| d.is_zero()) {
+        return None;
+    }
+    batch_inverse(&mut denominators);
+    Some(EdwardsAffine::new(
+        (b * q.y).double() * denominators[0],
+        (y2 - b4x2) * denominators[1],
+    ))
+}
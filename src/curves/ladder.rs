@@ -0,0 +1,134 @@
+//! Constant-time scalar multiplication for secret scalars, with a
+//! Montgomery ladder on the birationally equivalent Montgomery curve.
+
+use crate::{
+    EdwardsAffine, EdwardsParameters, EdwardsProjective, Fq, Fr, FrParameters,
+};
+use ark_ec::{models::MontgomeryModelParameters, AffineCurve};
+use ark_ff::{BigInteger256, Field, FpParameters, One, PrimeField, Zero};
+
+/// Compute `scalar * base` with a Montgomery ladder over the birationally
+/// equivalent Montgomery curve `B v^2 = u^3 + A u^2 + u`.
+///
+/// The ladder processes a fixed number of bits (the bit length of `r`)
+/// with the same sequence of field operations for every scalar, and swaps
+/// the ladder state arithmetically rather than with branches; the result is
+/// recovered from the `u`-coordinates with the Okeya-Sakurai formulas. This
+/// code path is independent of the GLV decomposition and is meant for
+/// secret scalars. Its timing only depends on the scalar through the
+/// underlying field arithmetic.
+///
+/// The base is public and must be in the prime order subgroup; the
+/// identity is handled separately.
+///
+/// # Panics
+///
+/// Panics if the base is the point `(0, -1)` of order 2, the only point
+/// of the curve besides the identity with `x = 0`, which the birational
+/// map sends to infinity. The results for other bases outside the
+/// subgroup are unspecified.
+pub fn ladder_mul(base: &EdwardsAffine, scalar: &Fr) -> EdwardsProjective {
+    if base.is_zero() {
+        return EdwardsProjective::zero();
+    }
+
+    // (u, v) = ((1 + y) / (1 - y), u / x); y = 1 only for x = 0
+    let x_inv = base
+        .x
+        .inverse()
+        .expect("the base must not be the point (0, -1) of order 2");
+    let u = (Fq::one() + base.y) * (Fq::one() - base.y).inverse().unwrap();
+    let v = u * x_inv;
+
+    let coeff_a = <EdwardsParameters as MontgomeryModelParameters>::COEFF_A;
+    let coeff_b = <EdwardsParameters as MontgomeryModelParameters>::COEFF_B;
+    let a24 = (coeff_a + Fq::from(2u64)) * Fq::from(4u64).inverse().unwrap();
+
+    let k = scalar.into_repr();
+    let num_bits = <FrParameters as FpParameters>::MODULUS_BITS as usize;
+
+    // (x0 : z0) = k' P and (x1 : z1) = (k' + 1) P, where k' is the prefix
+    // of k processed so far
+    let (mut x0, mut z0) = (Fq::one(), Fq::zero());
+    let (mut x1, mut z1) = (u, Fq::one());
+    let mut swap = 0u64;
+    for i in (0..num_bits).rev() {
+        let bit = (k.0[i / 64] >> (i % 64)) & 1;
+        swap ^= bit;
+        cswap(&mut x0, &mut x1, swap);
+        cswap(&mut z0, &mut z1, swap);
+        swap = bit;
+
+        // differential addition, with P = (u : 1) as the difference
+        let a = (x0 - z0) * (x1 + z1);
+        let b = (x0 + z0) * (x1 - z1);
+        x1 = (a + b).square();
+        z1 = u * (a - b).square();
+
+        // doubling
+        let s = (x0 + z0).square();
+        let d = (x0 - z0).square();
+        let e = s - d;
+        x0 = s * d;
+        z0 = e * (d + a24 * e);
+    }
+    cswap(&mut x0, &mut x1, swap);
+    cswap(&mut z0, &mut z1, swap);
+
+    // Okeya-Sakurai y-coordinate recovery of k P = (x : y : z)
+    let t1 = u * z0;
+    let t3 = (x0 - t1).square() * x1;
+    let t1 = coeff_a.double() * z0;
+    let t2 = (x0 + u * z0 + t1) * (u * x0 + z0) - t1 * z0;
+    let y = t2 * z1 - t3;
+    let t1 = coeff_b.double() * v * z0 * z1;
+    let x = t1 * x0;
+    let z = t1 * z0;
+
+    // back to twisted Edwards: (x_te, y_te) = (u / v, (u - 1) / (u + 1))
+    let res = EdwardsProjective::new(
+        x * (x + z),
+        y * (x - z),
+        x * (x - z),
+        y * (x + z),
+    );
+
+    // The recovery degenerates when k P or (k + 1) P is the identity, i.e.,
+    // for k = 0 and k = -1; select the correct results in constant time.
+    let zero = BigInteger256::default();
+    let k_is_zero = ct_is_equal(&z0.into_repr(), &zero);
+    let k_is_minus_one = ct_is_equal(&z1.into_repr(), &zero);
+    let res = select(&res, &EdwardsProjective::zero(), k_is_zero);
+    select(&res, &(-base.into_projective()), k_is_minus_one)
+}
+
+/// Swap `a` and `b` if `flag` is 1, leave them unchanged if it is 0.
+fn cswap(a: &mut Fq, b: &mut Fq, flag: u64) {
+    let t = Fq::from(flag) * (*a - *b);
+    *a -= t;
+    *b += t;
+}
+
+/// Return `b` if `flag` is 1, and `a` if it is 0.
+fn select(
+    a: &EdwardsProjective,
+    b: &EdwardsProjective,
+    flag: u64,
+) -> EdwardsProjective {
+    let flag = Fq::from(flag);
+    EdwardsProjective::new(
+        a.x + flag * (b.x - a.x),
+        a.y + flag * (b.y - a.y),
+        a.t + flag * (b.t - a.t),
+        a.z + flag * (b.z - a.z),
+    )
+}
+
+/// 1 if `a == b`, 0 otherwise, without branching on the limbs.
+fn ct_is_equal(a: &BigInteger256, b: &BigInteger256) -> u64 {
+    let diff =
+        a.0.iter()
+            .zip(b.0.iter())
+            .fold(0u64, |acc, (a, b)| acc | (a ^ b));
+    ((diff | diff.wrapping_neg()) >> 63) ^ 1
+}
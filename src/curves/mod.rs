@@ -5,9 +5,82 @@ use ark_ec::{
 };
 use ark_ff::{field_new, Field};
 
+mod batch;
+mod batch_encoding;
+mod batch_verify;
+mod encoding;
+mod extended;
+mod fixed_base;
 mod glv;
-
-pub use glv::{multi_scalar_mul, GLVParameters};
+mod gnark;
+mod isogeny;
+mod ladder;
+mod montgomery;
+mod msm;
+mod select;
+mod small_msm;
+mod small_scalar;
+mod torsion;
+mod validate;
+mod weierstrass;
+
+pub use batch::{
+    add_affine, add_affine_denominator, add_affine_with_inverse,
+    batch_add_affine, batch_glv_mul, batch_normalize, sum_signed, sum_where,
+};
+pub(crate) use batch::{batch_glv_mul_into, batch_normalize_into};
+pub(crate) use batch_encoding::split_batch;
+pub use batch_encoding::{
+    deserialize_batch, serialize_batch, BatchDecodingError,
+};
+pub use batch_verify::BatchVerifier;
+pub(crate) use encoding::fq_from_le_bytes;
+pub use encoding::{
+    from_bytes_uncompressed, from_compressed_bytes, to_bytes_uncompressed,
+    to_compressed_bytes, Endianness, PointFromCoordinate, COMPRESSED_SIZE,
+    UNCOMPRESSED_SIZE,
+};
+pub use extended::{
+    add_complete, double_n, CachedAffine, CachedPoint, MulByPow2,
+};
+pub(crate) use fixed_base::generator_table_is_initialized;
+pub use fixed_base::{
+    generator_table, mul_by_generator, CombTable, PrecomputedTable,
+    GENERATOR_COMB_TEETH,
+};
+pub(crate) use glv::LAMBDA;
+pub use glv::{
+    apply_lambda, double_base_mul, lambda, lambda_squared, mul_bytes,
+    multi_scalar_mul, GLVParameters,
+};
+pub use gnark::{from_gnark_bytes, gnark_base_point, to_gnark_bytes};
+pub use isogeny::{
+    dual_isogeny, isogeny, IsogenousAffine, IsogenousParameters,
+    IsogenousProjective,
+};
+pub use ladder::ladder_mul;
+pub use montgomery::{
+    from_montgomery, to_montgomery, MontgomeryAffine, MONTGOMERY_A,
+    MONTGOMERY_B,
+};
+#[cfg(feature = "parallel")]
+pub use msm::msm_par_iter;
+pub use msm::{
+    msm, msm_bigint, msm_bigint_with_scratch, msm_bytes, msm_iter,
+    msm_with_generator, msm_with_scratch, MsmAccumulator, MsmAlgorithm,
+    MsmContext, MsmScratch, TuningProfile, DEFAULT_MSM_CHUNK_SIZE,
+};
+pub use select::ConditionalSelect;
+pub use small_msm::{strauss_msm, wnaf_msm};
+pub use small_scalar::SmallScalarMul;
+pub use torsion::{
+    is_small_order, is_torsion_free, torsion_component, torsion_points,
+    ClearCofactor,
+};
+pub use validate::{PointError, Validate, ValidatedPoint};
+pub use weierstrass::{
+    from_weierstrass, to_weierstrass, SWAffine, SWParameters, SWProjective,
+};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,151 @@
+//! The Montgomery model `B v^2 = u^3 + A u^2 + u` of bandersnatch, with
+//! `A = 2 (a + d) / (a - d)` and `B = 4 / (a - d)`, and the birational maps
+//! `(u, v) = ((1 + y) / (1 - y), u / x)` and
+//! `(x, y) = (u / v, (u - 1) / (u + 1))` from and to the twisted Edwards
+//! model.
+
+use crate::{batch_inverse, EdwardsAffine, EdwardsParameters, Fq};
+use ark_ec::models::MontgomeryModelParameters;
+use ark_ff::{Field, One, Zero};
+use ark_std::ops::{Add, Neg};
+
+/// The coefficient `A` of the Montgomery model.
+pub const MONTGOMERY_A: Fq =
+    <EdwardsParameters as MontgomeryModelParameters>::COEFF_A;
+
+/// The coefficient `B` of the Montgomery model.
+pub const MONTGOMERY_B: Fq =
+    <EdwardsParameters as MontgomeryModelParameters>::COEFF_B;
+
+/// A point of the Montgomery model, in affine coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MontgomeryAffine {
+    pub u: Fq,
+    pub v: Fq,
+    /// Whether this is the point at infinity, the identity.
+    pub infinity: bool,
+}
+
+impl MontgomeryAffine {
+    /// Build the affine point `(u, v)`; the coordinates are not checked.
+    pub const fn new(u: Fq, v: Fq) -> Self {
+        Self {
+            u,
+            v,
+            infinity: false,
+        }
+    }
+
+    /// The point at infinity.
+    pub fn zero() -> Self {
+        Self {
+            u: Fq::zero(),
+            v: Fq::zero(),
+            infinity: true,
+        }
+    }
+
+    /// Whether this is the point at infinity.
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    /// Whether the point satisfies the curve equation.
+    pub fn is_on_curve(&self) -> bool {
+        self.infinity
+            || MONTGOMERY_B * self.v.square()
+                == self.u
+                    * (self.u.square() + MONTGOMERY_A * self.u + Fq::one())
+    }
+}
+
+/// The chord-and-tangent addition of the Montgomery model, which, unlike
+/// the twisted Edwards addition, also covers the two points of order 2 at
+/// infinity of the twisted Edwards model.
+impl Add for MontgomeryAffine {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.infinity {
+            return other;
+        }
+        if other.infinity {
+            return self;
+        }
+        let l = if self.u == other.u {
+            if self.v == -other.v {
+                return Self::zero();
+            }
+            // tangent: (3 u^2 + 2 A u + 1) / (2 B v)
+            let u2 = self.u.square();
+            (u2.double() + u2 + MONTGOMERY_A.double() * self.u + Fq::one())
+                / (MONTGOMERY_B.double() * self.v)
+        } else {
+            (other.v - self.v) / (other.u - self.u)
+        };
+        let u = MONTGOMERY_B * l.square() - MONTGOMERY_A - self.u - other.u;
+        let v = l * (self.u - u) - self.v;
+        Self::new(u, v)
+    }
+}
+
+impl Neg for MontgomeryAffine {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            u: self.u,
+            v: -self.v,
+            infinity: self.infinity,
+        }
+    }
+}
+
+/// Map a twisted Edwards point to the Montgomery model.
+///
+/// The identity maps to the point at infinity, and the point `(0, -1)` of
+/// order 2 to `(0, 0)`.
+pub fn to_montgomery(p: &EdwardsAffine) -> MontgomeryAffine {
+    if p.x.is_zero() {
+        return if p.y.is_one() {
+            MontgomeryAffine::zero()
+        } else {
+            MontgomeryAffine::new(Fq::zero(), Fq::zero())
+        };
+    }
+    // u = (1 + y) / (1 - y) and v = u / x, with a single inversion
+    let one_minus_y = Fq::one() - p.y;
+    let inv = (one_minus_y * p.x).inverse().unwrap();
+    let u = (Fq::one() + p.y) * inv * p.x;
+    let v = (Fq::one() + p.y) * inv;
+    MontgomeryAffine::new(u, v)
+}
+
+/// Map a point of the Montgomery model to the twisted Edwards model.
+///
+/// Returns `None` for the two points `(u, 0)` with `u != 0` of order 2,
+/// which map to points at infinity of the twisted Edwards model, and for
+/// points that are not on the curve.
+pub fn from_montgomery(p: &MontgomeryAffine) -> Option<EdwardsAffine> {
+    if p.infinity {
+        return Some(EdwardsAffine::zero());
+    }
+    if !p.is_on_curve() {
+        return None;
+    }
+    if p.u.is_zero() {
+        return Some(EdwardsAffine::new(Fq::zero(), -Fq::one()));
+    }
+    // x = u / v and y = (u - 1) / (u + 1); on the curve, v = 0 only at the
+    // two other points of order 2, and u = -1 would need v^2 = d, which is
+    // not a square
+    let mut denominators = [p.v, p.u + Fq::one()];
+    if denominators.iter().any(|d| d.is_zero()) {
+        return None;
+    }
+    batch_inverse(&mut denominators);
+    Some(EdwardsAffine::new(
+        p.u * denominators[0],
+        (p.u - Fq::one()) * denominators[1],
+    ))
+}
@@ -0,0 +1,555 @@
+use crate::{
+    batch_glv_mul_into, batch_normalize_into, mul_by_generator, strauss_msm,
+    wnaf_msm, EdwardsAffine, EdwardsProjective, Fq, Fr, FrParameters,
+};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{BigInteger256, FpParameters, PrimeField, Zero};
+use ark_std::{cfg_chunks_mut, cfg_iter, cmp::min, vec::Vec};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Compute `sum_i scalars[i] * bases[i]` with Pippenger's bucket method.
+///
+/// If the two slices have different lengths, the longer one is truncated.
+/// With the `parallel` feature, the windows are distributed over the rayon
+/// thread pool; the result does not depend on the number of threads.
+pub fn msm(bases: &[EdwardsAffine], scalars: &[Fr]) -> EdwardsProjective {
+    let size = min(bases.len(), scalars.len());
+    let scalars: Vec<BigInteger256> =
+        cfg_iter!(scalars[..size]).map(|s| s.into_repr()).collect();
+    msm_bigint(&bases[..size], &scalars)
+}
+
+/// Compute `k G + sum_i scalars[i] * bases[i]`, where `G` is the prime
+/// subgroup generator, the common shape of verification equations.
+///
+/// The generator term uses the precomputed comb table of
+/// [`mul_by_generator`], and the other terms the algorithm suited to their
+/// number, see [`TuningProfile`]. If the two slices have different
+/// lengths, the longer one is truncated.
+pub fn msm_with_generator(
+    k: &Fr,
+    bases: &[EdwardsAffine],
+    scalars: &[Fr],
+) -> EdwardsProjective {
+    mul_by_generator(k) + TuningProfile::default().msm(bases, scalars)
+}
+
+/// Same as [`msm`], with the scalars already in their canonical
+/// (non-Montgomery) big integer representation.
+///
+/// The scalars are recoded with signed digits in `[-2^{c-1}, 2^{c-1})`,
+/// where `c` is the window size; since negating a point is almost free on
+/// a twisted Edwards curve, a negative digit adds the negated base to the
+/// bucket of its absolute value. This halves the number of buckets, and
+/// thus the cost of summing them, in every window.
+///
+/// The scalars must be smaller than the group order r, e.g., the output
+/// of [`PrimeField::into_repr`]: the digits only cover the bits of r, so
+/// that a larger integer gives a wrong sum. This is checked by a debug
+/// assertion only.
+pub fn msm_bigint(
+    bases: &[EdwardsAffine],
+    scalars: &[BigInteger256],
+) -> EdwardsProjective {
+    pippenger(bases, scalars, &mut MsmScratch::new())
+}
+
+/// Same as [`msm`], with the temporaries stored in `scratch`.
+pub fn msm_with_scratch(
+    bases: &[EdwardsAffine],
+    scalars: &[Fr],
+    scratch: &mut MsmScratch,
+) -> EdwardsProjective {
+    let size = min(bases.len(), scalars.len());
+    let mut repr = core::mem::take(&mut scratch.scalars);
+    repr.clear();
+    repr.extend(scalars[..size].iter().map(|s| s.into_repr()));
+    let res = pippenger(bases, &repr, scratch);
+    scratch.scalars = repr;
+    res
+}
+
+/// Same as [`msm_bigint`], with the temporaries stored in `scratch`; the
+/// scalars must also be smaller than r.
+pub fn msm_bigint_with_scratch(
+    bases: &[EdwardsAffine],
+    scalars: &[BigInteger256],
+    scratch: &mut MsmScratch,
+) -> EdwardsProjective {
+    pippenger(bases, scalars, scratch)
+}
+
+/// Reusable buffers for Pippenger's method: the scalars in canonical form,
+/// their signed digits, the buckets and the window sums.
+///
+/// Passing the same scratch space to [`msm_with_scratch`] or
+/// [`msm_bigint_with_scratch`] avoids allocating at every call once the
+/// buffers have grown to the size of the largest MSM, or from the first
+/// call with [`MsmScratch::with_capacity`]. With the `parallel` feature,
+/// the scratch space holds one bucket buffer per window, so that the
+/// windows are summed in parallel without allocating either.
+#[derive(Clone, Debug, Default)]
+pub struct MsmScratch {
+    scalars: Vec<BigInteger256>,
+    digits: Vec<i32>,
+    #[cfg(not(feature = "parallel"))]
+    buckets: Vec<EdwardsProjective>,
+    #[cfg(feature = "parallel")]
+    buckets: Vec<Vec<EdwardsProjective>>,
+    window_sums: Vec<EdwardsProjective>,
+}
+
+impl MsmScratch {
+    /// An empty scratch space, which grows to the largest MSM.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A scratch space large enough for MSMs of up to `size` terms.
+    pub fn with_capacity(size: usize) -> Self {
+        let c = window_size(size);
+        let num_windows = num_windows(c);
+        Self {
+            scalars: Vec::with_capacity(size),
+            digits: Vec::with_capacity(size * num_windows),
+            #[cfg(not(feature = "parallel"))]
+            buckets: Vec::with_capacity(1 << (c - 1)),
+            #[cfg(feature = "parallel")]
+            buckets: (0..num_windows)
+                .map(|_| Vec::with_capacity(1 << (c - 1)))
+                .collect(),
+            window_sums: Vec::with_capacity(num_windows),
+        }
+    }
+}
+
+/// The number of windows of `c` bits of the signed digits of a scalar.
+fn num_windows(c: usize) -> usize {
+    let num_bits = <FrParameters as FpParameters>::MODULUS_BITS as usize;
+    // one more bit for the carry of the highest digit
+    (num_bits + 1).div_ceil(c)
+}
+
+/// Pippenger's method, with the temporaries stored in `scratch`.
+fn pippenger(
+    bases: &[EdwardsAffine],
+    scalars: &[BigInteger256],
+    scratch: &mut MsmScratch,
+) -> EdwardsProjective {
+    let size = min(bases.len(), scalars.len());
+    let bases = &bases[..size];
+    let scalars = &scalars[..size];
+    debug_assert!(
+        scalars.iter().all(|s| *s < FrParameters::MODULUS),
+        "the scalars must be smaller than the group order"
+    );
+
+    let c = window_size(size);
+    let num_windows = num_windows(c);
+
+    let digits = &mut scratch.digits;
+    digits.clear();
+    digits.resize(size * num_windows, 0);
+    cfg_chunks_mut!(digits, num_windows)
+        .zip(scalars)
+        .for_each(|(digits, scalar)| signed_digits(scalar, c, digits));
+    let digits = &digits[..];
+
+    let zero = EdwardsProjective::zero();
+
+    // Each window of `c` bits is handled independently, and in parallel
+    // if the `parallel` feature is enabled.
+    let window_sums = &mut scratch.window_sums;
+    window_sums.clear();
+    #[cfg(feature = "parallel")]
+    {
+        let buckets = &mut scratch.buckets;
+        if buckets.len() < num_windows {
+            buckets.resize_with(num_windows, Vec::new);
+        }
+        window_sums.par_extend(
+            buckets[..num_windows].par_iter_mut().enumerate().map(
+                |(w, buckets)| {
+                    buckets.resize(1 << (c - 1), zero);
+                    window_sum(bases, digits, num_windows, w, buckets)
+                },
+            ),
+        );
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        let buckets = &mut scratch.buckets;
+        buckets.clear();
+        buckets.resize(1 << (c - 1), zero);
+        window_sums.extend(
+            (0..num_windows)
+                .map(|w| window_sum(bases, digits, num_windows, w, buckets)),
+        );
+    }
+
+    // combine the windows from the highest to the lowest
+    let lowest = window_sums[0];
+    lowest
+        + window_sums[1..]
+            .iter()
+            .rev()
+            .fold(zero, |mut total, sum_i| {
+                total += sum_i;
+                for _ in 0..c {
+                    total.double_in_place();
+                }
+                total
+            })
+}
+
+/// The sum of window `w`, using `buckets` as scratch space; there is no
+/// bucket for the digit 0.
+fn window_sum(
+    bases: &[EdwardsAffine],
+    digits: &[i32],
+    num_windows: usize,
+    w: usize,
+    buckets: &mut [EdwardsProjective],
+) -> EdwardsProjective {
+    let zero = EdwardsProjective::zero();
+    buckets.iter_mut().for_each(|b| *b = zero);
+    for (i, base) in bases.iter().enumerate() {
+        let digit = digits[i * num_windows + w];
+        if digit > 0 {
+            buckets[(digit - 1) as usize].add_assign_mixed(base);
+        } else if digit < 0 {
+            buckets[(-digit - 1) as usize].add_assign_mixed(&-*base);
+        }
+    }
+
+    // res = sum_i i * buckets[i - 1], via a running sum
+    let mut res = zero;
+    let mut running_sum = zero;
+    for b in buckets.iter().rev() {
+        running_sum += b;
+        res += &running_sum;
+    }
+    res
+}
+
+/// Recode `scalar` into `digits.len()` signed digits `d_w` in
+/// `[-2^{c-1}, 2^{c-1})`, such that `scalar = sum_w d_w 2^{w c}`.
+fn signed_digits(scalar: &BigInteger256, c: usize, digits: &mut [i32]) {
+    let limbs = scalar.as_ref();
+    let mut carry = 0;
+    for (w, out) in digits.iter_mut().enumerate() {
+        // the c bits starting at w * c
+        let (limb, offset) = (w * c / 64, w * c % 64);
+        let mut bits = limbs.get(limb).map_or(0, |l| l >> offset);
+        if offset + c > 64 {
+            bits |= limbs.get(limb + 1).map_or(0, |l| l << (64 - offset));
+        }
+        let digit = (bits & ((1 << c) - 1)) as i32 + carry;
+        if digit >= 1 << (c - 1) {
+            *out = digit - (1 << c);
+            carry = 1;
+        } else {
+            *out = digit;
+            carry = 0;
+        }
+    }
+}
+
+/// Same as [`msm`], with the scalars given as canonical 32-byte
+/// little-endian encodings, which are read directly into big integers
+/// without going through the Montgomery form of [`Fr`].
+///
+/// Returns `None` if a scalar is not canonical, i.e., not below the group
+/// order. If the two slices have different lengths, the longer one is
+/// truncated.
+pub fn msm_bytes(
+    bases: &[EdwardsAffine],
+    scalars: &[[u8; 32]],
+) -> Option<EdwardsProjective> {
+    let scalars = scalars
+        .iter()
+        .map(bigint_from_bytes)
+        .collect::<Option<Vec<_>>>()?;
+    Some(msm_bigint(bases, &scalars))
+}
+
+/// Read a canonical 32-byte little-endian scalar into a big integer, or
+/// return `None` if it is not below the group order.
+pub(crate) fn bigint_from_bytes(bytes: &[u8; 32]) -> Option<BigInteger256> {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(chunk);
+        *limb = u64::from_le_bytes(buf);
+    }
+    let scalar = BigInteger256::new(limbs);
+    if scalar < <FrParameters as FpParameters>::MODULUS {
+        Some(scalar)
+    } else {
+        None
+    }
+}
+
+/// Compute the sum of the terms `scalar * base` produced by `terms`.
+///
+/// The terms are consumed lazily, in chunks of [`DEFAULT_MSM_CHUNK_SIZE`],
+/// so that callers producing them on the fly (e.g., from a transcript or
+/// a decompression stream) do not need to collect them first.
+pub fn msm_iter<I>(terms: I) -> EdwardsProjective
+where
+    I: IntoIterator<Item = (EdwardsAffine, Fr)>,
+{
+    let mut acc = MsmAccumulator::new();
+    acc.extend(terms);
+    acc.finalize()
+}
+
+/// Same as [`msm_iter`], with the terms produced by a parallel iterator;
+/// each rayon task accumulates its own terms.
+#[cfg(feature = "parallel")]
+pub fn msm_par_iter<I>(terms: I) -> EdwardsProjective
+where
+    I: IntoParallelIterator<Item = (EdwardsAffine, Fr)>,
+{
+    terms
+        .into_par_iter()
+        .fold(MsmAccumulator::new, |mut acc, (base, scalar)| {
+            acc.push(base, scalar);
+            acc
+        })
+        .map(MsmAccumulator::finalize)
+        .sum()
+}
+
+/// Reusable buffers for repeated multi-scalar multiplications and batch
+/// operations, e.g., in a prover loop: the [`MsmScratch`] of Pippenger's
+/// method and the inverses of batch normalizations are kept between calls
+/// instead of being reallocated every time.
+#[derive(Clone, Debug, Default)]
+pub struct MsmContext {
+    scratch: MsmScratch,
+    inverses: Vec<Fq>,
+}
+
+impl MsmContext {
+    /// A context with empty buffers, which grow to the largest call.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Same as [`msm`].
+    pub fn msm(
+        &mut self,
+        bases: &[EdwardsAffine],
+        scalars: &[Fr],
+    ) -> EdwardsProjective {
+        msm_with_scratch(bases, scalars, &mut self.scratch)
+    }
+
+    /// Same as [`msm_bigint`]: the scalars must be smaller than r.
+    pub fn msm_bigint(
+        &mut self,
+        bases: &[EdwardsAffine],
+        scalars: &[BigInteger256],
+    ) -> EdwardsProjective {
+        pippenger(bases, scalars, &mut self.scratch)
+    }
+
+    /// Same as [`batch_normalize`](crate::batch_normalize), writing the
+    /// affine points to `out`.
+    pub fn batch_normalize(
+        &mut self,
+        points: &[EdwardsProjective],
+        out: &mut Vec<EdwardsAffine>,
+    ) {
+        batch_normalize_into(points, &mut self.inverses, out)
+    }
+
+    /// Same as [`batch_glv_mul`](crate::batch_glv_mul), writing the
+    /// products to `out`.
+    pub fn batch_glv_mul(
+        &mut self,
+        bases: &[EdwardsAffine],
+        scalars: &[Fr],
+        out: &mut Vec<EdwardsProjective>,
+    ) {
+        batch_glv_mul_into(bases, scalars, out)
+    }
+}
+
+/// The window size used by Pippenger's method for `size` terms,
+/// i.e., roughly `ln(size) + 2`.
+pub(crate) fn window_size(size: usize) -> usize {
+    if size < 32 {
+        3
+    } else {
+        // log2(size) * ln(2)
+        (ark_std::log2(size) * 69 / 100) as usize + 2
+    }
+}
+
+/// The default number of terms buffered by an [`MsmAccumulator`].
+pub const DEFAULT_MSM_CHUNK_SIZE: usize = 1 << 16;
+
+/// A streaming multi-scalar multiplication.
+///
+/// Terms are pushed one at a time and buffered; once `chunk_size` terms
+/// are buffered, they are folded into a running sum with [`msm`]. Memory
+/// usage is therefore bounded by the chunk size rather than by the total
+/// number of terms.
+///
+/// ```
+/// use ark_ec::AffineCurve;
+/// use bandersnatch::{EdwardsAffine, Fr, MsmAccumulator};
+///
+/// let g = EdwardsAffine::prime_subgroup_generator();
+/// let mut acc = MsmAccumulator::with_chunk_size(2);
+/// for i in 1..=5u64 {
+///     acc.push(g, Fr::from(i));
+/// }
+/// assert_eq!(acc.finalize(), g.mul(Fr::from(15u64)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MsmAccumulator {
+    chunk_size: usize,
+    bases: Vec<EdwardsAffine>,
+    scalars: Vec<Fr>,
+    sum: EdwardsProjective,
+}
+
+impl Default for MsmAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MsmAccumulator {
+    /// Create an accumulator with the default chunk size.
+    pub fn new() -> Self {
+        Self::with_chunk_size(DEFAULT_MSM_CHUNK_SIZE)
+    }
+
+    /// Create an accumulator that buffers at most `chunk_size` terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        Self {
+            chunk_size,
+            bases: Vec::new(),
+            scalars: Vec::new(),
+            sum: EdwardsProjective::zero(),
+        }
+    }
+
+    /// Add the term `scalar * base`.
+    pub fn push(&mut self, base: EdwardsAffine, scalar: Fr) {
+        self.bases.push(base);
+        self.scalars.push(scalar);
+        if self.bases.len() == self.chunk_size {
+            self.flush();
+        }
+    }
+
+    /// Fold the buffered terms into the running sum.
+    fn flush(&mut self) {
+        if !self.bases.is_empty() {
+            self.sum += &msm(&self.bases, &self.scalars);
+            self.bases.clear();
+            self.scalars.clear();
+        }
+    }
+
+    /// Return the sum of all the terms pushed so far.
+    pub fn finalize(mut self) -> EdwardsProjective {
+        self.flush();
+        self.sum
+    }
+}
+
+impl Extend<(EdwardsAffine, Fr)> for MsmAccumulator {
+    fn extend<I: IntoIterator<Item = (EdwardsAffine, Fr)>>(&mut self, iter: I) {
+        for (base, scalar) in iter {
+            self.push(base, scalar);
+        }
+    }
+}
+
+/// An algorithm for multi-scalar multiplications.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MsmAlgorithm {
+    /// [`strauss_msm`], for a handful of terms.
+    Strauss,
+    /// [`wnaf_msm`], for a few dozen terms.
+    Wnaf,
+    /// [`msm`], for many terms.
+    Pippenger,
+}
+
+impl MsmAlgorithm {
+    /// Run the algorithm on `bases` and `scalars`.
+    pub fn msm(
+        &self,
+        bases: &[EdwardsAffine],
+        scalars: &[Fr],
+    ) -> EdwardsProjective {
+        match self {
+            MsmAlgorithm::Strauss => strauss_msm(bases, scalars),
+            MsmAlgorithm::Wnaf => wnaf_msm(bases, scalars),
+            MsmAlgorithm::Pippenger => msm(bases, scalars),
+        }
+    }
+}
+
+/// The crossover points between the MSM algorithms: MSMs of up to
+/// `strauss_max` terms use the Strauss method, those of up to `wnaf_max`
+/// terms use interleaved wNAFs, and the larger ones use Pippenger's method.
+///
+/// A profile for the current machine can be measured with
+/// `tune::sweep_msm` (with the `std` feature).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TuningProfile {
+    /// The largest size handled by the Strauss method.
+    pub strauss_max: usize,
+    /// The largest size handled by interleaved wNAFs.
+    pub wnaf_max: usize,
+}
+
+impl Default for TuningProfile {
+    fn default() -> Self {
+        // measured on an x86-64 machine, where interleaved wNAFs beat the
+        // Strauss method at every size
+        Self {
+            strauss_max: 0,
+            wnaf_max: 128,
+        }
+    }
+}
+
+impl TuningProfile {
+    /// The algorithm used for an MSM of `size` terms.
+    pub fn algorithm(&self, size: usize) -> MsmAlgorithm {
+        if size <= self.strauss_max {
+            MsmAlgorithm::Strauss
+        } else if size <= self.wnaf_max {
+            MsmAlgorithm::Wnaf
+        } else {
+            MsmAlgorithm::Pippenger
+        }
+    }
+
+    /// Compute `sum_i scalars[i] * bases[i]` with the algorithm suited to
+    /// the number of terms.
+    pub fn msm(
+        &self,
+        bases: &[EdwardsAffine],
+        scalars: &[Fr],
+    ) -> EdwardsProjective {
+        self.algorithm(min(bases.len(), scalars.len()))
+            .msm(bases, scalars)
+    }
+}
@@ -0,0 +1,67 @@
+//! Constant-time conditional selection and negation of points, on top of
+//! the branch-free limb selection of `fields::ct`, so that ladders,
+//! regular recodings and signing code do not branch on secret bits.
+
+use crate::{conditional_assign, EdwardsAffine, EdwardsProjective, Fq};
+use subtle::Choice;
+
+/// Points that can be selected and negated without branching on the
+/// choice.
+pub trait ConditionalSelect: Sized {
+    /// Set `self` to `other` if `choice` is set, and leave it unchanged
+    /// otherwise.
+    fn conditional_assign(&mut self, other: &Self, choice: Choice);
+
+    /// Return `b` if `choice` is set, and `a` otherwise.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+
+    /// Negate `self` if `choice` is set.
+    fn conditional_negate(&mut self, choice: Choice);
+}
+
+impl ConditionalSelect for EdwardsAffine {
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        let choice = choice.unwrap_u8() as u64;
+        conditional_assign(&mut self.x, &other.x, choice);
+        conditional_assign(&mut self.y, &other.y, choice);
+    }
+
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut res = *a;
+        res.conditional_assign(b, choice);
+        res
+    }
+
+    fn conditional_negate(&mut self, choice: Choice) {
+        let choice = choice.unwrap_u8() as u64;
+        negate_if(&mut self.x, choice);
+    }
+}
+
+impl ConditionalSelect for EdwardsProjective {
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        let choice = choice.unwrap_u8() as u64;
+        conditional_assign(&mut self.x, &other.x, choice);
+        conditional_assign(&mut self.y, &other.y, choice);
+        conditional_assign(&mut self.t, &other.t, choice);
+        conditional_assign(&mut self.z, &other.z, choice);
+    }
+
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut res = *a;
+        res.conditional_assign(b, choice);
+        res
+    }
+
+    fn conditional_negate(&mut self, choice: Choice) {
+        let choice = choice.unwrap_u8() as u64;
+        negate_if(&mut self.x, choice);
+        negate_if(&mut self.t, choice);
+    }
+}
+
+#[inline]
+fn negate_if(x: &mut Fq, choice: u64) {
+    let neg = -*x;
+    conditional_assign(x, &neg, choice);
+}
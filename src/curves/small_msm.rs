@@ -0,0 +1,138 @@
+use crate::{
+    batch_normalize, double_n, CachedAffine, EdwardsAffine, EdwardsProjective,
+    Fr,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, BigInteger256, PrimeField, Zero};
+use ark_std::{cmp::min, vec::Vec};
+
+/// The window of the Strauss method.
+const STRAUSS_WINDOW: usize = 4;
+
+/// The width of the interleaved wNAF method.
+const WNAF_WIDTH: usize = 5;
+
+/// Compute `sum_i scalars[i] * bases[i]` with the Strauss method: the
+/// scalars are cut into 4-bit windows, processed together from the highest
+/// to the lowest with shared doublings, and each base has a table of its
+/// 16 first multiples.
+///
+/// This is the fastest method for a handful of terms. If the two slices
+/// have different lengths, the longer one is truncated.
+pub fn strauss_msm(
+    bases: &[EdwardsAffine],
+    scalars: &[Fr],
+) -> EdwardsProjective {
+    let size = min(bases.len(), scalars.len());
+    let scalars: Vec<BigInteger256> =
+        scalars[..size].iter().map(|s| s.into_repr()).collect();
+
+    // tables[i * 15 + j - 1] = j * bases[i]
+    let digits = (1 << STRAUSS_WINDOW) - 1;
+    let mut tables = Vec::with_capacity(size * digits);
+    for base in &bases[..size] {
+        let base = base.into_projective();
+        let mut multiple = base;
+        for _ in 0..digits {
+            tables.push(multiple);
+            multiple += base;
+        }
+    }
+    let tables: Vec<CachedAffine> = batch_normalize(&tables)
+        .into_iter()
+        .map(CachedAffine::from)
+        .collect();
+
+    let num_bits = scalars.iter().map(|s| s.num_bits()).max().unwrap_or(0);
+    let num_windows = (num_bits as usize).div_ceil(STRAUSS_WINDOW);
+    let mut res = EdwardsProjective::zero();
+    for w in (0..num_windows).rev() {
+        res = double_n(&res, STRAUSS_WINDOW);
+        for (i, scalar) in scalars.iter().enumerate() {
+            let mut digit = 0;
+            for bit in (0..STRAUSS_WINDOW).rev() {
+                digit = (digit << 1)
+                    | scalar.get_bit(w * STRAUSS_WINDOW + bit) as usize;
+            }
+            if digit != 0 {
+                res += &tables[i * digits + digit - 1];
+            }
+        }
+    }
+    res
+}
+
+/// Compute `sum_i scalars[i] * bases[i]` with interleaved width-5 wNAFs:
+/// the scalars are recoded into non-adjacent forms, processed together
+/// with shared doublings, and each base has a table of its 8 first odd
+/// multiples.
+///
+/// If the two slices have different lengths, the longer one is truncated.
+pub fn wnaf_msm(bases: &[EdwardsAffine], scalars: &[Fr]) -> EdwardsProjective {
+    let size = min(bases.len(), scalars.len());
+    let nafs: Vec<Vec<i8>> = scalars[..size]
+        .iter()
+        .map(|s| wnaf(s.into_repr()))
+        .collect();
+
+    // tables[i * 8 + j] = (2j + 1) * bases[i]
+    let odd = 1 << (WNAF_WIDTH - 2);
+    let mut tables = Vec::with_capacity(size * odd);
+    for base in &bases[..size] {
+        let base = base.into_projective();
+        let double = base.double();
+        let mut multiple = base;
+        for _ in 0..odd {
+            tables.push(multiple);
+            multiple += double;
+        }
+    }
+    let tables: Vec<CachedAffine> = batch_normalize(&tables)
+        .into_iter()
+        .map(CachedAffine::from)
+        .collect();
+
+    let len = nafs.iter().map(|naf| naf.len()).max().unwrap_or(0);
+    let mut res = EdwardsProjective::zero();
+    for bit in (0..len).rev() {
+        res.double_in_place();
+        for (i, naf) in nafs.iter().enumerate() {
+            let digit = naf.get(bit).copied().unwrap_or(0);
+            if digit != 0 {
+                let multiple =
+                    &tables[i * odd + digit.unsigned_abs() as usize / 2];
+                if digit > 0 {
+                    res += multiple;
+                } else {
+                    res -= multiple;
+                }
+            }
+        }
+    }
+    res
+}
+
+/// The width-5 non-adjacent form of a scalar below the group order, from
+/// the lowest digit to the highest non-zero one.
+fn wnaf(mut scalar: BigInteger256) -> Vec<i8> {
+    let window = 1u64 << WNAF_WIDTH;
+    let mut digits = Vec::with_capacity(254);
+    while !scalar.is_zero() {
+        let mut digit = 0;
+        if scalar.is_odd() {
+            digit = (scalar.0[0] % window) as i8;
+            if digit >= (window / 2) as i8 {
+                digit -= window as i8;
+            }
+            // the scalar is below 2^253, so neither operation overflows
+            if digit > 0 {
+                scalar.sub_noborrow(&BigInteger256::from(digit as u64));
+            } else {
+                scalar.add_nocarry(&BigInteger256::from(-digit as u64));
+            }
+        }
+        digits.push(digit);
+        scalar.div2();
+    }
+    digits
+}
@@ -0,0 +1,92 @@
+use crate::{batch_normalize, EdwardsAffine, EdwardsProjective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::Zero;
+use ark_std::vec::Vec;
+
+/// The width of the wNAF used for small scalars.
+const WNAF_WIDTH: usize = 4;
+
+/// Multiplication by small scalars, which only processes the actual bit
+/// length of the scalar instead of the 253 bits of a full scalar.
+///
+/// This is meant for public scalars known to be small, such as indices,
+/// counters or truncated challenges; it is not constant time.
+pub trait SmallScalarMul {
+    /// Compute `scalar * self`.
+    fn mul_u64(&self, scalar: u64) -> EdwardsProjective {
+        self.mul_u128(scalar as u128)
+    }
+
+    /// Compute `scalar * self`.
+    fn mul_u128(&self, scalar: u128) -> EdwardsProjective;
+}
+
+impl SmallScalarMul for EdwardsAffine {
+    fn mul_u128(&self, scalar: u128) -> EdwardsProjective {
+        wnaf_mul(self.into_projective(), scalar)
+    }
+}
+
+impl SmallScalarMul for EdwardsProjective {
+    fn mul_u128(&self, scalar: u128) -> EdwardsProjective {
+        wnaf_mul(*self, scalar)
+    }
+}
+
+/// Compute `scalar * base` with a width-4 wNAF over the bit length of
+/// `scalar`.
+fn wnaf_mul(base: EdwardsProjective, scalar: u128) -> EdwardsProjective {
+    if scalar == 0 || base.is_zero() {
+        return EdwardsProjective::zero();
+    }
+    let digits = wnaf(scalar);
+
+    // table[j] = (2j + 1) * base
+    let double = base.double();
+    let mut table = [base; 1 << (WNAF_WIDTH - 2)];
+    for j in 1..table.len() {
+        table[j] = table[j - 1] + double;
+    }
+    let table = batch_normalize(&table);
+
+    let mut res = EdwardsProjective::zero();
+    for digit in digits.iter().rev() {
+        res.double_in_place();
+        if *digit > 0 {
+            res.add_assign_mixed(&table[(*digit as usize - 1) / 2]);
+        } else if *digit < 0 {
+            res.add_assign_mixed(&-table[(-*digit as usize - 1) / 2]);
+        }
+    }
+    res
+}
+
+/// The width-4 non-adjacent form of `scalar`, from the lowest digit to the
+/// highest non-zero one: odd digits in `(-8, 8)`, each followed by at
+/// least three zeros.
+fn wnaf(mut scalar: u128) -> Vec<i8> {
+    let window = 1u128 << WNAF_WIDTH;
+    let mut digits = Vec::with_capacity(129);
+    // the bit 128, set if rounding a digit up overflows
+    let mut high = false;
+    while scalar != 0 || high {
+        let mut digit = 0;
+        if scalar & 1 == 1 {
+            digit = (scalar % window) as i8;
+            if digit >= (window / 2) as i8 {
+                digit -= window as i8;
+            }
+            if digit > 0 {
+                scalar -= digit as u128;
+            } else {
+                let (sum, overflow) = scalar.overflowing_add(-digit as u128);
+                scalar = sum;
+                high |= overflow;
+            }
+        }
+        digits.push(digit);
+        scalar = (scalar >> 1) | ((high as u128) << 127);
+        high = false;
+    }
+    digits
+}
@@ -0,0 +1,136 @@
+//! The points of small order of bandersnatch, and the torsion component of
+//! arbitrary points.
+//!
+//! The group of points is `Z/r x Z/2 x Z/2`: besides the identity, the
+//! torsion subgroup has three points of order 2, and no point of order 4
+//! or 8. Only `(0, -1)` is affine in the twisted Edwards model; the two
+//! others lie at infinity, since `d / a` is a square. All four are affine
+//! in the Montgomery model, `O`, `(0, 0)`, `(u1, 0)` and `(u2, 0)`, where
+//! `u1` and `u2` are the roots of `u^2 + A u + 1`, so that the torsion
+//! points are given as [`MontgomeryAffine`] points.
+//!
+//! The torsion component of a point `P = P_r + T`, with `P_r` of order `r`
+//! and `T` of small order, is found without multiplying by `r`, with the
+//! 2-descent homomorphism `E / 2E -> (Fq* / Fq*^2)^2`, i.e., two Legendre
+//! symbols, since `2E` is the subgroup of order `r`.
+
+use crate::{
+    batch_legendre, to_montgomery, EdwardsAffine, EdwardsProjective, Fq,
+    MontgomeryAffine, MulByPow2, MONTGOMERY_B,
+};
+use ark_ff::{field_new, LegendreSymbol, One, SquareRootField, Zero};
+use ark_std::vec::Vec;
+
+/// Multiplication by the cofactor 4, which maps any point of the curve to
+/// the prime order subgroup, e.g., to sanitize untrusted points or the
+/// output of a map to the curve.
+pub trait ClearCofactor {
+    /// Compute `4 P` with two doublings.
+    fn clear_cofactor(&self) -> EdwardsProjective;
+}
+
+impl ClearCofactor for EdwardsProjective {
+    fn clear_cofactor(&self) -> EdwardsProjective {
+        self.mul_by_pow_2(2)
+    }
+}
+
+impl ClearCofactor for EdwardsAffine {
+    fn clear_cofactor(&self) -> EdwardsProjective {
+        EdwardsProjective::from(*self).clear_cofactor()
+    }
+}
+
+/// The smaller root of `u^2 + A u + 1`; the other one is `u1 + 2`.
+const U1: Fq = field_new!(
+    Fq,
+    "37446463827641770816307242315180085052603635617490163568005256780843403514036"
+);
+
+/// The larger root of `u^2 + A u + 1`.
+const U2: Fq = field_new!(
+    Fq,
+    "37446463827641770816307242315180085052603635617490163568005256780843403514038"
+);
+
+/// The four torsion points, `O`, `(0, 0)`, `(u1, 0)` and `(u2, 0)`, in the
+/// Montgomery model.
+pub fn torsion_points() -> [MontgomeryAffine; 4] {
+    [
+        MontgomeryAffine::zero(),
+        MontgomeryAffine::new(Fq::zero(), Fq::zero()),
+        MontgomeryAffine::new(U1, Fq::zero()),
+        MontgomeryAffine::new(U2, Fq::zero()),
+    ]
+}
+
+/// Whether `p` has small order, i.e., is the identity or `(0, -1)`, the
+/// only affine points of small order of the twisted Edwards model.
+pub fn is_small_order(p: &EdwardsAffine) -> bool {
+    p.x.is_zero()
+}
+
+/// The torsion component `T` of `p = P_r + T`, in the Montgomery model.
+///
+/// `p` must be on the curve.
+pub fn torsion_component(p: &EdwardsAffine) -> MontgomeryAffine {
+    let points = torsion_points();
+    if is_small_order(p) {
+        return to_montgomery(p);
+    }
+    // (x - e0, x - e1) modulo squares on y^2 = (x - e0)(x - e1)(x - e2),
+    // with x = B u and (e0, e1, e2) = (0, B u1, B u2); for the torsion
+    // points, these are (1, 1), (1, -1), (-1, 1) and (-1, -1).
+    let u = to_montgomery(p).u;
+    let first = is_square(&(MONTGOMERY_B * u));
+    let second = is_square(&(MONTGOMERY_B * (u - U1)));
+    match (first, second) {
+        (true, true) => points[0],
+        (true, false) => points[1],
+        (false, true) => points[2],
+        (false, false) => points[3],
+    }
+}
+
+/// Whether `p` has no torsion component, i.e., is in the prime order
+/// subgroup; `p` must be on the curve.
+///
+/// This costs two Legendre symbols, instead of a multiplication by `r`.
+pub fn is_torsion_free(p: &EdwardsAffine) -> bool {
+    torsion_component(p).is_zero()
+}
+
+/// [`is_torsion_free`] for many points at once, with the Legendre symbols
+/// computed by [`batch_legendre`] and without any inversion: since
+/// `u = (1 + y) / (1 - y)`, the symbols of `B u` and `B (u - u1)` are the
+/// ones of their products with `(1 - y)^2`.
+pub(crate) fn batch_is_torsion_free(points: &[EdwardsAffine]) -> Vec<bool> {
+    let mut values = Vec::with_capacity(2 * points.len());
+    for p in points {
+        if is_small_order(p) {
+            // placeholders, the result only depends on y
+            values.extend([Fq::one(), Fq::one()]);
+            continue;
+        }
+        let (one_plus_y, one_minus_y) = (Fq::one() + p.y, Fq::one() - p.y);
+        values.push(MONTGOMERY_B * one_plus_y * one_minus_y);
+        values
+            .push(MONTGOMERY_B * (one_plus_y - U1 * one_minus_y) * one_minus_y);
+    }
+    let symbols = batch_legendre(&values);
+    points
+        .iter()
+        .zip(symbols.chunks(2))
+        .map(|(p, symbols)| {
+            if is_small_order(p) {
+                p.y.is_one()
+            } else {
+                symbols == [1, 1]
+            }
+        })
+        .collect()
+}
+
+fn is_square(x: &Fq) -> bool {
+    x.legendre() == LegendreSymbol::QuadraticResidue
+}
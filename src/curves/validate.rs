@@ -0,0 +1,167 @@
+//! A single entry point to the validation of untrusted points, with errors
+//! that tell the failure modes apart, and a [`ValidatedPoint`] newtype for
+//! APIs that require points of the prime order subgroup.
+
+use super::encoding::fq_from_le_bytes;
+use crate::{
+    is_torsion_free, to_compressed_bytes, EdwardsAffine, Endianness, Fq,
+    PointFromCoordinate, COMPRESSED_SIZE, UNCOMPRESSED_SIZE,
+};
+use ark_ff::{Field, One};
+use ark_std::{
+    fmt,
+    ops::{Add, Neg, Sub},
+};
+
+/// The reasons why a point is rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointError {
+    /// The point does not satisfy the curve equation.
+    NotOnCurve,
+    /// The point is on the curve, but not in the prime order subgroup.
+    NotInPrimeSubgroup,
+    /// The encoding is not the canonical encoding of any point, e.g., a
+    /// coordinate is not smaller than `q`.
+    NonCanonicalEncoding,
+}
+
+impl fmt::Display for PointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PointError::NotOnCurve => write!(f, "point not on the curve"),
+            PointError::NotInPrimeSubgroup => {
+                write!(f, "point not in the prime order subgroup")
+            }
+            PointError::NonCanonicalEncoding => {
+                write!(f, "non-canonical point encoding")
+            }
+        }
+    }
+}
+
+/// Validation of points.
+pub trait Validate {
+    /// Check that the point is on the curve and in the prime order
+    /// subgroup.
+    fn validate(&self) -> Result<(), PointError>;
+}
+
+impl Validate for EdwardsAffine {
+    fn validate(&self) -> Result<(), PointError> {
+        if !self.is_on_curve() {
+            return Err(PointError::NotOnCurve);
+        }
+        if !is_torsion_free(self) {
+            return Err(PointError::NotInPrimeSubgroup);
+        }
+        Ok(())
+    }
+}
+
+/// A point that is known to be on the curve and in the prime order
+/// subgroup.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ValidatedPoint(EdwardsAffine);
+
+impl ValidatedPoint {
+    /// Validate a point.
+    pub fn new(point: EdwardsAffine) -> Result<Self, PointError> {
+        point.validate()?;
+        Ok(Self(point))
+    }
+
+    /// Wrap a point that is known to be in the prime order subgroup,
+    /// e.g., a multiple of the generator.
+    pub(crate) fn new_unchecked(point: EdwardsAffine) -> Self {
+        Self(point)
+    }
+
+    /// The underlying point.
+    pub fn as_affine(&self) -> &EdwardsAffine {
+        &self.0
+    }
+
+    /// Unwrap the underlying point.
+    pub fn into_affine(self) -> EdwardsAffine {
+        self.0
+    }
+
+    /// Decode and validate a point encoded with
+    /// [`to_compressed_bytes`](crate::to_compressed_bytes).
+    pub fn from_compressed_bytes(
+        bytes: &[u8; COMPRESSED_SIZE],
+    ) -> Result<Self, PointError> {
+        let odd = bytes[COMPRESSED_SIZE - 1] >> 7 == 1;
+        let mut y = [0u8; 32];
+        y.copy_from_slice(bytes);
+        y[COMPRESSED_SIZE - 1] &= 0x7f;
+        let y = canonical_fq(&y)?;
+        match EdwardsAffine::from_y_with_sign(y, odd) {
+            Some(point) => Self::new(point),
+            // x = 0, i.e., y = 1 or y = -1, with the parity bit set
+            None if odd && y.square() == Fq::one() => {
+                Err(PointError::NonCanonicalEncoding)
+            }
+            None => Err(PointError::NotOnCurve),
+        }
+    }
+
+    /// Decode and validate a point encoded with
+    /// [`to_bytes_uncompressed`](crate::to_bytes_uncompressed).
+    pub fn from_bytes_uncompressed(
+        bytes: &[u8; UNCOMPRESSED_SIZE],
+        endianness: Endianness,
+    ) -> Result<Self, PointError> {
+        let mut x = [0u8; 32];
+        let mut y = [0u8; 32];
+        x.copy_from_slice(&bytes[..32]);
+        y.copy_from_slice(&bytes[32..]);
+        if endianness == Endianness::Big {
+            x.reverse();
+            y.reverse();
+        }
+        Self::new(EdwardsAffine::new(canonical_fq(&x)?, canonical_fq(&y)?))
+    }
+
+    /// The compressed encoding of the point.
+    pub fn to_compressed_bytes(&self) -> [u8; COMPRESSED_SIZE] {
+        to_compressed_bytes(&self.0)
+    }
+}
+
+impl From<ValidatedPoint> for EdwardsAffine {
+    fn from(point: ValidatedPoint) -> Self {
+        point.0
+    }
+}
+
+/// The prime order subgroup is closed under addition, so that sums and
+/// differences of validated points need no check.
+impl Add for ValidatedPoint {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub for ValidatedPoint {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 + (-other.0))
+    }
+}
+
+impl Neg for ValidatedPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+/// Read a canonical little-endian field element.
+fn canonical_fq(bytes: &[u8; 32]) -> Result<Fq, PointError> {
+    fq_from_le_bytes(bytes).ok_or(PointError::NonCanonicalEncoding)
+}
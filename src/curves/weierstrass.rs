@@ -0,0 +1,99 @@
+//! The short Weierstrass model `y^2 = x^3 + a x + b` of bandersnatch,
+//! obtained from the Montgomery model `B v^2 = u^3 + A u^2 + u` with
+//! `x = u / B + A / (3B)` and `y = v / B`, so that
+//! `a = (3 - A^2) / (3 B^2)` and `b = (2 A^3 - 9 A) / (27 B^3)`.
+
+use crate::{
+    from_montgomery, to_montgomery, EdwardsAffine, Fq, Fr, MontgomeryAffine,
+    MONTGOMERY_B,
+};
+use ark_ec::models::{
+    short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    ModelParameters, SWModelParameters,
+};
+use ark_ff::{field_new, Zero};
+
+pub type SWAffine = GroupAffine<SWParameters>;
+pub type SWProjective = GroupProjective<SWParameters>;
+
+/// Bandersnatch in short Weierstrass form.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SWParameters;
+
+impl ModelParameters for SWParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for SWParameters {
+    /// COEFF_A = (3 - A^2) / (3 B^2)
+    const COEFF_A: Fq = field_new!(
+        Fq,
+        "10773120815616481058602537765553212789256758185246796157495669123169359657269"
+    );
+
+    /// COEFF_B = (2 A^3 - 9 A) / (27 B^3)
+    const COEFF_B: Fq = field_new!(
+        Fq,
+        "29569587568322301171008055308580903175558631321415017492731745847794083609535"
+    );
+
+    /// COFACTOR = 4
+    const COFACTOR: &'static [u64] = &[4];
+
+    /// COFACTOR^(-1) mod r =
+    /// 9831726595336160714896451345284868594481866920080427688839802480047265754601
+    const COFACTOR_INV: Fr = field_new!(
+        Fr,
+        "9831726595336160714896451345284868594481866920080427688839802480047265754601"
+    );
+
+    /// AFFINE_GENERATOR_COEFFS: the image of the twisted Edwards generator
+    const AFFINE_GENERATOR_COEFFS: (Fq, Fq) = (GENERATOR_X, GENERATOR_Y);
+}
+
+const GENERATOR_X: Fq = field_new!(
+    Fq,
+    "36825263486403546626300966120264772870284111435429821597925698003262359905220"
+);
+
+const GENERATOR_Y: Fq = field_new!(
+    Fq,
+    "3865016222540273726364778390358771198398896682531094491691896377074200900624"
+);
+
+/// The shift `A / (3B)` from `u / B` to `x`.
+const SHIFT: Fq = field_new!(
+    Fq,
+    "42460977304182762931716743824405123254375045638571669698531889431804823178961"
+);
+
+/// `1 / B`.
+const B_INV: Fq = field_new!(
+    Fq,
+    "41180284393978236561320365279764246793818536543197771097409483252169927600582"
+);
+
+/// Map a twisted Edwards point to the short Weierstrass model.
+pub fn to_weierstrass(p: &EdwardsAffine) -> SWAffine {
+    let m = to_montgomery(p);
+    if m.is_zero() {
+        return SWAffine::zero();
+    }
+    SWAffine::new(m.u * B_INV + SHIFT, m.v * B_INV, false)
+}
+
+/// Map a point of the short Weierstrass model to the twisted Edwards
+/// model.
+///
+/// Returns `None` for the two points of order 2 other than `(A / (3B), 0)`,
+/// which map to points at infinity of the twisted Edwards model, and for
+/// points that are not on the curve.
+pub fn from_weierstrass(q: &SWAffine) -> Option<EdwardsAffine> {
+    if q.infinity {
+        return Some(EdwardsAffine::zero());
+    }
+    let u = (q.x - SHIFT) * MONTGOMERY_B;
+    let v = q.y * MONTGOMERY_B;
+    from_montgomery(&MontgomeryAffine::new(u, v))
+}
@@ -0,0 +1,72 @@
+/*
+ * C interface of the bandersnatch crate, built with
+ *
+ *     cargo rustc --release --features ffi --crate-type cdylib
+ *
+ * Points of bandersnatch are 32-byte compressed encodings, scalars 32-byte
+ * little-endian canonical integers, and Banderwagon elements their 32-byte
+ * encoding. Every function returns BANDERSNATCH_OK or a negative error
+ * code, and writes its output only on success.
+ *
+ * Keep in sync with src/ffi/mod.rs.
+ */
+
+#ifndef BANDERSNATCH_H
+#define BANDERSNATCH_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define BANDERSNATCH_OK 0
+#define BANDERSNATCH_ERR_NULL_POINTER -1
+#define BANDERSNATCH_ERR_INVALID_POINT -2
+#define BANDERSNATCH_ERR_NOT_IN_SUBGROUP -3
+#define BANDERSNATCH_ERR_INVALID_SCALAR -4
+
+/* Decompress a point into its little-endian affine coordinates x || y. */
+int32_t bandersnatch_decompress(const uint8_t compressed[32],
+                                uint8_t uncompressed[64]);
+
+/* Check that a point is on the curve and in the prime order subgroup. */
+int32_t bandersnatch_subgroup_check(const uint8_t compressed[32]);
+
+/* out = scalar * point, for a point of the prime order subgroup. */
+int32_t bandersnatch_scalar_mul(const uint8_t point[32],
+                                const uint8_t scalar[32],
+                                uint8_t out[32]);
+
+/* out = sum_i scalars[i] * points[i], for points of the prime order
+ * subgroup; the arrays may be null if len is zero. */
+int32_t bandersnatch_msm(const uint8_t (*points)[32],
+                         const uint8_t (*scalars)[32],
+                         size_t len,
+                         uint8_t out[32]);
+
+/* Check that bytes are the encoding of a Banderwagon element. */
+int32_t banderwagon_validate(const uint8_t element[32]);
+
+/* out = scalar * element. */
+int32_t banderwagon_scalar_mul(const uint8_t element[32],
+                               const uint8_t scalar[32],
+                               uint8_t out[32]);
+
+/* out = sum_i scalars[i] * elements[i]; the arrays may be null if len is
+ * zero. */
+int32_t banderwagon_msm(const uint8_t (*elements)[32],
+                        const uint8_t (*scalars)[32],
+                        size_t len,
+                        uint8_t out[32]);
+
+/* Map an element to the little-endian scalar of x / y. */
+int32_t banderwagon_map_to_scalar_field(const uint8_t element[32],
+                                        uint8_t out[32]);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BANDERSNATCH_H */
@@ -0,0 +1,247 @@
+//! A C ABI for the core operations, behind the `ffi` feature, so that
+//! C, C++ or Nim clients can link this implementation rather than port it.
+//!
+//! The declarations are in `src/ffi/bandersnatch.h`. The shared library is
+//! built with
+//!
+//! ```text
+//! cargo rustc --release --features ffi --crate-type cdylib
+//! ```
+//!
+//! All values cross the boundary as little-endian byte arrays: points of
+//! bandersnatch as their 32-byte compressed encoding, see
+//! [`to_compressed_bytes`], scalars as their canonical 32-byte integers,
+//! and Banderwagon elements as their 32-byte encoding, see
+//! [`Element::to_bytes`], which is big-endian. Every function returns
+//! [`BANDERSNATCH_OK`] or a negative error code, and writes its output
+//! only on success. Inputs are validated: points must be on the curve
+//! and, for arithmetic, in the prime order subgroup.
+//!
+//! This is the only module with `unsafe` code, to read the arrays of
+//! [`bandersnatch_msm`] and [`banderwagon_msm`] from raw pointers; the
+//! crate forbids it without the feature.
+
+#![allow(unsafe_code)]
+
+use crate::{
+    banderwagon::Element, from_compressed_bytes, msm, to_bytes_uncompressed,
+    to_compressed_bytes, EdwardsAffine, EdwardsParameters, Endianness,
+    FieldBytes, Fr, GLVParameters, PointError, Validate, COMPRESSED_SIZE,
+    UNCOMPRESSED_SIZE,
+};
+use ark_ec::ProjectiveCurve;
+use ark_std::{slice, vec::Vec};
+
+#[cfg(test)]
+mod tests;
+
+/// Success.
+pub const BANDERSNATCH_OK: i32 = 0;
+/// A pointer argument is null.
+pub const BANDERSNATCH_ERR_NULL_POINTER: i32 = -1;
+/// An encoding is not canonical or not a point of the curve.
+pub const BANDERSNATCH_ERR_INVALID_POINT: i32 = -2;
+/// A point is not in the prime order subgroup.
+pub const BANDERSNATCH_ERR_NOT_IN_SUBGROUP: i32 = -3;
+/// A scalar is not smaller than the group order.
+pub const BANDERSNATCH_ERR_INVALID_SCALAR: i32 = -4;
+
+type Bytes32 = [u8; COMPRESSED_SIZE];
+
+fn error_code(err: PointError) -> i32 {
+    match err {
+        PointError::NotInPrimeSubgroup => BANDERSNATCH_ERR_NOT_IN_SUBGROUP,
+        PointError::NotOnCurve | PointError::NonCanonicalEncoding => {
+            BANDERSNATCH_ERR_INVALID_POINT
+        }
+    }
+}
+
+/// Run `f` on the inputs and write its output, or return the error code.
+fn run<T, F>(out: Option<&mut T>, f: F) -> i32
+where
+    F: FnOnce() -> Result<T, i32>,
+{
+    let out = match out {
+        Some(out) => out,
+        None => return BANDERSNATCH_ERR_NULL_POINTER,
+    };
+    match f() {
+        Ok(value) => {
+            *out = value;
+            BANDERSNATCH_OK
+        }
+        Err(code) => code,
+    }
+}
+
+fn required<T>(arg: Option<&T>) -> Result<&T, i32> {
+    arg.ok_or(BANDERSNATCH_ERR_NULL_POINTER)
+}
+
+fn point(bytes: Option<&Bytes32>) -> Result<EdwardsAffine, i32> {
+    from_compressed_bytes(required(bytes)?)
+        .ok_or(BANDERSNATCH_ERR_INVALID_POINT)
+}
+
+fn subgroup_point(bytes: Option<&Bytes32>) -> Result<EdwardsAffine, i32> {
+    let p = point(bytes)?;
+    p.validate().map_err(error_code)?;
+    Ok(p)
+}
+
+fn scalar(bytes: &Bytes32) -> Result<Fr, i32> {
+    Fr::from_bytes_le(bytes).ok_or(BANDERSNATCH_ERR_INVALID_SCALAR)
+}
+
+fn element(bytes: &Bytes32) -> Result<Element, i32> {
+    Element::from_bytes(bytes).map_err(error_code)
+}
+
+/// Read `len` 32-byte arrays starting at `ptr`, which may be null if
+/// `len` is zero.
+///
+/// # Safety
+///
+/// If `len` is nonzero, `ptr` must point to `32 len` readable bytes.
+unsafe fn arrays<'a>(ptr: *const Bytes32, len: usize) -> &'a [Bytes32] {
+    if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len)
+    }
+}
+
+/// Decompress a point into its little-endian affine coordinates `x || y`.
+#[no_mangle]
+pub extern "C" fn bandersnatch_decompress(
+    compressed: Option<&Bytes32>,
+    uncompressed: Option<&mut [u8; UNCOMPRESSED_SIZE]>,
+) -> i32 {
+    run(uncompressed, || {
+        Ok(to_bytes_uncompressed(
+            &point(compressed)?,
+            Endianness::Little,
+        ))
+    })
+}
+
+/// Check that a compressed point is on the curve and in the prime order
+/// subgroup.
+#[no_mangle]
+pub extern "C" fn bandersnatch_subgroup_check(
+    compressed: Option<&Bytes32>,
+) -> i32 {
+    match subgroup_point(compressed) {
+        Ok(_) => BANDERSNATCH_OK,
+        Err(code) => code,
+    }
+}
+
+/// Compute `scalar * point`, for a point of the prime order subgroup.
+#[no_mangle]
+pub extern "C" fn bandersnatch_scalar_mul(
+    point: Option<&Bytes32>,
+    scalar: Option<&Bytes32>,
+    out: Option<&mut Bytes32>,
+) -> i32 {
+    run(out, || {
+        let p = subgroup_point(point)?;
+        let s = self::scalar(required(scalar)?)?;
+        let product = EdwardsParameters::glv_mul(&p, &s).into_affine();
+        Ok(to_compressed_bytes(&product))
+    })
+}
+
+/// Compute `sum_i scalars[i] * points[i]` for `len` points of the prime
+/// order subgroup.
+///
+/// # Safety
+///
+/// `points` and `scalars` must each point to `32 len` readable bytes; they
+/// may be null if `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn bandersnatch_msm(
+    points: *const Bytes32,
+    scalars: *const Bytes32,
+    len: usize,
+    out: Option<&mut Bytes32>,
+) -> i32 {
+    if len != 0 && (points.is_null() || scalars.is_null()) {
+        return BANDERSNATCH_ERR_NULL_POINTER;
+    }
+    let (points, scalars) = (arrays(points, len), arrays(scalars, len));
+    run(out, || {
+        let bases = points
+            .iter()
+            .map(|p| subgroup_point(Some(p)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let scalars =
+            scalars.iter().map(scalar).collect::<Result<Vec<_>, _>>()?;
+        Ok(to_compressed_bytes(&msm(&bases, &scalars).into_affine()))
+    })
+}
+
+/// Check that bytes are the encoding of a Banderwagon element.
+#[no_mangle]
+pub extern "C" fn banderwagon_validate(element: Option<&Bytes32>) -> i32 {
+    match required(element).and_then(self::element) {
+        Ok(_) => BANDERSNATCH_OK,
+        Err(code) => code,
+    }
+}
+
+/// Compute `scalar * element` in Banderwagon.
+#[no_mangle]
+pub extern "C" fn banderwagon_scalar_mul(
+    element: Option<&Bytes32>,
+    scalar: Option<&Bytes32>,
+    out: Option<&mut Bytes32>,
+) -> i32 {
+    run(out, || {
+        let e = self::element(required(element)?)?;
+        let s = self::scalar(required(scalar)?)?;
+        Ok((e * s).to_bytes())
+    })
+}
+
+/// Compute `sum_i scalars[i] * elements[i]` in Banderwagon.
+///
+/// # Safety
+///
+/// `elements` and `scalars` must each point to `32 len` readable bytes;
+/// they may be null if `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn banderwagon_msm(
+    elements: *const Bytes32,
+    scalars: *const Bytes32,
+    len: usize,
+    out: Option<&mut Bytes32>,
+) -> i32 {
+    if len != 0 && (elements.is_null() || scalars.is_null()) {
+        return BANDERSNATCH_ERR_NULL_POINTER;
+    }
+    let (elements, scalars) = (arrays(elements, len), arrays(scalars, len));
+    run(out, || {
+        let elements = Element::batch_from_bytes(elements)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(error_code)?;
+        let scalars =
+            scalars.iter().map(scalar).collect::<Result<Vec<_>, _>>()?;
+        Ok(Element::msm(&elements, &scalars).to_bytes())
+    })
+}
+
+/// Map a Banderwagon element to its little-endian scalar, see
+/// [`Element::map_to_scalar_field`].
+#[no_mangle]
+pub extern "C" fn banderwagon_map_to_scalar_field(
+    element: Option<&Bytes32>,
+    out: Option<&mut Bytes32>,
+) -> i32 {
+    run(out, || {
+        let e = self::element(required(element)?)?;
+        Ok(e.map_to_scalar_field().to_bytes_le())
+    })
+}
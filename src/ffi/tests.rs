@@ -0,0 +1,192 @@
+use super::*;
+use crate::{mul_by_generator, Fq};
+use ark_ec::AffineCurve;
+use ark_ff::{One, Zero};
+use ark_std::{rand::Rng, test_rng, UniformRand};
+
+fn random_point(rng: &mut impl ark_std::rand::Rng) -> EdwardsAffine {
+    mul_by_generator(&Fr::rand(rng)).into_affine()
+}
+
+#[test]
+fn test_ffi_points() {
+    let mut rng = test_rng();
+    let p = random_point(&mut rng);
+    let s = Fr::rand(&mut rng);
+    let p_bytes = to_compressed_bytes(&p);
+    let s_bytes = s.to_bytes_le();
+
+    let mut uncompressed = [0u8; UNCOMPRESSED_SIZE];
+    assert_eq!(
+        bandersnatch_decompress(Some(&p_bytes), Some(&mut uncompressed)),
+        BANDERSNATCH_OK
+    );
+    assert_eq!(uncompressed, to_bytes_uncompressed(&p, Endianness::Little));
+    assert_eq!(bandersnatch_subgroup_check(Some(&p_bytes)), BANDERSNATCH_OK);
+
+    let mut out = [0u8; 32];
+    assert_eq!(
+        bandersnatch_scalar_mul(Some(&p_bytes), Some(&s_bytes), Some(&mut out)),
+        BANDERSNATCH_OK
+    );
+    assert_eq!(from_compressed_bytes(&out), Some(p.mul(s).into_affine()));
+
+    let points: Vec<EdwardsAffine> =
+        (0..5).map(|_| random_point(&mut rng)).collect();
+    let scalars: Vec<Fr> = (0..5).map(|_| Fr::rand(&mut rng)).collect();
+    let point_bytes: Vec<Bytes32> =
+        points.iter().map(to_compressed_bytes).collect();
+    let scalar_bytes: Vec<Bytes32> =
+        scalars.iter().map(|s| s.to_bytes_le()).collect();
+    let code = unsafe {
+        bandersnatch_msm(
+            point_bytes.as_ptr(),
+            scalar_bytes.as_ptr(),
+            5,
+            Some(&mut out),
+        )
+    };
+    assert_eq!(code, BANDERSNATCH_OK);
+    assert_eq!(
+        out,
+        to_compressed_bytes(&msm(&points, &scalars).into_affine())
+    );
+    let code = unsafe {
+        bandersnatch_msm(
+            ark_std::ptr::null(),
+            ark_std::ptr::null(),
+            0,
+            Some(&mut out),
+        )
+    };
+    assert_eq!(code, BANDERSNATCH_OK);
+    assert_eq!(out, to_compressed_bytes(&EdwardsAffine::zero()));
+}
+
+#[test]
+fn test_ffi_errors() {
+    let mut rng = test_rng();
+    let p_bytes = to_compressed_bytes(&random_point(&mut rng));
+    let s_bytes = Fr::one().to_bytes_le();
+    let mut out = [0xaau8; 32];
+
+    assert_eq!(
+        bandersnatch_scalar_mul(None, Some(&s_bytes), Some(&mut out)),
+        BANDERSNATCH_ERR_NULL_POINTER
+    );
+    assert_eq!(
+        bandersnatch_scalar_mul(Some(&p_bytes), Some(&s_bytes), None),
+        BANDERSNATCH_ERR_NULL_POINTER
+    );
+    // the point T = (0, -1) of order 2
+    let t = to_compressed_bytes(&EdwardsAffine::new(Fq::zero(), -Fq::one()));
+    assert_eq!(
+        bandersnatch_subgroup_check(Some(&t)),
+        BANDERSNATCH_ERR_NOT_IN_SUBGROUP
+    );
+    assert_eq!(
+        bandersnatch_subgroup_check(Some(&[0xff; 32])),
+        BANDERSNATCH_ERR_INVALID_POINT
+    );
+    assert_eq!(
+        bandersnatch_scalar_mul(
+            Some(&p_bytes),
+            Some(&[0xff; 32]),
+            Some(&mut out)
+        ),
+        BANDERSNATCH_ERR_INVALID_SCALAR
+    );
+    let code = unsafe {
+        bandersnatch_msm(
+            ark_std::ptr::null(),
+            ark_std::ptr::null(),
+            1,
+            Some(&mut out),
+        )
+    };
+    assert_eq!(code, BANDERSNATCH_ERR_NULL_POINTER);
+    // the output is untouched on failure
+    assert_eq!(out, [0xaa; 32]);
+
+    assert_eq!(
+        banderwagon_validate(Some(&[0xff; 32])),
+        BANDERSNATCH_ERR_INVALID_POINT
+    );
+    assert_eq!(banderwagon_validate(None), BANDERSNATCH_ERR_NULL_POINTER);
+}
+
+#[test]
+fn test_ffi_banderwagon() {
+    let mut rng = test_rng();
+    let elements: Vec<Element> = (0..4).map(|_| rng.gen()).collect();
+    let scalars: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+    let element_bytes: Vec<Bytes32> =
+        elements.iter().map(|e| e.to_bytes()).collect();
+    let scalar_bytes: Vec<Bytes32> =
+        scalars.iter().map(|s| s.to_bytes_le()).collect();
+
+    assert_eq!(
+        banderwagon_validate(Some(&element_bytes[0])),
+        BANDERSNATCH_OK
+    );
+    let mut out = [0u8; 32];
+    assert_eq!(
+        banderwagon_scalar_mul(
+            Some(&element_bytes[0]),
+            Some(&scalar_bytes[0]),
+            Some(&mut out)
+        ),
+        BANDERSNATCH_OK
+    );
+    assert_eq!(out, (elements[0] * scalars[0]).to_bytes());
+
+    let code = unsafe {
+        banderwagon_msm(
+            element_bytes.as_ptr(),
+            scalar_bytes.as_ptr(),
+            4,
+            Some(&mut out),
+        )
+    };
+    assert_eq!(code, BANDERSNATCH_OK);
+    assert_eq!(out, Element::msm(&elements, &scalars).to_bytes());
+
+    assert_eq!(
+        banderwagon_map_to_scalar_field(
+            Some(&element_bytes[1]),
+            Some(&mut out)
+        ),
+        BANDERSNATCH_OK
+    );
+    assert_eq!(out, elements[1].map_to_scalar_field().to_bytes_le());
+}
+
+#[test]
+fn test_header() {
+    // every exported function is declared in the header, and conversely
+    let header = include_str!("bandersnatch.h");
+    let source = include_str!("mod.rs");
+    let exported: Vec<&str> = source
+        .split("extern \"C\" fn ")
+        .skip(1)
+        .map(|s| s.split('(').next().unwrap())
+        .collect();
+    let declared: Vec<&str> = header
+        .split("int32_t ")
+        .skip(1)
+        .map(|s| s.split('(').next().unwrap())
+        .collect();
+    assert_eq!(exported.len(), 8);
+    assert_eq!(exported, declared);
+    for constant in [
+        "BANDERSNATCH_OK 0",
+        "BANDERSNATCH_ERR_NULL_POINTER -1",
+        "BANDERSNATCH_ERR_INVALID_POINT -2",
+        "BANDERSNATCH_ERR_NOT_IN_SUBGROUP -3",
+        "BANDERSNATCH_ERR_INVALID_SCALAR -4",
+    ]
+    .iter()
+    {
+        assert!(header.contains(constant));
+    }
+}
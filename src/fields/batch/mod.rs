@@ -0,0 +1,81 @@
+//! Batched field operations, which amortize the cost of an expensive
+//! operation, e.g., an inversion, over many elements.
+
+use crate::{Fq, FqParameters};
+use ark_ff::{
+    batch_inversion, BigInteger, BigInteger256, Field, FpParameters, PrimeField,
+};
+use ark_std::{cfg_iter, vec::Vec};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Replace every element of `values` with its inverse, with Montgomery's
+/// trick: a single field inversion and three multiplications per element.
+///
+/// Zeros, which have no inverse, are left as zeros, without affecting the
+/// other elements. With the `parallel` feature, the slice is split among
+/// the rayon thread pool.
+pub fn batch_inverse<F: Field>(values: &mut [F]) {
+    batch_inversion(values);
+}
+
+/// The Legendre symbols of `values`: `1` for a nonzero square, `-1` for a
+/// non-square, and `0` for zero, e.g., to check many compressed points at
+/// once.
+///
+/// Legendre symbols do not combine like inversions, so the batch saves on
+/// each symbol instead: it is computed as a Jacobi symbol with the binary
+/// algorithm, on the canonical integers, which is about three times faster
+/// than the exponentiation by `(q - 1) / 2` of
+/// `SquareRootField::legendre`. With the `parallel` feature, the symbols
+/// are computed by the rayon thread pool. Not constant time, see
+/// [`ct_legendre`](crate::ct_legendre).
+pub fn batch_legendre(values: &[Fq]) -> Vec<i8> {
+    cfg_iter!(values).map(legendre_binary).collect()
+}
+
+/// The Jacobi symbol `(x / q)`, with the binary algorithm: halve the
+/// numerator, swap by quadratic reciprocity, and subtract.
+fn legendre_binary(x: &Fq) -> i8 {
+    let mut a = x.into_repr();
+    let mut n: BigInteger256 = FqParameters::MODULUS;
+    let mut sign = 1i8;
+    while !a.is_zero() {
+        let twos = trailing_zeros(&a);
+        a.divn(twos);
+        // (2 / n) = -1 if and only if n = 3 or 5 mod 8
+        let n_mod_8 = n.0[0] & 7;
+        if twos & 1 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+            sign = -sign;
+        }
+        if a < n {
+            ark_std::mem::swap(&mut a, &mut n);
+            // (a / n) = -(n / a) if and only if a = n = 3 mod 4
+            if a.0[0] & 3 == 3 && n.0[0] & 3 == 3 {
+                sign = -sign;
+            }
+        }
+        a.sub_noborrow(&n);
+    }
+    if n == BigInteger256::from(1) {
+        sign
+    } else {
+        0
+    }
+}
+
+/// The number of trailing zero bits of a nonzero integer.
+fn trailing_zeros(a: &BigInteger256) -> u32 {
+    let mut zeros = 0;
+    for limb in a.0.iter() {
+        if *limb != 0 {
+            return zeros + limb.trailing_zeros();
+        }
+        zeros += 64;
+    }
+    zeros
+}
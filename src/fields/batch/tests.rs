@@ -0,0 +1,47 @@
+use super::*;
+use crate::{Fq, Fr};
+use ark_ff::{Field, LegendreSymbol, One, SquareRootField, Zero};
+use ark_std::{test_rng, vec::Vec, UniformRand};
+
+#[test]
+fn test_batch_inverse() {
+    let mut rng = test_rng();
+    let mut values: Vec<Fq> = (0..33).map(|_| Fq::rand(&mut rng)).collect();
+    values[5] = Fq::zero();
+    values[20] = Fq::one();
+    let expected: Vec<Fq> = values
+        .iter()
+        .map(|v| v.inverse().unwrap_or_else(Fq::zero))
+        .collect();
+    batch_inverse(&mut values);
+    assert_eq!(values, expected);
+
+    let mut scalars: Vec<Fr> = (0..8).map(|_| Fr::rand(&mut rng)).collect();
+    let expected: Vec<Fr> =
+        scalars.iter().map(|s| s.inverse().unwrap()).collect();
+    batch_inverse(&mut scalars);
+    assert_eq!(scalars, expected);
+
+    let mut empty: [Fq; 0] = [];
+    batch_inverse(&mut empty);
+}
+
+#[test]
+fn test_batch_legendre() {
+    let mut rng = test_rng();
+    let mut values: Vec<Fq> = (0..64).map(|_| Fq::rand(&mut rng)).collect();
+    values.extend_from_slice(&[Fq::zero(), Fq::one(), -Fq::one()]);
+    let symbols = batch_legendre(&values);
+    assert_eq!(symbols.len(), values.len());
+    for (v, symbol) in values.iter().zip(&symbols) {
+        let expected = match v.legendre() {
+            LegendreSymbol::Zero => 0,
+            LegendreSymbol::QuadraticResidue => 1,
+            LegendreSymbol::QuadraticNonResidue => -1,
+        };
+        assert_eq!(*symbol, expected);
+    }
+    // both symbols occur among random elements
+    assert!(symbols.contains(&1) && symbols.contains(&-1));
+    assert!(batch_legendre(&[]).is_empty());
+}
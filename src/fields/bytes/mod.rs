@@ -0,0 +1,110 @@
+//! Conversions between the fields of bandersnatch and bytes, defined here
+//! rather than delegated to the arkworks serializer, for both `Fq` and
+//! `Fr`.
+//!
+//! Elements are written as their canonical integers, in `[0, p)`, in
+//! either byte order, since interoperability targets disagree: e.g., the
+//! arkworks serializer is little-endian, while Go and EVM implementations
+//! are usually big-endian. Reading checks that the integer is canonical,
+//! i.e., smaller than the modulus; [`FieldBytes::from_canonical_bytes`]
+//! does so in constant time, for secret values.
+
+use ark_ff::{
+    fields::{Fp256, Fp256Parameters},
+    BigInteger, BigInteger256, PrimeField,
+};
+use subtle::{Choice, CtOption};
+
+#[cfg(test)]
+mod tests;
+
+/// The size of an encoded field element, in bytes.
+pub const FIELD_BYTES_SIZE: usize = 32;
+
+/// The size of the input of [`FieldBytes::from_bytes_wide`], in bytes.
+pub const WIDE_BYTES_SIZE: usize = 64;
+
+/// Byte conversions of `Fq` and `Fr`.
+pub trait FieldBytes: Sized {
+    /// Reduce 64 little-endian bytes modulo the field order.
+    ///
+    /// For uniformly random bytes, the result is uniformly random up to a
+    /// statistical distance of at most `2^-256`, as the modulus is smaller
+    /// than `2^256`, e.g., to derive scalars from the output of a hash
+    /// without modulo bias.
+    fn from_bytes_wide(bytes: &[u8; WIDE_BYTES_SIZE]) -> Self;
+
+    /// The canonical integer of the element, in little-endian.
+    fn to_bytes_le(&self) -> [u8; FIELD_BYTES_SIZE];
+
+    /// The canonical integer of the element, in big-endian.
+    fn to_bytes_be(&self) -> [u8; FIELD_BYTES_SIZE] {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Read a little-endian integer, or `None` if it is not smaller than
+    /// the modulus.
+    fn from_bytes_le(bytes: &[u8; FIELD_BYTES_SIZE]) -> Option<Self>;
+
+    /// Read a big-endian integer, or `None` if it is not smaller than the
+    /// modulus.
+    fn from_bytes_be(bytes: &[u8; FIELD_BYTES_SIZE]) -> Option<Self> {
+        let mut le = *bytes;
+        le.reverse();
+        Self::from_bytes_le(&le)
+    }
+
+    /// [`FieldBytes::from_bytes_le`] in constant time: the canonicity
+    /// check is a borrow chain rather than a comparison, and a
+    /// non-canonical input is replaced with zero before the conversion to
+    /// the Montgomery form, so that only the returned `Choice` depends on
+    /// it, e.g., to load secret scalars.
+    fn from_canonical_bytes(bytes: &[u8; FIELD_BYTES_SIZE]) -> CtOption<Self>;
+}
+
+impl<P: Fp256Parameters> FieldBytes for Fp256<P> {
+    fn from_bytes_wide(bytes: &[u8; WIDE_BYTES_SIZE]) -> Self {
+        Self::from_le_bytes_mod_order(bytes)
+    }
+
+    fn to_bytes_le(&self) -> [u8; FIELD_BYTES_SIZE] {
+        let mut bytes = [0u8; FIELD_BYTES_SIZE];
+        bytes.copy_from_slice(&self.into_repr().to_bytes_le());
+        bytes
+    }
+
+    fn from_bytes_le(bytes: &[u8; FIELD_BYTES_SIZE]) -> Option<Self> {
+        Self::from_repr(BigInteger256::new(limbs_from_le_bytes(bytes)))
+    }
+
+    fn from_canonical_bytes(bytes: &[u8; FIELD_BYTES_SIZE]) -> CtOption<Self> {
+        let mut limbs = limbs_from_le_bytes(bytes);
+        // the final borrow of limbs - MODULUS is 1 if and only if the
+        // integer is canonical
+        let mut borrow = 0u64;
+        for (limb, modulus) in limbs.iter().zip(&P::MODULUS.0) {
+            let diff =
+                (*limb as u128).wrapping_sub(*modulus as u128 + borrow as u128);
+            borrow = (diff >> 127) as u64;
+        }
+        let mask = borrow.wrapping_neg();
+        for limb in limbs.iter_mut() {
+            *limb &= mask;
+        }
+        // limbs R^2 R^-1 = limbs R, the Montgomery form of limbs
+        let value = Self::new(BigInteger256::new(limbs)) * Self::new(P::R2);
+        CtOption::new(value, Choice::from(borrow as u8))
+    }
+}
+
+fn limbs_from_le_bytes(bytes: &[u8; FIELD_BYTES_SIZE]) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(chunk);
+        *limb = u64::from_le_bytes(word);
+    }
+    limbs
+}
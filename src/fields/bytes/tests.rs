@@ -0,0 +1,109 @@
+use super::*;
+use crate::{Fq, Fr};
+use ark_ff::{field_new, FpParameters, One, PrimeField, Zero};
+use ark_std::{test_rng, UniformRand};
+
+fn from_hex(s: &str) -> [u8; WIDE_BYTES_SIZE] {
+    let mut bytes = [0u8; WIDE_BYTES_SIZE];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+    }
+    bytes
+}
+
+#[test]
+fn test_from_bytes_wide() {
+    // SHA-512("bandersnatch")
+    let bytes = from_hex(
+        "b051bce5e2a8600983fe1c7d2a1ab6460e05e7ce4f71ea9fe8ccf75f1b351ff8\
+         5ef13e2b7b1bddcb74ca824cb26da04272f5c3ed64debb3d5a9e7a4dbfd15747",
+    );
+    assert_eq!(
+        Fr::from_bytes_wide(&bytes),
+        field_new!(
+            Fr,
+            "6236480355916046090086673750097639397307354217886570147768565034087654893187"
+        )
+    );
+    assert_eq!(
+        Fq::from_bytes_wide(&bytes),
+        field_new!(
+            Fq,
+            "6033733843790393056917845710628768488321742514539473741345481723311451071221"
+        )
+    );
+    assert_eq!(
+        Fr::from_bytes_wide(&[0xff; WIDE_BYTES_SIZE]),
+        field_new!(
+            Fr,
+            "4932290691328759802879919559207542894238895193980447506221046538067943049162"
+        )
+    );
+    assert!(Fr::from_bytes_wide(&[0; WIDE_BYTES_SIZE]).is_zero());
+    let mut one = [0; WIDE_BYTES_SIZE];
+    one[0] = 1;
+    assert!(Fr::from_bytes_wide(&one).is_one());
+}
+
+#[test]
+fn test_bytes_le_be() {
+    let mut rng = test_rng();
+    for _ in 0..16 {
+        let x = Fq::rand(&mut rng);
+        let le = x.to_bytes_le();
+        let mut be = x.to_bytes_be();
+        assert_eq!(Fq::from_bytes_le(&le), Some(x));
+        assert_eq!(Fq::from_bytes_be(&be), Some(x));
+        be.reverse();
+        assert_eq!(be, le);
+
+        let s = Fr::rand(&mut rng);
+        assert_eq!(Fr::from_bytes_le(&s.to_bytes_le()), Some(s));
+        assert_eq!(Fr::from_bytes_be(&s.to_bytes_be()), Some(s));
+    }
+
+    let mut one = [0u8; FIELD_BYTES_SIZE];
+    one[FIELD_BYTES_SIZE - 1] = 1;
+    assert_eq!(Fr::one().to_bytes_be(), one);
+    assert_eq!(Fr::from_bytes_be(&one), Some(Fr::one()));
+    assert_ne!(Fr::from_bytes_le(&one), Some(Fr::one()));
+
+    // the modulus is not canonical, the modulus minus one is
+    let mut modulus = [0u8; FIELD_BYTES_SIZE];
+    modulus.copy_from_slice(&<Fr as PrimeField>::Params::MODULUS.to_bytes_le());
+    assert_eq!(Fr::from_bytes_le(&modulus), None);
+    modulus[0] -= 1;
+    assert_eq!(Fr::from_bytes_le(&modulus), Some(-Fr::one()));
+    assert_eq!(Fq::from_bytes_le(&[0xff; FIELD_BYTES_SIZE]), None);
+    assert_eq!(Fq::from_bytes_be(&[0xff; FIELD_BYTES_SIZE]), None);
+}
+
+#[test]
+fn test_from_canonical_bytes() {
+    let mut rng = test_rng();
+    for _ in 0..16 {
+        let s = Fr::rand(&mut rng);
+        let parsed = Fr::from_canonical_bytes(&s.to_bytes_le());
+        assert!(bool::from(parsed.is_some()));
+        assert_eq!(parsed.unwrap(), s);
+        let x = Fq::rand(&mut rng);
+        assert_eq!(Fq::from_canonical_bytes(&x.to_bytes_le()).unwrap(), x);
+    }
+    assert!(Fr::from_canonical_bytes(&[0; FIELD_BYTES_SIZE])
+        .unwrap()
+        .is_zero());
+
+    let mut modulus = [0u8; FIELD_BYTES_SIZE];
+    modulus.copy_from_slice(&<Fr as PrimeField>::Params::MODULUS.to_bytes_le());
+    assert!(bool::from(Fr::from_canonical_bytes(&modulus).is_none()));
+    modulus[0] -= 1;
+    assert_eq!(Fr::from_canonical_bytes(&modulus).unwrap(), -Fr::one());
+    // canonical for Fq, not for Fr
+    modulus.copy_from_slice(&<Fq as PrimeField>::Params::MODULUS.to_bytes_le());
+    modulus[0] -= 1;
+    assert_eq!(Fq::from_canonical_bytes(&modulus).unwrap(), -Fq::one());
+    assert!(bool::from(Fr::from_canonical_bytes(&modulus).is_none()));
+    assert!(bool::from(
+        Fq::from_canonical_bytes(&[0xff; FIELD_BYTES_SIZE]).is_none()
+    ));
+}
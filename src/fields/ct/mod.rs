@@ -0,0 +1,123 @@
+//! Constant-time exponentiation, Legendre symbol and square root in `Fq`.
+//!
+//! The arkworks `legendre` and `sqrt` branch on the value: Tonelli-Shanks
+//! loops a number of times that depends on its input. The functions of
+//! this module run the same sequence of field operations whatever their
+//! input, with fixed 4-bit windows for the exponentiations and
+//! conditional moves instead of branches, so that they can be applied to
+//! secret-derived values, e.g., when hashing secret keys to the curve.
+//! Only the final outcome (whether the input is a square) is revealed.
+
+use crate::{Fq, FqParameters};
+use ark_ff::{
+    FftField, FftParameters, Field, FpParameters, LegendreSymbol, One,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The window of the exponentiations.
+const WINDOW: usize = 4;
+
+/// Compute `base^exp`, where `exp` is given by its little-endian limbs,
+/// with a fixed sequence of squarings and multiplications: every window
+/// of the exponent, including the zero ones, costs four squarings and a
+/// multiplication by a table entry selected in constant time.
+pub fn ct_pow(base: &Fq, exp: &[u64]) -> Fq {
+    // table[i] = base^i
+    let mut table = [Fq::one(); 1 << WINDOW];
+    for i in 1..table.len() {
+        table[i] = table[i - 1] * base;
+    }
+
+    let mut res = Fq::one();
+    for limb in exp.iter().rev() {
+        for w in (0..64 / WINDOW).rev() {
+            for _ in 0..WINDOW {
+                res.square_in_place();
+            }
+            let digit = (limb >> (w * WINDOW)) & ((1 << WINDOW) - 1);
+            let mut entry = table[0];
+            for (i, candidate) in table.iter().enumerate() {
+                conditional_assign(
+                    &mut entry,
+                    candidate,
+                    eq_u64(i as u64, digit),
+                );
+            }
+            res *= entry;
+        }
+    }
+    res
+}
+
+/// The Legendre symbol of `x`, computed as `x^((q - 1) / 2)` with
+/// [`ct_pow`].
+pub fn ct_legendre(x: &Fq) -> LegendreSymbol {
+    let s = ct_pow(x, FqParameters::MODULUS_MINUS_ONE_DIV_TWO.as_ref());
+    let is_zero = ct_eq(&s, &Fq::from(0u64));
+    let is_one = ct_eq(&s, &Fq::one());
+    if is_zero == 1 {
+        LegendreSymbol::Zero
+    } else if is_one == 1 {
+        LegendreSymbol::QuadraticResidue
+    } else {
+        LegendreSymbol::QuadraticNonResidue
+    }
+}
+
+/// A square root of `x`, or `None` if `x` is not a square.
+///
+/// This is the constant-time variant of Tonelli-Shanks of the hash to
+/// curve specification (RFC 9380, appendix I.4): the inner loop always
+/// runs its worst-case number of iterations, and its updates are
+/// conditional moves.
+pub fn ct_sqrt(x: &Fq) -> Option<Fq> {
+    let two_adicity = <FqParameters as FftParameters>::TWO_ADICITY;
+    let mut z = ct_pow(x, FqParameters::T_MINUS_ONE_DIV_TWO.as_ref());
+    let mut t = z.square() * x;
+    z *= x;
+    let mut b = t;
+    let mut c = Fq::two_adic_root_of_unity();
+    for i in (2..=two_adicity).rev() {
+        for _ in 1..i - 1 {
+            b.square_in_place();
+        }
+        let e = ct_eq(&b, &Fq::one());
+        let zc = z * c;
+        conditional_assign(&mut z, &zc, e ^ 1);
+        c.square_in_place();
+        let tc = t * c;
+        conditional_assign(&mut t, &tc, e ^ 1);
+        b = t;
+    }
+    if ct_eq(&z.square(), x) == 1 {
+        Some(z)
+    } else {
+        None
+    }
+}
+
+/// Set `a` to `b` if `choice` is 1, and leave it unchanged if it is 0.
+pub(crate) fn conditional_assign(a: &mut Fq, b: &Fq, choice: u64) {
+    let mask = choice.wrapping_neg();
+    for (x, y) in (a.0).0.iter_mut().zip(&(b.0).0) {
+        *x ^= mask & (*x ^ *y);
+    }
+}
+
+/// 1 if `a == b`, 0 otherwise.
+pub(crate) fn ct_eq(a: &Fq, b: &Fq) -> u64 {
+    let diff = (a.0)
+        .0
+        .iter()
+        .zip(&(b.0).0)
+        .fold(0, |acc, (x, y)| acc | (x ^ y));
+    eq_u64(diff, 0)
+}
+
+/// 1 if `a == b`, 0 otherwise.
+fn eq_u64(a: u64, b: u64) -> u64 {
+    let diff = a ^ b;
+    ((diff | diff.wrapping_neg()) >> 63) ^ 1
+}
@@ -0,0 +1,46 @@
+use super::*;
+use ark_ff::{PrimeField, SquareRootField, Zero};
+use ark_std::{rand::Rng, test_rng};
+
+#[test]
+fn test_ct_pow() {
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let x: Fq = rng.gen();
+        let exp: [u64; 4] = rng.gen();
+        assert_eq!(ct_pow(&x, &exp), x.pow(exp));
+    }
+    let x: Fq = rng.gen();
+    assert_eq!(ct_pow(&x, &[]), Fq::one());
+    assert_eq!(ct_pow(&x, &[0, 0]), Fq::one());
+    assert_eq!(ct_pow(&x, &[1]), x);
+    assert_eq!(ct_pow(&Fq::zero(), &[5]), Fq::zero());
+    assert_eq!(ct_pow(&x, Fq::characteristic()), x);
+}
+
+#[test]
+fn test_ct_legendre_sqrt() {
+    let mut rng = test_rng();
+    assert_eq!(ct_legendre(&Fq::zero()), LegendreSymbol::Zero);
+    assert_eq!(ct_sqrt(&Fq::zero()), Some(Fq::zero()));
+    assert_eq!(ct_sqrt(&Fq::one()).map(|r| r.square()), Some(Fq::one()));
+    for _ in 0..50 {
+        let x: Fq = rng.gen();
+        assert_eq!(ct_legendre(&x), x.legendre());
+        match ct_sqrt(&x) {
+            Some(r) => {
+                assert_eq!(r.square(), x);
+                assert!(x.legendre().is_qr());
+            }
+            None => assert!(x.legendre().is_qnr()),
+        }
+        let y = x.square();
+        assert_eq!(ct_sqrt(&y).map(|r| r.square()), Some(y));
+    }
+    // the two-adic root of unity exercises every step of the loop
+    let root = Fq::two_adic_root_of_unity();
+    assert_eq!(ct_sqrt(&root), None);
+    let r = ct_sqrt(&root.square()).unwrap();
+    assert_eq!(r.square(), root.square());
+    assert_eq!(Fq::from_repr(r.into_repr()), Some(r));
+}
@@ -1 +1,42 @@
+//! The base field of bandersnatch, which is the scalar field of BLS12-381.
+//!
+//! `Fq` is a re-export of `ark_bls12_381::Fr`, not a copy of its
+//! parameters: values flow between the two without any conversion, e.g.,
+//! into pairing-based proofs over BLS12-381. The functions below only
+//! spell the conversion out where the two roles of the field meet.
+
 pub use ark_bls12_381::{Fr as Fq, FrParameters as FqParameters};
+
+/// The scalar of BLS12-381 with the same value as `x`, at no cost.
+///
+/// ```
+/// use ark_ff::One;
+/// use bandersnatch::{fq_to_bls12_381_fr, Fq};
+///
+/// let x: ark_bls12_381::Fr = fq_to_bls12_381_fr(Fq::one());
+/// assert!(x.is_one());
+/// ```
+#[inline(always)]
+pub const fn fq_to_bls12_381_fr(x: Fq) -> ark_bls12_381::Fr {
+    x
+}
+
+/// The element of `Fq` with the same value as the scalar `x` of
+/// BLS12-381, at no cost.
+#[inline(always)]
+pub const fn fq_from_bls12_381_fr(x: ark_bls12_381::Fr) -> Fq {
+    x
+}
+
+/// Reinterpret a slice of `Fq` as scalars of BLS12-381, without copying.
+#[inline(always)]
+pub fn fq_slice_as_bls12_381_fr(xs: &[Fq]) -> &[ark_bls12_381::Fr] {
+    xs
+}
+
+/// Reinterpret a slice of scalars of BLS12-381 as elements of `Fq`,
+/// without copying.
+#[inline(always)]
+pub fn fq_slice_from_bls12_381_fr(xs: &[ark_bls12_381::Fr]) -> &[Fq] {
+    xs
+}
@@ -16,13 +16,13 @@ impl FftParameters for FrParameters {
     /// such that `N = 2^s * t` for some odd integer `t`.
     const TWO_ADICITY: u32 = 5;
 
-    /// 2^s root of unity computed by GENERATOR^t
-    /// 4740934665446857387895054948191089665295030226009829406950782728666658007874
+    /// 2^s root of unity computed by GENERATOR^t, in Montgomery form
+    /// 11433346699146074483943994170040299922811140455678567390710209993320426101088
     const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
-        0xa4dcdba087826b42,
-        0x6e4ab162f57f862a,
-        0xabc5492749348d6a,
-        0xa7b462035f8c169,
+        0x4b263b9a8d79c573,
+        0xeadb3d0a007af1fd,
+        0xa54c8a4668832589,
+        0x610860c4254fb9d,
     ]);
 }
 impl FpParameters for FrParameters {
@@ -71,13 +71,13 @@ impl FpParameters for FrParameters {
 
     /// A multiplicative generator of the field.
     /// `Self::GENERATOR` is an element having multiplicative order
-    /// `Self::MODULUS - 1`.
-    /// n = 9962557815892774795293348142308860067333132192265356416788884706064406244838
+    /// `Self::MODULUS - 1`, in Montgomery form.
+    /// n = 7
     const GENERATOR: BigInteger = BigInteger([
-        0x56b6f3ab7b616de6,
-        0x114f419d6c9083e5,
-        0xbf518d217780c4b9,
-        0x16069b9f45dbce7f,
+        0x1fb566d55baabf63,
+        0x1accd4e45609f0f7,
+        0xf2cde1856d1be1c3,
+        0x1817c84bc55e4972,
     ]);
 
     /// (Self::MODULUS - 1) / 2
@@ -1,8 +1,24 @@
+mod batch;
+mod bytes;
+mod ct;
 pub mod fq;
 pub mod fr;
+mod products;
+pub mod roots;
+mod secret;
+mod sqrt;
 
+pub use batch::{batch_inverse, batch_legendre};
+pub use bytes::{FieldBytes, FIELD_BYTES_SIZE, WIDE_BYTES_SIZE};
+pub(crate) use ct::{conditional_assign, ct_eq};
+pub use ct::{ct_legendre, ct_pow, ct_sqrt};
 pub use fq::*;
 pub use fr::*;
+pub use products::SumOfProducts;
+#[cfg(feature = "zeroize")]
+pub use secret::zeroize_field;
+pub(crate) use secret::{wipe, wipe_bytes, wipe_limbs};
+pub use sqrt::{batch_sqrt, sqrt_with_tables};
 
 #[cfg(all(feature = "ed_on_bls12_381_bandersnatch", test))]
 mod tests;
@@ -0,0 +1,90 @@
+//! Sums of products with delayed reduction, for both `Fq` and `Fr`.
+//!
+//! `sum_i a[i] b[i]` computed with field multiplications pays a Montgomery
+//! reduction per term. Here, the unreduced 512-bit products of the
+//! internal representations are accumulated in 640 bits instead, and the
+//! sum is reduced once: about half of the word multiplications of the
+//! naive sum, e.g., for the inner products of the IPA and the barycentric
+//! evaluations of [`PrecomputedWeights`](crate::ipa::PrecomputedWeights).
+
+use ark_ff::{
+    fields::{Fp256, Fp256Parameters},
+    BigInteger, BigInteger256,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The number of 64-bit limbs of the accumulator: the products of two
+/// elements are smaller than `2^510`, so that `2^64` of them fit, with room
+/// for the reduction.
+const ACCUMULATOR_LIMBS: usize = 10;
+
+/// Fused sums of products of `Fq` and `Fr`.
+pub trait SumOfProducts: Sized {
+    /// `sum_i a[i] b[i]`, reduced once, e.g.,
+    /// `Fq::sum_of_products(&a, &b)`.
+    ///
+    /// If the two slices have different lengths, the longer one is
+    /// truncated.
+    fn sum_of_products(a: &[Self], b: &[Self]) -> Self;
+}
+
+impl<P: Fp256Parameters> SumOfProducts for Fp256<P> {
+    fn sum_of_products(a: &[Self], b: &[Self]) -> Self {
+        let mut acc = [0u64; ACCUMULATOR_LIMBS];
+        for (a, b) in a.iter().zip(b) {
+            let (a, b) = ((a.0).0, (b.0).0);
+            for i in 0..4 {
+                let mut carry = 0u64;
+                for j in 0..4 {
+                    acc[i + j] = mac(acc[i + j], a[i], b[j], &mut carry);
+                }
+                propagate(&mut acc[i + 4..], carry);
+            }
+        }
+
+        // Montgomery reduction of the 640-bit sum: acc / 2^256, which is
+        // congruent to R sum_i a[i] b[i], the internal representation of
+        // the result, and smaller than 2^384.
+        let modulus = P::MODULUS.0;
+        for i in 0..4 {
+            let k = acc[i].wrapping_mul(P::INV);
+            let mut carry = 0u64;
+            for j in 0..4 {
+                acc[i + j] = mac(acc[i + j], k, modulus[j], &mut carry);
+            }
+            propagate(&mut acc[i + 4..], carry);
+        }
+
+        // lo + 2^256 hi, where lo is an internal representation and
+        // 2^256 hi the representation of hi
+        let mut lo = BigInteger256([acc[4], acc[5], acc[6], acc[7]]);
+        while lo >= P::MODULUS {
+            lo.sub_noborrow(&P::MODULUS);
+        }
+        let hi = ((acc[9] as u128) << 64) | acc[8] as u128;
+        Self::new(lo) + Self::from(hi)
+    }
+}
+
+/// `acc + a b + carry`, setting the carry to the high word.
+#[inline(always)]
+fn mac(acc: u64, a: u64, b: u64, carry: &mut u64) -> u64 {
+    let t = acc as u128 + (a as u128) * (b as u128) + *carry as u128;
+    *carry = (t >> 64) as u64;
+    t as u64
+}
+
+/// Add `carry` to the integer with limbs `limbs`.
+#[inline(always)]
+fn propagate(limbs: &mut [u64], mut carry: u64) {
+    for limb in limbs.iter_mut() {
+        if carry == 0 {
+            return;
+        }
+        let (sum, overflow) = limb.overflowing_add(carry);
+        *limb = sum;
+        carry = overflow as u64;
+    }
+}
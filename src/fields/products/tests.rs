@@ -0,0 +1,40 @@
+use super::*;
+use crate::{Fq, Fr};
+use ark_ff::{Field, One, Zero};
+use ark_std::{test_rng, vec, vec::Vec, UniformRand};
+
+fn naive<F: Field>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b).map(|(a, b)| *a * b).sum()
+}
+
+#[test]
+fn test_sum_of_products() {
+    let mut rng = test_rng();
+    for n in [0, 1, 2, 3, 16, 255, 1000] {
+        let a: Vec<Fq> = (0..n).map(|_| Fq::rand(&mut rng)).collect();
+        let b: Vec<Fq> = (0..n).map(|_| Fq::rand(&mut rng)).collect();
+        assert_eq!(Fq::sum_of_products(&a, &b), naive(&a, &b));
+        let a: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+        let b: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+        assert_eq!(Fr::sum_of_products(&a, &b), naive(&a, &b));
+        // the longer slice is truncated
+        assert_eq!(
+            Fr::sum_of_products(&a, &b[..n / 2]),
+            naive(&a[..n / 2], &b[..n / 2])
+        );
+    }
+    assert!(Fq::sum_of_products(&[], &[]).is_zero());
+    assert!(Fq::sum_of_products(&[Fq::one()], &[Fq::one()]).is_one());
+}
+
+#[test]
+fn test_sum_of_products_extremes() {
+    // the largest representations, so that the accumulator carries
+    for n in [1, 2, 100, 4096] {
+        let a = vec![-Fq::one(); n];
+        assert_eq!(Fq::sum_of_products(&a, &a), Fq::from(n as u64));
+        let a = vec![-Fr::one(); n];
+        let b = vec![-Fr::from(2u64); n];
+        assert_eq!(Fr::sum_of_products(&a, &b), Fr::from(2 * n as u64));
+    }
+}
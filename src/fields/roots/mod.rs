@@ -0,0 +1,67 @@
+//! The roots of unity of power-of-two order in `Fr`, for radix-2 FFTs and
+//! evaluation domains over the scalar field of bandersnatch.
+//!
+//! The two-adicity of `r - 1` is only 5, so the largest radix-2 domain has
+//! 32 elements; the helpers below return `None` for larger sizes rather
+//! than a root of the wrong order.
+
+use crate::{Fr, FrParameters};
+use ark_ff::{FftField, FftParameters, Field, One};
+use ark_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+/// The largest `k` such that `Fr` has a primitive `2^k`-th root of unity.
+pub const MAX_ROOT_OF_UNITY_LOG_SIZE: u32 = FrParameters::TWO_ADICITY;
+
+/// The size of the largest radix-2 evaluation domain of `Fr`.
+pub const MAX_DOMAIN_SIZE: usize = 1 << MAX_ROOT_OF_UNITY_LOG_SIZE;
+
+/// Whether `size` is the size of a radix-2 evaluation domain of `Fr`,
+/// i.e., a power of two that is at most [`MAX_DOMAIN_SIZE`].
+pub fn is_valid_domain_size(size: usize) -> bool {
+    size.is_power_of_two() && size <= MAX_DOMAIN_SIZE
+}
+
+/// The primitive `2^log_size`-th root of unity `w^(2^(s - log_size))`,
+/// where `w` is the root of order `2^s` of arkworks and
+/// `s` = [`MAX_ROOT_OF_UNITY_LOG_SIZE`], or `None` if `log_size > s`.
+pub fn root_of_unity(log_size: u32) -> Option<Fr> {
+    if log_size > MAX_ROOT_OF_UNITY_LOG_SIZE {
+        return None;
+    }
+    let mut root = Fr::two_adic_root_of_unity();
+    for _ in log_size..MAX_ROOT_OF_UNITY_LOG_SIZE {
+        root.square_in_place();
+    }
+    Some(root)
+}
+
+/// The inverse of [`root_of_unity`].
+pub fn inverse_root_of_unity(log_size: u32) -> Option<Fr> {
+    // w^-1 = w^(2^log_size - 1)
+    root_of_unity(log_size).map(|w| w.pow([(1u64 << log_size) - 1]))
+}
+
+/// The primitive root of unity of order `size`, or `None` if `size` is not
+/// a valid domain size.
+pub fn root_of_unity_for_size(size: usize) -> Option<Fr> {
+    if !is_valid_domain_size(size) {
+        return None;
+    }
+    root_of_unity(size.trailing_zeros())
+}
+
+/// The elements `1, w, ..., w^(size - 1)` of the domain of size `size`,
+/// or `None` if `size` is not a valid domain size.
+pub fn domain_elements(size: usize) -> Option<Vec<Fr>> {
+    let w = root_of_unity_for_size(size)?;
+    let mut elements = Vec::with_capacity(size);
+    let mut x = Fr::one();
+    for _ in 0..size {
+        elements.push(x);
+        x *= w;
+    }
+    Some(elements)
+}
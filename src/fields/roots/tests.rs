@@ -0,0 +1,75 @@
+use super::*;
+use ark_ff::{SquareRootField, Zero};
+use ark_std::{rand::Rng, str::FromStr, test_rng};
+
+#[test]
+fn test_root_of_unity() {
+    assert_eq!(MAX_ROOT_OF_UNITY_LOG_SIZE, 5);
+    assert_eq!(root_of_unity(0), Some(Fr::one()));
+    assert_eq!(root_of_unity(1), Some(-Fr::one()));
+    assert_eq!(root_of_unity(6), None);
+
+    for log_size in 0..=MAX_ROOT_OF_UNITY_LOG_SIZE {
+        let w = root_of_unity(log_size).unwrap();
+        let n = 1u64 << log_size;
+        // primitive of order 2^log_size
+        assert_eq!(w.pow([n]), Fr::one());
+        if log_size > 0 {
+            assert_ne!(w.pow([n / 2]), Fr::one());
+        }
+        assert_eq!(w * inverse_root_of_unity(log_size).unwrap(), Fr::one());
+        assert_eq!(root_of_unity_for_size(n as usize), Some(w));
+    }
+
+    // the largest root is the arkworks one
+    assert_eq!(
+        root_of_unity(MAX_ROOT_OF_UNITY_LOG_SIZE),
+        Some(Fr::two_adic_root_of_unity())
+    );
+    assert_eq!(
+        Fr::two_adic_root_of_unity(),
+        Fr::from_str(
+            "11433346699146074483943994170040299922811140455678567390710209993320426101088"
+        )
+        .unwrap()
+    );
+    // computed from the multiplicative generator
+    assert_eq!(Fr::multiplicative_generator(), Fr::from(7u64));
+    let t = <FrParameters as ark_ff::FpParameters>::T;
+    assert_eq!(
+        Fr::multiplicative_generator().pow(t),
+        Fr::two_adic_root_of_unity()
+    );
+}
+
+#[test]
+fn test_domain_size() {
+    assert!(is_valid_domain_size(1));
+    assert!(is_valid_domain_size(32));
+    assert!(!is_valid_domain_size(0));
+    assert!(!is_valid_domain_size(12));
+    assert!(!is_valid_domain_size(64));
+    assert_eq!(root_of_unity_for_size(12), None);
+    assert_eq!(domain_elements(64), None);
+
+    let elements = domain_elements(8).unwrap();
+    assert_eq!(elements.len(), 8);
+    // the elements are the distinct roots of x^8 - 1, which sum to 0
+    for (i, x) in elements.iter().enumerate() {
+        assert_eq!(x.pow([8u64]), Fr::one());
+        assert!(!elements[..i].contains(x));
+    }
+    assert!(elements.iter().sum::<Fr>().is_zero());
+}
+
+#[test]
+fn test_fr_sqrt() {
+    // Tonelli-Shanks relies on the root of unity
+    let mut rng = test_rng();
+    for _ in 0..50 {
+        let x: Fr = rng.gen();
+        let root = x.square().sqrt().unwrap();
+        assert!(root == x || root == -x);
+    }
+    assert!(Fr::multiplicative_generator().sqrt().is_none());
+}
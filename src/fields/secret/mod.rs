@@ -0,0 +1,46 @@
+//! Erasure of secret values, behind the `zeroize` feature.
+//!
+//! The fields are arkworks types, for which this crate cannot implement
+//! `Zeroize`; [`zeroize_field`] erases an element of either field through
+//! its limbs instead. Within the crate, secret keys, nonces, the bytes
+//! they are hashed from, and the intermediates of the GLV decomposition
+//! are erased once used with the helpers below, which are no-ops without
+//! the feature, so that the call sites need no `cfg`.
+
+use ark_ff::fields::{Fp256, Fp256Parameters};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+#[cfg(all(test, feature = "zeroize"))]
+mod tests;
+
+/// Overwrite the element with zero, with writes that are not optimized
+/// away, e.g., `zeroize_field(&mut scalar)` once a secret scalar is no
+/// longer needed.
+#[cfg(feature = "zeroize")]
+pub fn zeroize_field<P: Fp256Parameters>(x: &mut Fp256<P>) {
+    (x.0).0.zeroize();
+}
+
+/// Erase a secret field element, if the `zeroize` feature is enabled.
+#[inline(always)]
+pub(crate) fn wipe<P: Fp256Parameters>(_x: &mut Fp256<P>) {
+    #[cfg(feature = "zeroize")]
+    zeroize_field(_x);
+}
+
+/// Erase the limbs of a secret integer, if the `zeroize` feature is
+/// enabled.
+#[inline(always)]
+pub(crate) fn wipe_limbs(_limbs: &mut [u64]) {
+    #[cfg(feature = "zeroize")]
+    _limbs.zeroize();
+}
+
+/// Erase secret bytes, if the `zeroize` feature is enabled.
+#[inline(always)]
+pub(crate) fn wipe_bytes(_bytes: &mut [u8]) {
+    #[cfg(feature = "zeroize")]
+    _bytes.zeroize();
+}
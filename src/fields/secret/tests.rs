@@ -0,0 +1,22 @@
+use super::*;
+use crate::{Fq, Fr};
+use ark_ff::Zero;
+use ark_std::{test_rng, UniformRand};
+
+#[test]
+fn test_zeroize_field() {
+    let mut rng = test_rng();
+    let mut s = Fr::rand(&mut rng);
+    let mut x = Fq::rand(&mut rng);
+    zeroize_field(&mut s);
+    zeroize_field(&mut x);
+    assert!(s.is_zero());
+    assert!(x.is_zero());
+
+    let mut s = Fr::rand(&mut rng);
+    wipe(&mut s);
+    assert!(s.is_zero());
+    let mut bytes = [0xffu8; 32];
+    wipe_bytes(&mut bytes);
+    assert_eq!(bytes, [0u8; 32]);
+}
@@ -0,0 +1,212 @@
+//! Square roots in `Fq` with precomputed tables, after Sarkar, "Computing
+//! Square Roots Faster than the Tonelli-Shanks/Bernstein Algorithm", as in
+//! the `pasta_curves` crate.
+//!
+//! With `q - 1 = 2^32 t` and `t` odd, Tonelli-Shanks spends up to about
+//! `32^2 / 2` squarings finding the discrete logarithm of `u^t` in the
+//! group of `2^32`-th roots of unity, on top of the exponentiation by
+//! `(t - 1) / 2`. Here, the logarithm is found 8 bits at a time, from 24
+//! squarings and a lookup in the table of the logarithms of the `256`-th
+//! roots of unity, and the correction is read from tables of powers of
+//! the root of unity. The tables take 32 KiB and are built on first use.
+//!
+//! [`batch_sqrt`] computes many roots at once: non-squares are filtered
+//! out with [`batch_legendre`], and the roots of the squares share the
+//! tables and a fixed-window decomposition of the exponent.
+//!
+//! As the arkworks `sqrt`, this branches on its input; see
+//! [`ct_sqrt`](crate::ct_sqrt) for secret values.
+
+use crate::{batch_legendre, Fq, FqParameters};
+use ark_ff::{
+    BigInteger, FftField, FftParameters, Field, FpParameters, One, Zero,
+};
+use ark_std::{boxed::Box, cfg_iter, vec::Vec};
+use once_cell::race::OnceBox;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The size of the windows of the discrete logarithm, in bits.
+const WINDOW: u32 = 8;
+
+/// The number of windows, such that `WINDOWS * WINDOW` is the 2-adicity
+/// of `q - 1`.
+const WINDOWS: usize = 4;
+
+/// The size of the windows of the exponentiation by `(t - 1) / 2`, in
+/// bits.
+const EXPONENT_WINDOW: u32 = 4;
+
+struct SqrtTables {
+    /// `inverse_powers[m][j] = g^(-j 2^(8 m))`, where `g` is the
+    /// `2^32`-th root of unity.
+    inverse_powers: [[Fq; 1 << WINDOW]; WINDOWS],
+    /// `(key(z^j), j)` sorted by key, where `z = g^(2^24)` is a `256`-th
+    /// root of unity and `key` is the lowest limb of the internal
+    /// representation, which is distinct for the `256` roots.
+    logarithms: [(u64, u8); 1 << WINDOW],
+    /// The digits of `(t - 1) / 2` in base `2^4`, most significant first.
+    exponent_digits: Vec<u8>,
+}
+
+impl SqrtTables {
+    fn new() -> Self {
+        debug_assert_eq!(
+            <FqParameters as FftParameters>::TWO_ADICITY,
+            WINDOW * WINDOWS as u32
+        );
+        let mut inverse_powers = [[Fq::one(); 1 << WINDOW]; WINDOWS];
+        let mut base = Fq::two_adic_root_of_unity().inverse().unwrap();
+        for powers in inverse_powers.iter_mut() {
+            for j in 1..powers.len() {
+                powers[j] = powers[j - 1] * base;
+            }
+            for _ in 0..WINDOW {
+                base.square_in_place();
+            }
+        }
+        let mut logarithms = [(0u64, 0u8); 1 << WINDOW];
+        // z^j = (z^-1)^(256 - j)
+        let roots = &inverse_powers[WINDOWS - 1];
+        for (j, entry) in logarithms.iter_mut().enumerate() {
+            let root = roots[(roots.len() - j) % roots.len()];
+            *entry = (key(&root), j as u8);
+        }
+        logarithms.sort_unstable_by_key(|(key, _)| *key);
+
+        let bits = FqParameters::T_MINUS_ONE_DIV_TWO.to_bits_le();
+        let exponent_digits = bits
+            .chunks(EXPONENT_WINDOW as usize)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .rev()
+                    .fold(0u8, |acc, bit| (acc << 1) | *bit as u8)
+            })
+            .rev()
+            .skip_while(|digit| *digit == 0)
+            .collect();
+        Self {
+            inverse_powers,
+            logarithms,
+            exponent_digits,
+        }
+    }
+
+    /// `u^((t - 1) / 2)`, with a fixed window of 4 bits: about a quarter
+    /// of the multiplications of square-and-multiply.
+    fn pow_t_minus_one_div_two(&self, u: &Fq) -> Fq {
+        let mut powers = [Fq::one(); 1 << EXPONENT_WINDOW];
+        for j in 1..powers.len() {
+            powers[j] = powers[j - 1] * u;
+        }
+        let mut result = Fq::one();
+        for digit in self.exponent_digits.iter() {
+            for _ in 0..EXPONENT_WINDOW {
+                result.square_in_place();
+            }
+            if *digit != 0 {
+                result *= &powers[*digit as usize];
+            }
+        }
+        result
+    }
+
+    /// The discrete logarithm of a `256`-th root of unity, base `z`.
+    fn logarithm(&self, root: &Fq) -> u32 {
+        let i = self
+            .logarithms
+            .binary_search_by_key(&key(root), |(key, _)| *key)
+            .expect("a 256-th root of unity");
+        self.logarithms[i].1 as u32
+    }
+}
+
+fn key(x: &Fq) -> u64 {
+    (x.0).0[0]
+}
+
+static SQRT_TABLES: OnceBox<SqrtTables> = OnceBox::new();
+
+fn sqrt_tables() -> &'static SqrtTables {
+    SQRT_TABLES.get_or_init(|| Box::new(SqrtTables::new()))
+}
+
+/// A square root of `u`, or `None` if `u` is not a square, with the same
+/// exponentiation as Tonelli-Shanks but tables for its discrete
+/// logarithm. Which of the two roots is returned is unspecified.
+pub fn sqrt_with_tables(u: &Fq) -> Option<Fq> {
+    sqrt_in(sqrt_tables(), u)
+}
+
+/// The square roots of `values`, as [`sqrt_with_tables`] of each, with
+/// `None` for the non-squares.
+///
+/// The Legendre symbols of all values are computed first with
+/// [`batch_legendre`], which costs about a third of a root, so that
+/// non-squares skip the exponentiation altogether; the roots of the
+/// squares then share the tables and the decomposition of the exponent.
+/// With the `parallel` feature, the symbols and the roots are computed by
+/// the rayon thread pool.
+pub fn batch_sqrt(values: &[Fq]) -> Vec<Option<Fq>> {
+    let tables = sqrt_tables();
+    let symbols = batch_legendre(values);
+    cfg_iter!(values)
+        .zip(symbols)
+        .map(|(u, symbol)| match symbol {
+            -1 => None,
+            _ => sqrt_in(tables, u),
+        })
+        .collect()
+}
+
+fn sqrt_in(tables: &SqrtTables, u: &Fq) -> Option<Fq> {
+    if u.is_zero() {
+        return Some(Fq::zero());
+    }
+    // x = u^((t + 1) / 2) and b = u^t, so that x^2 = u b
+    let v = tables.pow_t_minus_one_div_two(u);
+    let x = *u * v;
+    let b = x * v;
+
+    // b^(2^(8 i)) for i = 0, ..., 3
+    let mut b_powers = [b; WINDOWS];
+    for i in 1..WINDOWS {
+        b_powers[i] = b_powers[i - 1];
+        for _ in 0..WINDOW {
+            b_powers[i].square_in_place();
+        }
+    }
+
+    // b = g^e, with the digits of e found from the least significant one:
+    // (b g^-(e mod 2^(8 k)))^(2^(24 - 8 k)) = z^(k-th digit)
+    let mut digits = [0u32; WINDOWS];
+    for k in 0..WINDOWS {
+        let mut root = b_powers[WINDOWS - 1 - k];
+        for (i, digit) in digits[..k].iter().enumerate() {
+            root *=
+                tables.inverse_powers[WINDOWS - 1 - (k - i)][*digit as usize];
+        }
+        digits[k] = tables.logarithm(&root);
+        // u is a square if and only if e is even
+        if k == 0 && digits[0] & 1 == 1 {
+            return None;
+        }
+    }
+
+    // sqrt(u) = x g^(-e / 2)
+    let half_e = digits
+        .iter()
+        .rev()
+        .fold(0u32, |acc, digit| (acc << WINDOW) | digit)
+        >> 1;
+    let mut root = x;
+    for (m, powers) in tables.inverse_powers.iter().enumerate() {
+        root *= powers[((half_e >> (WINDOW * m as u32)) & 0xff) as usize];
+    }
+    Some(root)
+}
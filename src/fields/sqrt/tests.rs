@@ -0,0 +1,79 @@
+use super::*;
+use ark_ff::SquareRootField;
+use ark_std::{test_rng, vec::Vec, UniformRand};
+
+#[test]
+fn test_sqrt_with_tables() {
+    let mut rng = test_rng();
+    for _ in 0..100 {
+        let u = Fq::rand(&mut rng);
+        match u.sqrt() {
+            Some(_) => {
+                let root = sqrt_with_tables(&u).unwrap();
+                assert_eq!(root.square(), u);
+            }
+            None => assert_eq!(sqrt_with_tables(&u), None),
+        }
+        let root = sqrt_with_tables(&u.square()).unwrap();
+        assert!(root == u || root == -u);
+    }
+    assert_eq!(sqrt_with_tables(&Fq::zero()), Some(Fq::zero()));
+    assert_eq!(sqrt_with_tables(&Fq::one()).unwrap().square(), Fq::one());
+    // roots of unity, for which u^t = u has a large discrete logarithm
+    let g = Fq::two_adic_root_of_unity();
+    assert_eq!(sqrt_with_tables(&g), None);
+    let mut root = g;
+    for _ in 0..32 {
+        let square = root.square();
+        assert_eq!(sqrt_with_tables(&square).unwrap().square(), square);
+        root = square;
+    }
+}
+
+#[test]
+fn test_sqrt_tables() {
+    let tables = SqrtTables::new();
+    // the keys of the 256-th roots of unity are distinct
+    for pair in tables.logarithms.windows(2) {
+        assert!(pair[0].0 < pair[1].0);
+    }
+    let z = tables.inverse_powers[WINDOWS - 1][1].inverse().unwrap();
+    let mut power = Fq::one();
+    for j in 0..256 {
+        assert_eq!(tables.logarithm(&power), j);
+        power *= z;
+    }
+    assert!(power.is_one());
+}
+
+#[test]
+fn test_batch_sqrt() {
+    let mut rng = test_rng();
+    let mut values: Vec<Fq> = (0..64).map(|_| Fq::rand(&mut rng)).collect();
+    values.push(Fq::zero());
+    values.push(Fq::two_adic_root_of_unity());
+    let roots = batch_sqrt(&values);
+    assert_eq!(roots.len(), values.len());
+    for (u, root) in values.iter().zip(&roots) {
+        match u.sqrt() {
+            Some(_) => assert_eq!(root.unwrap().square(), *u),
+            None => assert_eq!(*root, None),
+        }
+    }
+    assert_eq!(roots[64], Some(Fq::zero()));
+    assert_eq!(roots[65], None);
+    assert!(batch_sqrt(&[]).is_empty());
+}
+
+#[test]
+fn test_pow_t_minus_one_div_two() {
+    let tables = SqrtTables::new();
+    let mut rng = test_rng();
+    for _ in 0..16 {
+        let u = Fq::rand(&mut rng);
+        assert_eq!(
+            tables.pow_t_minus_one_div_two(&u),
+            u.pow(FqParameters::T_MINUS_ONE_DIV_TWO)
+        );
+    }
+}
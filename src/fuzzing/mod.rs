@@ -0,0 +1,228 @@
+//! Implementations of [`arbitrary::Arbitrary`], behind the `fuzzing`
+//! feature, so that fuzz targets and property tests can draw structured
+//! inputs from raw bytes.
+//!
+//! The points and field elements of bandersnatch are arkworks types, for
+//! which this crate cannot implement `Arbitrary`; they are drawn through
+//! the newtypes below instead. [`Element`] implements `Arbitrary` itself.
+//!
+//! [`ArbitraryEncoding`] draws compressed encodings, see
+//! [`to_compressed_bytes`], deliberately including invalid ones, each
+//! labeled with the outcome that
+//! [`ValidatedPoint::from_compressed_bytes`](crate::ValidatedPoint::from_compressed_bytes)
+//! must have:
+//!
+//! ```ignore
+//! fuzz_target!(|encoding: ArbitraryEncoding| {
+//!     let decoded = ValidatedPoint::from_compressed_bytes(&encoding.bytes);
+//!     if let Some(expected) = encoding.kind.expected() {
+//!         assert_eq!(decoded.err(), expected);
+//!     }
+//! });
+//! ```
+
+use crate::{
+    banderwagon::Element, from_montgomery, mul_by_generator,
+    to_compressed_bytes, to_montgomery, torsion_points, EdwardsAffine, Fq, Fr,
+    PointError, PointFromCoordinate, COMPRESSED_SIZE,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{BigInteger, One, PrimeField};
+
+#[cfg(test)]
+mod tests;
+
+/// An element of `Fr`, drawn as 32 bytes reduced modulo `r`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArbitraryScalar(pub Fr);
+
+/// An element of `Fq`, drawn as 32 bytes reduced modulo `q`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArbitraryFq(pub Fq);
+
+/// A point of the prime order subgroup, `k G` for an arbitrary scalar `k`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArbitraryPoint(pub EdwardsAffine);
+
+/// A point of the curve, in or out of the prime order subgroup: a point of
+/// the subgroup plus an arbitrary torsion point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArbitraryCurvePoint(pub EdwardsAffine);
+
+/// The kinds of compressed encodings drawn by [`ArbitraryEncoding`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncodingKind {
+    /// A point of the prime order subgroup.
+    Valid,
+    /// A point of the curve with a torsion component.
+    NotInPrimeSubgroup,
+    /// A canonical `y` coordinate with no point on the curve.
+    NotOnCurve,
+    /// A `y` coordinate not smaller than `q`, or `x = 0` with the parity
+    /// bit set.
+    NonCanonical,
+    /// 32 arbitrary bytes.
+    Unstructured,
+}
+
+impl EncodingKind {
+    /// The error of
+    /// [`ValidatedPoint::from_compressed_bytes`](crate::ValidatedPoint::from_compressed_bytes)
+    /// on an encoding of this kind, `Some(None)` for a valid encoding, or
+    /// `None` if the outcome is unknown.
+    pub fn expected(&self) -> Option<Option<PointError>> {
+        match self {
+            EncodingKind::Valid => Some(None),
+            EncodingKind::NotInPrimeSubgroup => {
+                Some(Some(PointError::NotInPrimeSubgroup))
+            }
+            EncodingKind::NotOnCurve => Some(Some(PointError::NotOnCurve)),
+            EncodingKind::NonCanonical => {
+                Some(Some(PointError::NonCanonicalEncoding))
+            }
+            EncodingKind::Unstructured => None,
+        }
+    }
+}
+
+/// A compressed encoding of the given kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArbitraryEncoding {
+    /// The kind of the encoding.
+    pub kind: EncodingKind,
+    /// The encoding.
+    pub bytes: [u8; COMPRESSED_SIZE],
+}
+
+impl<'a> Arbitrary<'a> for ArbitraryScalar {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes: [u8; 32] = u.arbitrary()?;
+        Ok(Self(Fr::from_le_bytes_mod_order(&bytes)))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (32, Some(32))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ArbitraryFq {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes: [u8; 32] = u.arbitrary()?;
+        Ok(Self(Fq::from_le_bytes_mod_order(&bytes)))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (32, Some(32))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ArbitraryPoint {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let k = ArbitraryScalar::arbitrary(u)?.0;
+        Ok(Self(mul_by_generator(&k).into_affine()))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        ArbitraryScalar::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ArbitraryCurvePoint {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let p = ArbitraryPoint::arbitrary(u)?.0;
+        let torsion = torsion_points()[u.choose_index(4)?];
+        // the sum is affine in the twisted Edwards model unless p = O
+        let sum = to_montgomery(&p) + torsion;
+        Ok(Self(from_montgomery(&sum).unwrap_or(p)))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let (lower, upper) = ArbitraryPoint::size_hint(depth);
+        (lower + 1, upper.map(|upper| upper + 4))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Element {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Element::mul_by_generator(&ArbitraryScalar::arbitrary(u)?.0))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        ArbitraryScalar::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for EncodingKind {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[
+            EncodingKind::Valid,
+            EncodingKind::NotInPrimeSubgroup,
+            EncodingKind::NotOnCurve,
+            EncodingKind::NonCanonical,
+            EncodingKind::Unstructured,
+        ])
+        .copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for ArbitraryEncoding {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let kind = EncodingKind::arbitrary(u)?;
+        let bytes = match kind {
+            EncodingKind::Valid => {
+                to_compressed_bytes(&ArbitraryPoint::arbitrary(u)?.0)
+            }
+            EncodingKind::NotInPrimeSubgroup => {
+                let p = ArbitraryPoint::arbitrary(u)?.0;
+                let torsion = torsion_points()[1 + u.choose_index(3)?];
+                let sum = to_montgomery(&p) + torsion;
+                // O + (u, 0) is at infinity for u != 0
+                let q = from_montgomery(&sum).unwrap_or_else(|| {
+                    EdwardsAffine::new(Fq::from(0u64), -Fq::one())
+                });
+                to_compressed_bytes(&q)
+            }
+            EncodingKind::NotOnCurve => {
+                let mut y = ArbitraryFq::arbitrary(u)?.0;
+                // about half of the coordinates are on the curve
+                while EdwardsAffine::from_y_with_sign(y, false).is_some() {
+                    y += Fq::one();
+                }
+                let mut bytes = encode_y(&y.into_repr());
+                bytes[COMPRESSED_SIZE - 1] |=
+                    u8::from(bool::arbitrary(u)?) << 7;
+                bytes
+            }
+            EncodingKind::NonCanonical => {
+                if bool::arbitrary(u)? {
+                    // y in [0x74 * 2^248, 2^255), above q = 0x73ed... and
+                    // with an arbitrary parity bit
+                    let mut bytes: [u8; COMPRESSED_SIZE] = u.arbitrary()?;
+                    let top = u.int_in_range(0x74..=0x7f)?;
+                    bytes[COMPRESSED_SIZE - 1] =
+                        (bytes[COMPRESSED_SIZE - 1] & 0x80) | top;
+                    bytes
+                } else {
+                    // x = 0 with the parity bit set, y = 1 or y = -1
+                    let y = if bool::arbitrary(u)? {
+                        Fq::one()
+                    } else {
+                        -Fq::one()
+                    };
+                    let mut bytes = encode_y(&y.into_repr());
+                    bytes[COMPRESSED_SIZE - 1] |= 0x80;
+                    bytes
+                }
+            }
+            EncodingKind::Unstructured => u.arbitrary()?,
+        };
+        Ok(Self { kind, bytes })
+    }
+}
+
+fn encode_y<B: BigInteger>(y: &B) -> [u8; COMPRESSED_SIZE] {
+    let mut bytes = [0u8; COMPRESSED_SIZE];
+    bytes.copy_from_slice(&y.to_bytes_le());
+    bytes
+}
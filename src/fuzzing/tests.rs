@@ -0,0 +1,64 @@
+use super::*;
+use crate::{is_torsion_free, Validate, ValidatedPoint};
+use ark_ff::{BigInteger, Zero};
+use ark_std::{rand::RngCore, test_rng, vec::Vec};
+
+/// Draw `count` values from random bytes.
+fn draw<T: for<'a> Arbitrary<'a>>(count: usize) -> Vec<T> {
+    let mut rng = test_rng();
+    let mut bytes = ark_std::vec![0u8; 128 * count];
+    rng.fill_bytes(&mut bytes);
+    let mut u = Unstructured::new(&bytes);
+    (0..count).map(|_| T::arbitrary(&mut u).unwrap()).collect()
+}
+
+#[test]
+fn test_points() {
+    for p in draw::<ArbitraryPoint>(32) {
+        assert_eq!(p.0.validate(), Ok(()));
+    }
+    for e in draw::<Element>(8) {
+        assert_eq!(Element::from_bytes(&e.to_bytes()), Ok(e));
+    }
+
+    let points = draw::<ArbitraryCurvePoint>(64);
+    assert!(points.iter().all(|p| p.0.is_on_curve()));
+    assert!(points.iter().any(|p| is_torsion_free(&p.0)));
+    assert!(points.iter().any(|p| !is_torsion_free(&p.0)));
+}
+
+#[test]
+fn test_encodings() {
+    let encodings = draw::<ArbitraryEncoding>(256);
+    for kind in [
+        EncodingKind::Valid,
+        EncodingKind::NotInPrimeSubgroup,
+        EncodingKind::NotOnCurve,
+        EncodingKind::NonCanonical,
+        EncodingKind::Unstructured,
+    ] {
+        assert!(encodings.iter().any(|e| e.kind == kind));
+    }
+    let q = <crate::FqParameters as ark_ff::FpParameters>::MODULUS;
+    assert_eq!(q.to_bytes_le()[31], 0x73);
+    for encoding in encodings {
+        let decoded = ValidatedPoint::from_compressed_bytes(&encoding.bytes);
+        if let Some(expected) = encoding.kind.expected() {
+            assert_eq!(decoded.err(), expected, "{:?}", encoding);
+        }
+    }
+}
+
+#[test]
+fn test_exhausted_input() {
+    // arbitrary pads exhausted inputs with zeros
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(
+        ArbitraryScalar::arbitrary(&mut u).unwrap().0,
+        Fr::from(0u64)
+    );
+    let mut u = Unstructured::new(&[]);
+    let encoding = ArbitraryEncoding::arbitrary(&mut u).unwrap();
+    assert_eq!(encoding.kind, EncodingKind::Valid);
+    assert_eq!(encoding.bytes, to_compressed_bytes(&EdwardsAffine::zero()));
+}
@@ -0,0 +1,143 @@
+//! A minimal interface to prime order groups, so that protocols can be
+//! written once and instantiated with other groups, while bandersnatch
+//! remains the default, with its tuned arithmetic.
+//!
+//! The trait is implemented by the Banderwagon
+//! [`Element`](crate::banderwagon::Element) and by [`ValidatedPoint`], a
+//! point of the prime order subgroup of bandersnatch; the full curve, with
+//! its cofactor of 4, is not a prime order group. The Schnorr signatures of
+//! [`schnorr::generic`](crate::schnorr::generic), which [`SigningKey`]
+//! instantiates, and the Pedersen commitments of
+//! [`commit_reveal::pedersen_commit`](crate::commit_reveal::pedersen_commit)
+//! are written over it. The inner product argument is not, as it relies on
+//! the MSM and the point encoding of bandersnatch; the crate has no DLEQ
+//! or OPRF protocol.
+//!
+//! [`SigningKey`]: crate::schnorr::SigningKey
+
+use crate::{
+    ladder_mul, mul_by_generator, wipe, EdwardsAffine, EdwardsParameters,
+    Endianness, Fr, GLVParameters, ValidatedPoint, UNCOMPRESSED_SIZE,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, ToBytes, Zero};
+use ark_std::{
+    fmt::Debug,
+    ops::{Add, Neg, Sub},
+    vec::Vec,
+};
+
+/// A group of prime order, written additively, with a fixed generator.
+///
+/// Implementations must only produce elements of the group: in particular,
+/// [`PrimeOrderGroup::from_bytes`] rejects anything that is not the
+/// encoding of an element.
+pub trait PrimeOrderGroup:
+    Copy
+    + Debug
+    + Eq
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The field of the scalars, of the order of the group.
+    type Scalar: PrimeField;
+
+    /// The size of an encoded element, in bytes.
+    const ENCODED_SIZE: usize;
+
+    /// The neutral element.
+    fn identity() -> Self;
+
+    /// The fixed generator.
+    fn generator() -> Self;
+
+    /// Whether this is the neutral element.
+    fn is_identity(&self) -> bool;
+
+    /// Compute `scalar * self`, possibly in variable time: the scalar must
+    /// be public.
+    fn mul(&self, scalar: &Self::Scalar) -> Self;
+
+    /// Compute `scalar * self` in constant time, for secret scalars.
+    fn mul_secret(&self, scalar: &Self::Scalar) -> Self;
+
+    /// Compute `scalar * G`, where `G` is the generator, possibly in
+    /// variable time: the scalar must be public.
+    fn mul_by_generator(scalar: &Self::Scalar) -> Self {
+        Self::generator().mul(scalar)
+    }
+
+    /// Erase a secret scalar once it is no longer needed.
+    fn wipe_scalar(scalar: &mut Self::Scalar);
+
+    /// Encode the element as [`PrimeOrderGroup::ENCODED_SIZE`] bytes.
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Decode an element encoded with [`PrimeOrderGroup::to_bytes`],
+    /// returning `None` if `bytes` does not encode an element of the group.
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+/// The prime order subgroup of bandersnatch, with the GLV and comb table
+/// multiplications for public scalars and the Montgomery ladder for secret
+/// ones.
+///
+/// Elements are encoded uncompressed, as [`to_bytes_uncompressed`] does
+/// in little-endian order, which is the encoding hashed into the
+/// challenges of [`SigningKey`].
+///
+/// [`to_bytes_uncompressed`]: crate::to_bytes_uncompressed
+/// [`SigningKey`]: crate::schnorr::SigningKey
+impl PrimeOrderGroup for ValidatedPoint {
+    type Scalar = Fr;
+
+    const ENCODED_SIZE: usize = UNCOMPRESSED_SIZE;
+
+    fn identity() -> Self {
+        Self::new_unchecked(EdwardsAffine::zero())
+    }
+
+    fn generator() -> Self {
+        Self::new_unchecked(EdwardsAffine::prime_subgroup_generator())
+    }
+
+    fn is_identity(&self) -> bool {
+        self.as_affine().is_zero()
+    }
+
+    fn mul(&self, scalar: &Fr) -> Self {
+        Self::new_unchecked(
+            EdwardsParameters::glv_mul(self.as_affine(), scalar).into_affine(),
+        )
+    }
+
+    fn mul_secret(&self, scalar: &Fr) -> Self {
+        Self::new_unchecked(ladder_mul(self.as_affine(), scalar).into_affine())
+    }
+
+    fn mul_by_generator(scalar: &Fr) -> Self {
+        Self::new_unchecked(mul_by_generator(scalar).into_affine())
+    }
+
+    fn wipe_scalar(scalar: &mut Fr) {
+        wipe(scalar);
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::ENCODED_SIZE);
+        self.as_affine().write(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut array = [0u8; UNCOMPRESSED_SIZE];
+        if bytes.len() != UNCOMPRESSED_SIZE {
+            return None;
+        }
+        array.copy_from_slice(bytes);
+        Self::from_bytes_uncompressed(&array, Endianness::Little).ok()
+    }
+}
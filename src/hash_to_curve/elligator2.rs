@@ -0,0 +1,109 @@
+//! The Elligator 2 map of RFC 9380, section 6.7.1, to the Montgomery model
+//! `K t^2 = s^3 + J s^2 + s` of bandersnatch, i.e., `J = A` and `K = B`,
+//! followed by the rational map of appendix D.1 to the twisted Edwards
+//! model.
+//!
+//! The map follows the straight-line description of appendix F.3: the
+//! square root, Legendre symbol and inversions are computed with
+//! [`ct_sqrt`], [`ct_legendre`] and [`ct_pow`], and the branches are
+//! conditional moves.
+
+use crate::{
+    conditional_assign, ct_eq, ct_legendre, ct_pow, ct_sqrt, to_montgomery,
+    EdwardsAffine, Fq, FqParameters, MONTGOMERY_A, MONTGOMERY_B,
+};
+use ark_ff::{
+    field_new, BigInteger, BigInteger256, Field, FpParameters, LegendreSymbol,
+    One, PrimeField, SquareRootField, Zero,
+};
+use ark_std::vec::Vec;
+
+/// The non-square `Z` of the map, as chosen by the procedure of RFC 9380,
+/// appendix H.3.
+pub const ELL2_Z: Fq = field_new!(Fq, "5");
+
+/// Map a field element to a point of the curve, not necessarily in the
+/// prime order subgroup.
+///
+/// The map runs in constant time, except for the final inversion of the
+/// conversion from projective coordinates.
+pub fn map_to_curve_elligator2(u: &Fq) -> EdwardsAffine {
+    let k_inv = MONTGOMERY_B.inverse().unwrap();
+    let j_over_k = MONTGOMERY_A * k_inv;
+    let k_inv2 = k_inv.square();
+    // g(x) = x^3 + (J / K) x^2 + x / K^2
+    let g = |x: &Fq| *x * (x.square() + j_over_k * x + k_inv2);
+
+    // x1 = -(J / K) / (1 + Z u^2), or -(J / K) if the latter is 0
+    let mut x1 = -j_over_k * inv0(&(Fq::one() + ELL2_Z * u.square()));
+    let e = ct_eq(&x1, &Fq::zero());
+    conditional_assign(&mut x1, &-j_over_k, e);
+    let gx1 = g(&x1);
+    let x2 = -x1 - j_over_k;
+    let gx2 = g(&x2);
+
+    // x = x1 and sgn0(y) = 1 if gx1 is a square, x = x2 and sgn0(y) = 0
+    // otherwise
+    let e1 = (ct_legendre(&gx1) != LegendreSymbol::QuadraticNonResidue) as u64;
+    let mut x = x2;
+    conditional_assign(&mut x, &x1, e1);
+    let mut y2 = gx2;
+    conditional_assign(&mut y2, &gx1, e1);
+    let mut y = ct_sqrt(&y2).expect("either gx1 or gx2 is a square");
+    let e2 = y.into_repr().is_odd() as u64;
+    let minus_y = -y;
+    conditional_assign(&mut y, &minus_y, e1 ^ e2);
+
+    let s = x * MONTGOMERY_B;
+    let t = y * MONTGOMERY_B;
+    montgomery_to_edwards(&s, &t)
+}
+
+/// All the `u` such that `map_to_curve_elligator2(u) = p`, at most four,
+/// since the map is 2-to-1 on each of its two branches. Not constant time.
+///
+/// The identity and `(0, -1)`, which only have exceptional preimages, if
+/// any, are reported without preimages.
+pub(super) fn elligator2_preimages(p: &EdwardsAffine) -> Vec<Fq> {
+    let mut preimages = Vec::new();
+    if p.x.is_zero() {
+        return preimages;
+    }
+    let j_over_k = MONTGOMERY_A * MONTGOMERY_B.inverse().unwrap();
+    let x = to_montgomery(p).u / MONTGOMERY_B;
+    // x = x1 = -(J / K) / (1 + Z u^2), or x = x2 = -x1 - J / K
+    let branches =
+        [(-(x + j_over_k), ELL2_Z * x), (-x, ELL2_Z * (x + j_over_k))];
+    for (num, den) in branches.iter() {
+        let root = match den.inverse().and_then(|inv| (*num * inv).sqrt()) {
+            Some(root) => root,
+            None => continue,
+        };
+        for u in [root, -root].iter() {
+            if !preimages.contains(u) && map_to_curve_elligator2(u) == *p {
+                preimages.push(*u);
+            }
+        }
+    }
+    preimages
+}
+
+/// The rational map `(v, w) = (s / t, (s - 1) / (s + 1))`, which sends the
+/// exceptional points with `t = 0` or `s = -1` to the identity.
+fn montgomery_to_edwards(s: &Fq, t: &Fq) -> EdwardsAffine {
+    let tv1 = *s + Fq::one();
+    let tv2 = inv0(&(tv1 * t));
+    let v = tv2 * tv1 * s;
+    let mut w = tv2 * t * (*s - Fq::one());
+    let e = ct_eq(&tv2, &Fq::zero());
+    conditional_assign(&mut w, &Fq::one(), e);
+    EdwardsAffine::new(v, w)
+}
+
+/// `x^(q - 2)`, i.e., the inverse of `x`, or 0 if `x` is 0, in constant
+/// time.
+pub(super) fn inv0(x: &Fq) -> Fq {
+    let mut exp = FqParameters::MODULUS;
+    exp.sub_noborrow(&BigInteger256::from(2));
+    ct_pow(x, exp.as_ref())
+}
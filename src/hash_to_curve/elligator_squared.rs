@@ -0,0 +1,126 @@
+//! Elligator Squared, after Tibouchi, "Elligator Squared: Uniform Points on
+//! Elliptic Curves of Prime Order as Uniform Random Strings", with the
+//! Elligator 2 map `f` of
+//! [`map_to_curve_elligator2`](super::map_to_curve_elligator2).
+//!
+//! A point `P` is represented by a pair `(u0, u1)` with
+//! `f(u0) + f(u1) = P`: `u0` is sampled uniformly, and `u1` among the
+//! preimages of `P - f(u0)`, which are computed by inverting the map. For
+//! uniformly distributed points, the pair is uniformly distributed in
+//! `Fq^2`, up to a negligible statistical distance. Each element is then
+//! written as a uniformly random 48-byte big-endian integer congruent to
+//! it modulo `q`, so that the 96-byte encoding is indistinguishable from
+//! random bytes, and is decoded as [`hash_to_field`](super::hash_to_field)
+//! reduces 48 bytes.
+//!
+//! Encoding is not constant time, and needs about two tries, each with a
+//! square root and up to four map evaluations.
+
+use super::elligator2::{elligator2_preimages, map_to_curve_elligator2};
+use crate::{
+    from_montgomery, to_montgomery, EdwardsAffine, Fq, FqParameters,
+    MontgomeryAffine,
+};
+use ark_ff::{BigInteger, BigInteger384, FpParameters, PrimeField};
+use ark_std::{rand::Rng, UniformRand};
+
+/// The size of the encoding of a field element, in bytes.
+const FIELD_ENCODED_SIZE: usize = 48;
+
+/// The size of an Elligator Squared encoding, in bytes.
+pub const ELLIGATOR_SQUARED_SIZE: usize = 2 * FIELD_ENCODED_SIZE;
+
+/// Encode `p` as [`ELLIGATOR_SQUARED_SIZE`] bytes which are uniformly
+/// random if `p` is a uniformly random point of the curve.
+pub fn elligator_squared_encode<R: Rng + ?Sized>(
+    p: &EdwardsAffine,
+    rng: &mut R,
+) -> [u8; ELLIGATOR_SQUARED_SIZE] {
+    encode_montgomery(&to_montgomery(p), rng)
+}
+
+/// Decode bytes produced by [`elligator_squared_encode`], or any bytes,
+/// into a point of the curve.
+///
+/// Returns `None` if the point is one of the two points of order 2 at
+/// infinity of the twisted Edwards model, which happens with negligible
+/// probability for random bytes.
+pub fn elligator_squared_decode(
+    bytes: &[u8; ELLIGATOR_SQUARED_SIZE],
+) -> Option<EdwardsAffine> {
+    from_montgomery(&decode_montgomery(bytes))
+}
+
+/// [`elligator_squared_encode`] for a point given in the Montgomery model,
+/// which may be at infinity in the twisted Edwards model.
+pub(crate) fn encode_montgomery<R: Rng + ?Sized>(
+    p: &MontgomeryAffine,
+    rng: &mut R,
+) -> [u8; ELLIGATOR_SQUARED_SIZE] {
+    loop {
+        let u0 = Fq::rand(rng);
+        // P - f(u0), which has no preimage if it is at infinity
+        let q = *p + -to_montgomery(&map_to_curve_elligator2(&u0));
+        let preimages = match from_montgomery(&q) {
+            Some(q) => elligator2_preimages(&q),
+            None => continue,
+        };
+        // keep each of the at most four preimages with probability 1 / 4,
+        // so that the pair is uniform
+        let i = rng.gen_range(0..4);
+        if let Some(u1) = preimages.get(i) {
+            let mut bytes = [0u8; ELLIGATOR_SQUARED_SIZE];
+            bytes[..FIELD_ENCODED_SIZE].copy_from_slice(&lift(&u0, rng));
+            bytes[FIELD_ENCODED_SIZE..].copy_from_slice(&lift(u1, rng));
+            return bytes;
+        }
+    }
+}
+
+/// [`elligator_squared_decode`] to the Montgomery model, which also
+/// represents the points at infinity of the twisted Edwards model.
+pub(crate) fn decode_montgomery(
+    bytes: &[u8; ELLIGATOR_SQUARED_SIZE],
+) -> MontgomeryAffine {
+    let (b0, b1) = bytes.split_at(FIELD_ENCODED_SIZE);
+    let u0 = Fq::from_be_bytes_mod_order(b0);
+    let u1 = Fq::from_be_bytes_mod_order(b1);
+    to_montgomery(&map_to_curve_elligator2(&u0))
+        + to_montgomery(&map_to_curve_elligator2(&u1))
+}
+
+/// A uniformly random 48-byte big-endian integer congruent to `u` modulo
+/// `q`: a random integer `n` whose residue is replaced with `u`.
+fn lift<R: Rng + ?Sized>(u: &Fq, rng: &mut R) -> [u8; FIELD_ENCODED_SIZE] {
+    let mut bytes = [0u8; FIELD_ENCODED_SIZE];
+    rng.fill_bytes(&mut bytes);
+    let residue = widen(&Fq::from_be_bytes_mod_order(&bytes).into_repr());
+    let u = widen(&u.into_repr());
+    let mut n = BigInteger384([0; 6]);
+    for (limb, chunk) in n.0.iter_mut().zip(bytes.rchunks(8)) {
+        let mut be = [0u8; 8];
+        be.copy_from_slice(chunk);
+        *limb = u64::from_be_bytes(be);
+    }
+    if u >= residue {
+        let mut diff = u;
+        diff.sub_noborrow(&residue);
+        // n - residue + u overflows only if n lies in the last, partial,
+        // interval of length q
+        if n.add_nocarry(&diff) {
+            n.sub_noborrow(&widen(&FqParameters::MODULUS));
+        }
+    } else {
+        let mut diff = residue;
+        diff.sub_noborrow(&u);
+        n.sub_noborrow(&diff);
+    }
+    bytes.copy_from_slice(&n.to_bytes_be());
+    bytes
+}
+
+fn widen(n: &<Fq as PrimeField>::BigInt) -> BigInteger384 {
+    let mut wide = BigInteger384([0; 6]);
+    wide.0[..4].copy_from_slice(&n.0);
+    wide
+}
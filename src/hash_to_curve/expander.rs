@@ -0,0 +1,294 @@
+use super::{suite_dst, Encoding, HashToCurveError};
+use ark_std::vec::Vec;
+use sha2::{
+    digest::{core_api::BlockSizeUser, Digest},
+    Sha256, Sha512,
+};
+
+#[cfg(any(feature = "sha3", feature = "blake3"))]
+use sha2::digest::{ExtendableOutput, Update, XofReader};
+
+/// Prefix used to hash domain separation tags longer than 255 bytes.
+const OVERSIZE_DST_SALT: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Maximum length of a domain separation tag.
+const MAX_DST_LENGTH: usize = 255;
+
+/// An `expand_message` function as specified in RFC 9380, section 5.3,
+/// bound to a domain separation tag.
+pub trait Expander: Sized {
+    /// The `HASH_ID` component of the suite ID, e.g. `XMD:SHA-256`.
+    const HASH_ID: &'static [u8];
+
+    /// Build an expander for a domain separation tag. Tags longer than
+    /// 255 bytes are hashed down as in RFC 9380, section 5.3.3.
+    fn new(dst: &[u8]) -> Self;
+
+    /// The domain separation tag actually used by the expander.
+    fn dst(&self) -> &[u8];
+
+    /// Expand `msg` into `len_in_bytes` uniformly random bytes.
+    fn expand_message(
+        &self,
+        msg: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, HashToCurveError>;
+
+    /// Build an expander whose domain separation tag is
+    /// `tag || "-with-" || suite_id`, where the suite ID is derived from
+    /// this expander, the map `map_id` and the `encoding`.
+    fn with_suite(tag: &[u8], map_id: &[u8], encoding: Encoding) -> Self {
+        Self::new(&suite_dst::<Self>(tag, map_id, encoding))
+    }
+}
+
+/// `expand_message_xmd` instantiated with SHA-256.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpanderXmdSha256 {
+    dst: Vec<u8>,
+}
+
+impl Expander for ExpanderXmdSha256 {
+    const HASH_ID: &'static [u8] = b"XMD:SHA-256";
+
+    fn new(dst: &[u8]) -> Self {
+        Self {
+            dst: reduce_dst_xmd::<Sha256>(dst),
+        }
+    }
+
+    fn dst(&self) -> &[u8] {
+        &self.dst
+    }
+
+    fn expand_message(
+        &self,
+        msg: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, HashToCurveError> {
+        expand_xmd::<Sha256>(&self.dst, msg, len_in_bytes)
+    }
+}
+
+/// `expand_message_xmd` instantiated with SHA-512.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpanderXmdSha512 {
+    dst: Vec<u8>,
+}
+
+impl Expander for ExpanderXmdSha512 {
+    const HASH_ID: &'static [u8] = b"XMD:SHA-512";
+
+    fn new(dst: &[u8]) -> Self {
+        Self {
+            dst: reduce_dst_xmd::<Sha512>(dst),
+        }
+    }
+
+    fn dst(&self) -> &[u8] {
+        &self.dst
+    }
+
+    fn expand_message(
+        &self,
+        msg: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, HashToCurveError> {
+        expand_xmd::<Sha512>(&self.dst, msg, len_in_bytes)
+    }
+}
+
+/// `expand_message_xof` instantiated with SHAKE128.
+#[cfg(feature = "sha3")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpanderXofShake128 {
+    dst: Vec<u8>,
+}
+
+#[cfg(feature = "sha3")]
+impl Expander for ExpanderXofShake128 {
+    const HASH_ID: &'static [u8] = b"XOF:SHAKE128";
+
+    fn new(dst: &[u8]) -> Self {
+        Self {
+            dst: reduce_dst_xof::<sha3::Shake128>(dst, 32),
+        }
+    }
+
+    fn dst(&self) -> &[u8] {
+        &self.dst
+    }
+
+    fn expand_message(
+        &self,
+        msg: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, HashToCurveError> {
+        expand_xof::<sha3::Shake128>(&self.dst, msg, len_in_bytes)
+    }
+}
+
+/// `expand_message_xof` instantiated with SHAKE256.
+#[cfg(feature = "sha3")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpanderXofShake256 {
+    dst: Vec<u8>,
+}
+
+#[cfg(feature = "sha3")]
+impl Expander for ExpanderXofShake256 {
+    const HASH_ID: &'static [u8] = b"XOF:SHAKE256";
+
+    fn new(dst: &[u8]) -> Self {
+        Self {
+            dst: reduce_dst_xof::<sha3::Shake256>(dst, 64),
+        }
+    }
+
+    fn dst(&self) -> &[u8] {
+        &self.dst
+    }
+
+    fn expand_message(
+        &self,
+        msg: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, HashToCurveError> {
+        expand_xof::<sha3::Shake256>(&self.dst, msg, len_in_bytes)
+    }
+}
+
+/// `expand_message_xof` instantiated with BLAKE3 in its XOF mode.
+#[cfg(feature = "blake3")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpanderXofBlake3 {
+    dst: Vec<u8>,
+}
+
+#[cfg(feature = "blake3")]
+impl Expander for ExpanderXofBlake3 {
+    const HASH_ID: &'static [u8] = b"XOF:BLAKE3";
+
+    fn new(dst: &[u8]) -> Self {
+        Self {
+            dst: reduce_dst_xof::<blake3::Hasher>(dst, 32),
+        }
+    }
+
+    fn dst(&self) -> &[u8] {
+        &self.dst
+    }
+
+    fn expand_message(
+        &self,
+        msg: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, HashToCurveError> {
+        expand_xof::<blake3::Hasher>(&self.dst, msg, len_in_bytes)
+    }
+}
+
+/// Hash an oversized tag down to the output size of `H`, RFC 9380,
+/// section 5.3.3.
+fn reduce_dst_xmd<H: Digest>(dst: &[u8]) -> Vec<u8> {
+    if dst.len() <= MAX_DST_LENGTH {
+        return dst.to_vec();
+    }
+    H::new()
+        .chain_update(OVERSIZE_DST_SALT)
+        .chain_update(dst)
+        .finalize()
+        .to_vec()
+}
+
+/// RFC 9380, section 5.3.1.
+fn expand_xmd<H: Digest + BlockSizeUser + Clone>(
+    dst: &[u8],
+    msg: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, HashToCurveError> {
+    let b_in_bytes = <H as Digest>::output_size();
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    if ell > 255 || len_in_bytes > 65535 {
+        return Err(HashToCurveError::OutputTooLong);
+    }
+
+    let dst_len = [dst.len() as u8];
+    let z_pad = ark_std::vec![0u8; H::block_size()];
+    let l_i_b_str = (len_in_bytes as u16).to_be_bytes();
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let b_0 = H::new()
+        .chain_update(&z_pad)
+        .chain_update(msg)
+        .chain_update(l_i_b_str)
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    let mut b_i = H::new()
+        .chain_update(&b_0)
+        .chain_update([1u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+        let xored: Vec<u8> =
+            b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = H::new()
+            .chain_update(&xored)
+            .chain_update([i as u8])
+            .chain_update(dst)
+            .chain_update(dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+/// RFC 9380, section 5.3.2.
+#[cfg(any(feature = "sha3", feature = "blake3"))]
+fn expand_xof<H: Default + Update + ExtendableOutput>(
+    dst: &[u8],
+    msg: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, HashToCurveError> {
+    if len_in_bytes > 65535 {
+        return Err(HashToCurveError::OutputTooLong);
+    }
+
+    let mut hasher = H::default();
+    hasher.update(msg);
+    hasher.update(&(len_in_bytes as u16).to_be_bytes());
+    hasher.update(dst);
+    hasher.update(&[dst.len() as u8]);
+
+    let mut uniform_bytes = ark_std::vec![0u8; len_in_bytes];
+    hasher.finalize_xof().read(&mut uniform_bytes);
+    Ok(uniform_bytes)
+}
+
+/// Hash an oversized tag down to `len = ceil(2 * k / 8)` bytes, where `k`
+/// is the security level of the XOF.
+#[cfg(any(feature = "sha3", feature = "blake3"))]
+fn reduce_dst_xof<H: Default + Update + ExtendableOutput>(
+    dst: &[u8],
+    len: usize,
+) -> Vec<u8> {
+    if dst.len() <= MAX_DST_LENGTH {
+        return dst.to_vec();
+    }
+    let mut hasher = H::default();
+    hasher.update(OVERSIZE_DST_SALT);
+    hasher.update(dst);
+    let mut reduced = ark_std::vec![0u8; len];
+    hasher.finalize_xof().read(&mut reduced);
+    reduced
+}
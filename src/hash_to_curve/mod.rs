@@ -0,0 +1,272 @@
+//! This module implements the building blocks for hashing to the
+//! bandersnatch curve following
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+//!
+//! Messages are expanded into uniform bytes with either
+//! `expand_message_xmd` (SHA-256 or SHA-512), or `expand_message_xof`
+//! (SHAKE128 and SHAKE256 behind the `sha3` feature, BLAKE3 behind the
+//! `blake3` feature). The expanders are public, so that other hashes, e.g.,
+//! Fiat-Shamir challenges, can share them.
+//! Every expander carries a domain separation tag (DST); the helpers
+//! [`suite_id`] and [`suite_dst`] derive a DST that embeds the suite ID,
+//! so that two suites never share a DST.
+//!
+//! [`hash_to_curve`] is the `hash_to_curve` function of the RFC, with the
+//! Elligator 2 map of [`map_to_curve_elligator2`], for any expander: the
+//! suite is determined by the DST of the expander, e.g.,
+//! `ExpanderXmdSha256::with_suite(tag, ELL2_MAP_ID, Encoding::Uniform)`
+//! for `bandersnatch_XMD:SHA-256_ELL2_RO_`. [`hash_to_curve_sswu`] is the
+//! same with the simplified SWU map of [`map_to_curve_sswu`], through the
+//! 2-isogenous short Weierstrass curve. [`encode_to_curve`] and
+//! [`encode_to_curve_sswu`] are the cheaper nonuniform encodings, with a
+//! single map evaluation, for suites with `ENC_VAR = NU_`. [`hash_to_field`]
+//! is also exposed on its own, to derive field-valued hashes, e.g.,
+//! challenges, with the same expanders.
+//!
+//! [`hash_to_curve_try_and_increment`] is the legacy try-and-increment map
+//! of earlier deployments, only kept to verify the data they produced.
+//!
+//! [`elligator_squared_encode`] and [`elligator_squared_decode`] go the
+//! other way, and represent points as uniformly random bytes, for
+//! protocols whose points must be indistinguishable from random on the
+//! wire.
+
+mod elligator2;
+pub(crate) mod elligator_squared;
+mod expander;
+mod sswu;
+mod try_and_increment;
+
+#[cfg(test)]
+mod tests;
+
+pub use elligator2::{map_to_curve_elligator2, ELL2_Z};
+pub use elligator_squared::{
+    elligator_squared_decode, elligator_squared_encode, ELLIGATOR_SQUARED_SIZE,
+};
+#[cfg(feature = "blake3")]
+pub use expander::ExpanderXofBlake3;
+pub use expander::{Expander, ExpanderXmdSha256, ExpanderXmdSha512};
+#[cfg(feature = "sha3")]
+pub use expander::{ExpanderXofShake128, ExpanderXofShake256};
+pub use sswu::{map_to_curve_sswu, map_to_isogenous_sswu, SSWU_Z};
+pub use try_and_increment::hash_to_curve_try_and_increment;
+
+use crate::{ClearCofactor, EdwardsAffine, Fq, Fr};
+use ark_ec::ProjectiveCurve;
+use ark_ff::PrimeField;
+use ark_std::{fmt, vec::Vec};
+
+/// The `CURVE_ID` component of the suite IDs.
+pub const CURVE_ID: &[u8] = b"bandersnatch";
+
+/// The `MAP_ID` component of the suite IDs of the Elligator 2 map.
+pub const ELL2_MAP_ID: &[u8] = b"ELL2";
+
+/// The `MAP_ID` component of the suite IDs of the simplified SWU map.
+pub const SSWU_MAP_ID: &[u8] = b"SSWU";
+
+/// Domain separation tag of the derivation of generators.
+const GENERATOR_DST: &[u8] = b"BANDERSNATCH-V01-GENERATOR";
+
+/// Errors that can occur while hashing to the curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashToCurveError {
+    /// The requested output exceeds what `expand_message` can produce.
+    OutputTooLong,
+}
+
+impl fmt::Display for HashToCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashToCurveError::OutputTooLong => {
+                write!(f, "requested too many bytes from expand_message")
+            }
+        }
+    }
+}
+
+/// The encoding type of a suite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// `hash_to_curve`, i.e., random oracle encoding.
+    Uniform,
+    /// `encode_to_curve`, i.e., nonuniform encoding.
+    NonUniform,
+}
+
+impl Encoding {
+    /// The `ENC_VAR` component of the suite ID.
+    pub fn id(&self) -> &'static [u8] {
+        match self {
+            Encoding::Uniform => b"RO",
+            Encoding::NonUniform => b"NU",
+        }
+    }
+}
+
+/// The suite ID `CURVE_ID || "_" || HASH_ID || "_" || MAP_ID || "_" ||
+/// ENC_VAR || "_"` for the expander `E`, as in RFC 9380, section 8.10.
+pub fn suite_id<E: Expander>(map_id: &[u8], encoding: Encoding) -> Vec<u8> {
+    let mut id = Vec::new();
+    id.extend_from_slice(CURVE_ID);
+    id.push(b'_');
+    id.extend_from_slice(E::HASH_ID);
+    id.push(b'_');
+    id.extend_from_slice(map_id);
+    id.push(b'_');
+    id.extend_from_slice(encoding.id());
+    id.push(b'_');
+    id
+}
+
+/// The domain separation tag `tag || "-with-" || suite_id`, following the
+/// recommendation of RFC 9380, section 3.1.
+pub fn suite_dst<E: Expander>(
+    tag: &[u8],
+    map_id: &[u8],
+    encoding: Encoding,
+) -> Vec<u8> {
+    let mut dst = tag.to_vec();
+    dst.extend_from_slice(b"-with-");
+    dst.extend_from_slice(&suite_id::<E>(map_id, encoding));
+    dst
+}
+
+/// Hash `msg` to a point of the prime order subgroup, uniformly, with the
+/// expander and its DST, as the `hash_to_curve` function of RFC 9380,
+/// section 3, with the Elligator 2 map.
+///
+/// The message is hashed to two elements of `Fq` of 48 bytes each, which
+/// are mapped to the curve with [`map_to_curve_elligator2`]. The cofactor
+/// of both points is cleared before they are added: this is equivalent to
+/// clearing the cofactor of their sum, and only uses the complete addition
+/// formulas of the prime order subgroup.
+pub fn hash_to_curve<E: Expander>(expander: &E, msg: &[u8]) -> EdwardsAffine {
+    hash_with_map(expander, msg, map_to_curve_elligator2)
+}
+
+/// Same as [`hash_to_curve`], with the simplified SWU map of
+/// [`map_to_curve_sswu`] instead of Elligator 2, e.g., for the suite
+/// `bandersnatch_XMD:SHA-256_SSWU_RO_`.
+pub fn hash_to_curve_sswu<E: Expander>(
+    expander: &E,
+    msg: &[u8],
+) -> EdwardsAffine {
+    hash_with_map(expander, msg, map_to_curve_sswu)
+}
+
+/// Encode `msg` to a point of the prime order subgroup, as the
+/// `encode_to_curve` function of RFC 9380, section 3, with the Elligator 2
+/// map, e.g., for the suite `bandersnatch_XMD:SHA-256_ELL2_NU_`.
+///
+/// The message is hashed to a single element of `Fq`, so that the map and
+/// the cofactor clearing are evaluated once. The output is not uniformly
+/// distributed, only about half of the points can be reached: use
+/// [`hash_to_curve`] whenever a random oracle is needed.
+pub fn encode_to_curve<E: Expander>(expander: &E, msg: &[u8]) -> EdwardsAffine {
+    encode_with_map(expander, msg, map_to_curve_elligator2)
+}
+
+/// Same as [`encode_to_curve`], with the simplified SWU map of
+/// [`map_to_curve_sswu`], e.g., for the suite
+/// `bandersnatch_XMD:SHA-256_SSWU_NU_`.
+pub fn encode_to_curve_sswu<E: Expander>(
+    expander: &E,
+    msg: &[u8],
+) -> EdwardsAffine {
+    encode_with_map(expander, msg, map_to_curve_sswu)
+}
+
+/// Hash `msg` to two field elements, map them to the curve with `map`,
+/// and clear the cofactor of their sum.
+fn hash_with_map<E: Expander>(
+    expander: &E,
+    msg: &[u8],
+    map: fn(&Fq) -> EdwardsAffine,
+) -> EdwardsAffine {
+    let u: Vec<Fq> = hash_to_field(expander, msg, 2)
+        .expect("the length is within the expander's output length");
+    let q0 = map(&u[0]).clear_cofactor();
+    let q1 = map(&u[1]).clear_cofactor();
+    (q0 + q1).into_affine()
+}
+
+/// Hash `msg` to a field element, map it to the curve with `map`, and
+/// clear the cofactor.
+fn encode_with_map<E: Expander>(
+    expander: &E,
+    msg: &[u8],
+    map: fn(&Fq) -> EdwardsAffine,
+) -> EdwardsAffine {
+    let u: Vec<Fq> = hash_to_field(expander, msg, 1)
+        .expect("the length is within the expander's output length");
+    map(&u[0]).clear_cofactor().into_affine()
+}
+
+/// Derive the `index`-th generator of `domain`, a point of the prime order
+/// subgroup whose discrete logarithm nobody knows, e.g., a blinding base
+/// of Pedersen commitments.
+///
+/// The generator is [`hash_to_curve`] of `len(domain) || domain || index`,
+/// where the length and `index` are big-endian `u64`s, with the suite
+/// `bandersnatch_XMD:SHA-256_ELL2_RO_` and the DST
+/// `BANDERSNATCH-V01-GENERATOR-with-bandersnatch_XMD:SHA-256_ELL2_RO_`.
+///
+/// The construction is fixed: the generators of a given domain and index
+/// never change.
+pub fn derive_generator(domain: &[u8], index: u64) -> EdwardsAffine {
+    let mut msg = Vec::with_capacity(8 + domain.len() + 8);
+    msg.extend_from_slice(&(domain.len() as u64).to_be_bytes());
+    msg.extend_from_slice(domain);
+    msg.extend_from_slice(&index.to_be_bytes());
+    let expander = ExpanderXmdSha256::with_suite(
+        GENERATOR_DST,
+        ELL2_MAP_ID,
+        Encoding::Uniform,
+    );
+    hash_to_curve(&expander, &msg)
+}
+
+/// Hash the concatenation of `parts` to a scalar with
+/// `expand_message_xmd`, reducing 48 uniform bytes so that the bias is
+/// negligible.
+pub(crate) fn hash_to_scalar(dst: &[u8], parts: &[&[u8]]) -> Fr {
+    hash_to_prime_field(dst, parts)
+}
+
+/// Hash the concatenation of `parts` to an element of any prime field with
+/// [`hash_to_field`] and the SHA-256 `expand_message_xmd` expander.
+pub(crate) fn hash_to_prime_field<F: PrimeField>(
+    dst: &[u8],
+    parts: &[&[u8]],
+) -> F {
+    let mut input = Vec::new();
+    for part in parts {
+        input.extend_from_slice(part);
+    }
+    hash_to_field(&ExpanderXmdSha256::new(dst), &input, 1)
+        .expect("the length is within the expander's output length")[0]
+}
+
+/// Hash `msg` to `count` elements of a prime field, e.g., `Fq` or `Fr`, as
+/// the `hash_to_field` function of RFC 9380, section 5.2, with the
+/// security parameter `k = 128`: each element reduces
+/// `ceil((log2(p) + 128) / 8)` uniform bytes, 48 bytes for both fields,
+/// so that the bias is negligible.
+///
+/// The DST is the one of the expander, e.g.,
+/// `hash_to_field::<Fr, _>(&ExpanderXmdSha256::new(dst), msg, 2)` derives
+/// two challenges. The only error is an output too long for the expander.
+pub fn hash_to_field<F: PrimeField, E: Expander>(
+    expander: &E,
+    msg: &[u8],
+    count: usize,
+) -> Result<Vec<F>, HashToCurveError> {
+    let len = (F::size_in_bits() + 128).div_ceil(8);
+    let total = count
+        .checked_mul(len)
+        .ok_or(HashToCurveError::OutputTooLong)?;
+    let bytes = expander.expand_message(msg, total)?;
+    Ok(bytes.chunks(len).map(F::from_be_bytes_mod_order).collect())
+}
@@ -0,0 +1,64 @@
+//! The simplified SWU map of RFC 9380, section 6.6.2, to the short
+//! Weierstrass curve 2-isogenous to bandersnatch, followed by the dual
+//! isogeny to bandersnatch.
+//!
+//! As for [`map_to_curve_elligator2`](super::map_to_curve_elligator2), the
+//! map follows the straight-line description of appendix F.2, with
+//! constant-time square root, Legendre symbol and inversion.
+
+use super::elligator2::inv0;
+use crate::{
+    conditional_assign, ct_eq, ct_legendre, ct_sqrt, dual_isogeny,
+    EdwardsAffine, Fq, IsogenousAffine, IsogenousParameters,
+};
+use ark_ec::SWModelParameters;
+use ark_ff::{
+    field_new, BigInteger, Field, LegendreSymbol, One, PrimeField, Zero,
+};
+
+/// The non-square `Z` of the map, as chosen by the procedure of RFC 9380,
+/// appendix H.2, for the isogenous curve.
+pub const SSWU_Z: Fq = field_new!(Fq, "-7");
+
+/// Map a field element to a point of the isogenous curve with the
+/// simplified SWU map.
+pub fn map_to_isogenous_sswu(u: &Fq) -> IsogenousAffine {
+    let a = IsogenousParameters::COEFF_A;
+    let b = IsogenousParameters::COEFF_B;
+    let g = |x: &Fq| *x * (x.square() + a) + b;
+
+    // x1 = (-B / A) (1 + 1 / (Z^2 u^4 + Z u^2)), or B / (Z A) if the
+    // denominator is 0
+    let z_u2 = SSWU_Z * u.square();
+    let tv1 = inv0(&(z_u2.square() + z_u2));
+    let minus_b_over_a = -b * a.inverse().unwrap();
+    let mut x1 = minus_b_over_a * (Fq::one() + tv1);
+    let e = ct_eq(&tv1, &Fq::zero());
+    conditional_assign(&mut x1, &(-minus_b_over_a / SSWU_Z), e);
+    let gx1 = g(&x1);
+    let x2 = z_u2 * x1;
+    let gx2 = g(&x2);
+
+    // x = x1 if gx1 is a square, x2 otherwise, and sgn0(y) = sgn0(u)
+    let e1 = (ct_legendre(&gx1) != LegendreSymbol::QuadraticNonResidue) as u64;
+    let mut x = x2;
+    conditional_assign(&mut x, &x1, e1);
+    let mut y2 = gx2;
+    conditional_assign(&mut y2, &gx1, e1);
+    let mut y = ct_sqrt(&y2).expect("either gx1 or gx2 is a square");
+    let e2 = (u.into_repr().is_odd() != y.into_repr().is_odd()) as u64;
+    let minus_y = -y;
+    conditional_assign(&mut y, &minus_y, e2);
+    IsogenousAffine::new(x, y, false)
+}
+
+/// Map a field element to a point of bandersnatch, not necessarily in the
+/// prime order subgroup, with [`map_to_isogenous_sswu`] and the dual
+/// isogeny.
+///
+/// The points of order 2 at infinity of the twisted Edwards model, which
+/// the cofactor clearing sends to the identity anyway, are replaced with
+/// the identity.
+pub fn map_to_curve_sswu(u: &Fq) -> EdwardsAffine {
+    dual_isogeny(&map_to_isogenous_sswu(u)).unwrap_or_else(EdwardsAffine::zero)
+}
@@ -0,0 +1,533 @@
+use super::*;
+use ark_ff::Zero;
+use ark_std::vec::Vec;
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// RFC 9380, appendix K.1
+#[test]
+fn test_expand_message_xmd_sha256() {
+    let expander =
+        ExpanderXmdSha256::new(b"QUUX-V01-CS02-with-expander-SHA256-128");
+    let vectors = [
+        (
+            "",
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+        ),
+        (
+            "abc",
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+        ),
+    ];
+    for (msg, uniform_bytes) in vectors.iter() {
+        assert_eq!(
+            expander.expand_message(msg.as_bytes(), 0x20).unwrap(),
+            from_hex(uniform_bytes)
+        );
+    }
+}
+
+// RFC 9380, appendix K.2
+#[test]
+fn test_expand_message_xmd_sha512() {
+    let expander =
+        ExpanderXmdSha512::new(b"QUUX-V01-CS02-with-expander-SHA512-256");
+    let vectors = [
+        (
+            "",
+            "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba",
+        ),
+        (
+            "abc",
+            "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc",
+        ),
+    ];
+    for (msg, uniform_bytes) in vectors.iter() {
+        assert_eq!(
+            expander.expand_message(msg.as_bytes(), 0x20).unwrap(),
+            from_hex(uniform_bytes)
+        );
+    }
+}
+
+// RFC 9380, appendix K.3
+#[cfg(feature = "sha3")]
+#[test]
+fn test_expand_message_xof_shake128() {
+    let expander =
+        ExpanderXofShake128::new(b"QUUX-V01-CS02-with-expander-SHAKE128");
+    let vectors = [
+        (
+            "",
+            "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2",
+        ),
+        (
+            "abc",
+            "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468",
+        ),
+    ];
+    for (msg, uniform_bytes) in vectors.iter() {
+        assert_eq!(
+            expander.expand_message(msg.as_bytes(), 0x20).unwrap(),
+            from_hex(uniform_bytes)
+        );
+    }
+}
+
+// RFC 9380, appendix K.4
+#[cfg(feature = "sha3")]
+#[test]
+fn test_expand_message_xof_shake256() {
+    let expander =
+        ExpanderXofShake256::new(b"QUUX-V01-CS02-with-expander-SHAKE256");
+    let vectors = [
+        (
+            "",
+            "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76",
+        ),
+        (
+            "abc",
+            "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07",
+        ),
+    ];
+    for (msg, uniform_bytes) in vectors.iter() {
+        assert_eq!(
+            expander.expand_message(msg.as_bytes(), 0x20).unwrap(),
+            from_hex(uniform_bytes)
+        );
+    }
+}
+
+#[test]
+fn test_expand_message_lengths() {
+    let expander = ExpanderXmdSha256::new(b"bandersnatch-test");
+    for len in [1, 31, 32, 33, 48, 128, 255 * 32] {
+        let bytes = expander.expand_message(b"msg", len).unwrap();
+        assert_eq!(bytes.len(), len);
+    }
+    // outputs of different lengths are not prefixes of each other
+    let short = expander.expand_message(b"msg", 32).unwrap();
+    let long = expander.expand_message(b"msg", 64).unwrap();
+    assert_ne!(short[..], long[..32]);
+
+    assert_eq!(
+        expander.expand_message(b"msg", 255 * 32 + 1),
+        Err(HashToCurveError::OutputTooLong)
+    );
+}
+
+#[test]
+fn test_oversize_dst() {
+    let long_tag = [b'a'; 256];
+    let expander = ExpanderXmdSha256::new(&long_tag);
+    assert_eq!(expander.dst().len(), 32);
+
+    let tag = [b'a'; 255];
+    let expander = ExpanderXmdSha256::new(&tag);
+    assert_eq!(expander.dst(), &tag[..]);
+}
+
+#[test]
+fn test_suite_dst() {
+    assert_eq!(
+        suite_id::<ExpanderXmdSha256>(b"ELL2", Encoding::Uniform),
+        b"bandersnatch_XMD:SHA-256_ELL2_RO_".to_vec()
+    );
+    let expander = ExpanderXmdSha256::with_suite(
+        b"MY-APP-V01",
+        b"ELL2",
+        Encoding::NonUniform,
+    );
+    assert_eq!(
+        expander.dst(),
+        &b"MY-APP-V01-with-bandersnatch_XMD:SHA-256_ELL2_NU_"[..]
+    );
+
+    // different suites yield different outputs for the same tag
+    let ro = ExpanderXmdSha256::with_suite(b"APP", b"ELL2", Encoding::Uniform);
+    let nu =
+        ExpanderXmdSha256::with_suite(b"APP", b"ELL2", Encoding::NonUniform);
+    assert_ne!(
+        ro.expand_message(b"msg", 32).unwrap(),
+        nu.expand_message(b"msg", 32).unwrap()
+    );
+}
+
+#[cfg(feature = "blake3")]
+#[test]
+fn test_expand_message_xof_blake3() {
+    let expander = ExpanderXofBlake3::with_suite(
+        b"bandersnatch-test",
+        b"ELL2",
+        Encoding::Uniform,
+    );
+    let short = expander.expand_message(b"msg", 32).unwrap();
+    let long = expander.expand_message(b"msg", 64).unwrap();
+    assert_eq!(short.len(), 32);
+    assert_eq!(long.len(), 64);
+    // the output length is bound into the XOF input
+    assert_ne!(short[..], long[..32]);
+
+    let expander = ExpanderXofBlake3::new(&[b'a'; 256]);
+    assert_eq!(expander.dst().len(), 32);
+}
+
+// Computed with an independent implementation of RFC 9380, section 5.2
+#[test]
+fn test_hash_to_field() {
+    use ark_ff::field_new;
+
+    let expander = ExpanderXmdSha256::new(
+        b"QUUX-V01-CS02-with-bandersnatch_XMD:SHA-256_ELL2_RO_",
+    );
+    let u: Vec<Fq> = hash_to_field(&expander, b"abc", 2).unwrap();
+    assert_eq!(
+        u,
+        [
+            field_new!(
+                Fq,
+                "13750464695475369940105197225259440549858576822756017054231842387404724636812"
+            ),
+            field_new!(
+                Fq,
+                "33997461584090940242421783404006099081034570872666184246392632025457113477941"
+            ),
+        ]
+    );
+    let s: Vec<Fr> = hash_to_field(&expander, b"abc", 2).unwrap();
+    assert_eq!(
+        s,
+        [
+            field_new!(
+                Fr,
+                "12206232384946112050458109087263725514835369677344625342970032320964740580965"
+            ),
+            field_new!(
+                Fr,
+                "10846753165733469175015196260229973866467413049399225234718818517667030522416"
+            ),
+        ]
+    );
+    // the output length is part of the expansion, so the count changes
+    // every element
+    let u1: Vec<Fq> = hash_to_field(&expander, b"abc", 1).unwrap();
+    assert_ne!(u1[0], u[0]);
+    assert!(hash_to_field::<Fq, _>(&expander, b"abc", 0)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        hash_to_field::<Fq, _>(&expander, b"abc", 256),
+        Err(HashToCurveError::OutputTooLong)
+    );
+    // count * 48 overflows
+    assert_eq!(
+        hash_to_field::<Fq, _>(&expander, b"abc", usize::MAX / 2),
+        Err(HashToCurveError::OutputTooLong)
+    );
+}
+
+#[test]
+fn test_derive_generator() {
+    let g0 = derive_generator(b"pedersen", 0);
+    let g1 = derive_generator(b"pedersen", 1);
+    for g in &[g0, g1] {
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(!g.is_zero());
+    }
+    assert_ne!(g0, g1);
+    assert_eq!(derive_generator(b"pedersen", 0), g0);
+    assert_ne!(derive_generator(b"pedersen2", 0), g0);
+    assert_ne!(derive_generator(b"", 0), derive_generator(b"", 1));
+
+    // the compressed encodings of the generators, generated by
+    // `python3 scripts/hash_to_curve_vectors.py`
+    let vectors: [(&[u8], u64, &str); 4] = [
+        (
+            b"",
+            0,
+            "71caf9765bf646a2b20162462f20e21f7aba76257f5724faec54bf41141b1d37",
+        ),
+        (
+            b"pedersen",
+            0,
+            "24a635001c9890f2a74081452a41c2c2fd2d4e64045902d457e26501adca39aa",
+        ),
+        (
+            b"pedersen",
+            1,
+            "5f3d3d3e9c8f9376539e86567f327361d3cfb852c0a923180593fbbe20de7f5a",
+        ),
+        (
+            b"bandersnatch",
+            42,
+            "204a6936282063c81dfefe1c90e3028f184da7e61a7231722faa0408fdb6a54f",
+        ),
+    ];
+    for (domain, index, encoding) in vectors.iter() {
+        assert_eq!(
+            crate::to_compressed_bytes(&derive_generator(domain, *index))[..],
+            from_hex(encoding)[..]
+        );
+    }
+}
+
+// Generated by `python3 scripts/hash_to_curve_vectors.py`, which implements
+// the suite from the generic description of RFC 9380 rather than the
+// straight-line one of the crate
+#[test]
+fn test_hash_to_curve() {
+    let expander = ExpanderXmdSha256::new(
+        b"QUUX-V01-CS02-with-bandersnatch_XMD:SHA-256_ELL2_RO_",
+    );
+    let q128 = [b"q128_".as_ref(), &[b'q'; 128]].concat();
+    let a512 = [b"a512_".as_ref(), &[b'a'; 512]].concat();
+    let vectors: [(&[u8], &str); 5] = [
+        (
+            b"",
+            "519ddc8a582e819196a06b6c0dce9bfa9d316016b4873b05d82f17a72696a560",
+        ),
+        (
+            b"abc",
+            "8565557cc3f8cb6c23853fdb4bb1aba4eed4cf4b0254463356b6df332bcc9f14",
+        ),
+        (
+            b"abcdef0123456789",
+            "07ea654970d3fdd00781c147f58d0fbc71172509e5b9de3f45bb14fc404768aa",
+        ),
+        (
+            &q128,
+            "78a724b92baae90e7a94aaa3bc032a5c2031fc6fb68c872dc7b4b6a05d52544a",
+        ),
+        (
+            &a512,
+            "ea519f92e5c5459e1d86660be45a2b19f3bf7a08743d4c9e966503a85bd32bcf",
+        ),
+    ];
+    for (msg, encoding) in vectors.iter() {
+        let p = hash_to_curve(&expander, msg);
+        assert!(p.is_on_curve());
+        assert!(crate::is_torsion_free(&p));
+        assert_eq!(crate::to_compressed_bytes(&p)[..], from_hex(encoding)[..]);
+    }
+}
+
+// Generated by `python3 scripts/hash_to_curve_vectors.py`
+#[test]
+fn test_encode_to_curve() {
+    let expander = ExpanderXmdSha256::new(
+        b"QUUX-V01-CS02-with-bandersnatch_XMD:SHA-256_ELL2_NU_",
+    );
+    let q128 = [b"q128_".as_ref(), &[b'q'; 128]].concat();
+    let a512 = [b"a512_".as_ref(), &[b'a'; 512]].concat();
+    let vectors: [(&[u8], &str); 5] = [
+        (
+            b"",
+            "ce1393e2065676d252f0c9c9061bfb21487790fef6715cfdf39c86f493f632c3",
+        ),
+        (
+            b"abc",
+            "451a28c87ef4adef2bfb656aef670bb2be8d1abfe02f31e0980f1bafc032a42e",
+        ),
+        (
+            b"abcdef0123456789",
+            "eabb6863a223d90fb0b56e17c753e076e3acc9a096ce5f5c20fa02a241eec83d",
+        ),
+        (
+            &q128,
+            "2e4361dfbfaf5695e891e96cfa09a8877a14b55803560d2155340eea58258a38",
+        ),
+        (
+            &a512,
+            "71d1b2b5513138f404c4b79a40a1bb1391495ef57781903c39c2e8f26b3c63ba",
+        ),
+    ];
+    for (msg, encoding) in vectors.iter() {
+        let p = encode_to_curve(&expander, msg);
+        assert!(p.is_on_curve());
+        assert!(crate::is_torsion_free(&p));
+        assert_eq!(crate::to_compressed_bytes(&p)[..], from_hex(encoding)[..]);
+    }
+}
+
+#[test]
+fn test_map_to_curve_elligator2() {
+    use ark_std::{test_rng, UniformRand};
+
+    let mut rng = test_rng();
+    for _ in 0..32 {
+        let u = Fq::rand(&mut rng);
+        let p = map_to_curve_elligator2(&u);
+        assert!(p.is_on_curve());
+        assert_eq!(map_to_curve_elligator2(&-u), p);
+    }
+    // u = 0 maps to (0, 0) in the Montgomery model, hence to the identity
+    assert!(map_to_curve_elligator2(&Fq::zero()).is_zero());
+}
+
+// Generated by `python3 scripts/hash_to_curve_vectors.py`, which computes
+// the isogenous curve and its dual isogeny from the curve parameters
+#[test]
+fn test_hash_to_curve_sswu() {
+    let expander = ExpanderXmdSha256::new(
+        b"QUUX-V01-CS02-with-bandersnatch_XMD:SHA-256_SSWU_RO_",
+    );
+    let q128 = [b"q128_".as_ref(), &[b'q'; 128]].concat();
+    let a512 = [b"a512_".as_ref(), &[b'a'; 512]].concat();
+    let vectors: [(&[u8], &str); 5] = [
+        (
+            b"",
+            "7d0ad2b49808d323d289cc70373a783919e89ca7a369e9b8d9b2d362eeb0e3b8",
+        ),
+        (
+            b"abc",
+            "88a853dbfa9a4a890384943817b3f657774d213c3372d2efd909d6da7dcbe71e",
+        ),
+        (
+            b"abcdef0123456789",
+            "2fee8d2f4fb44ce6cf983952eb7a58049ff4aef31917e4ef49ab981f834d1a63",
+        ),
+        (
+            &q128,
+            "ba0917d523cce25473ed63937cad79e2237d1bbdcbbd10ed2b6a15ef8cb73cd2",
+        ),
+        (
+            &a512,
+            "e6a228141ecd6a72371e6e7945dd56a4267a13731126347ce6e7bf9c3ba0d503",
+        ),
+    ];
+    for (msg, encoding) in vectors.iter() {
+        let p = hash_to_curve_sswu(&expander, msg);
+        assert!(p.is_on_curve());
+        assert!(crate::is_torsion_free(&p));
+        assert_eq!(crate::to_compressed_bytes(&p)[..], from_hex(encoding)[..]);
+    }
+}
+
+// Generated by `python3 scripts/hash_to_curve_vectors.py`
+#[test]
+fn test_encode_to_curve_sswu() {
+    let expander = ExpanderXmdSha256::new(
+        b"QUUX-V01-CS02-with-bandersnatch_XMD:SHA-256_SSWU_NU_",
+    );
+    let q128 = [b"q128_".as_ref(), &[b'q'; 128]].concat();
+    let a512 = [b"a512_".as_ref(), &[b'a'; 512]].concat();
+    let vectors: [(&[u8], &str); 5] = [
+        (
+            b"",
+            "ada8a508f271e13b763cd4fc11cce8992ee727c0a38181f514fd3033e3b7f42b",
+        ),
+        (
+            b"abc",
+            "f87627dbcb28ee90b60390fd7647cd197ea440b32662947d7f7dc80cefca18cb",
+        ),
+        (
+            b"abcdef0123456789",
+            "67beaad67a9e487e49b211a08dd05b04d20173542d076e1738cb70688bc90a33",
+        ),
+        (
+            &q128,
+            "04f170fc7516679122045312476da34c2a24a0d16b9d4a975713a14c5dab2d82",
+        ),
+        (
+            &a512,
+            "98b18a18941dc444ecfb0057463498d7add73421d7f6027ed9d30ecb77cf450c",
+        ),
+    ];
+    for (msg, encoding) in vectors.iter() {
+        let p = encode_to_curve_sswu(&expander, msg);
+        assert!(p.is_on_curve());
+        assert!(crate::is_torsion_free(&p));
+        assert_eq!(crate::to_compressed_bytes(&p)[..], from_hex(encoding)[..]);
+    }
+}
+
+#[test]
+fn test_map_to_curve_sswu() {
+    use ark_ff::{BigInteger, PrimeField};
+    use ark_std::{test_rng, UniformRand};
+
+    let mut rng = test_rng();
+    for _ in 0..32 {
+        let u = Fq::rand(&mut rng);
+        let q = map_to_isogenous_sswu(&u);
+        assert!(q.is_on_curve());
+        assert_eq!(q.y.into_repr().is_odd(), u.into_repr().is_odd());
+        assert!(map_to_curve_sswu(&u).is_on_curve());
+    }
+    // the exceptional case u = 0
+    assert!(map_to_isogenous_sswu(&Fq::zero()).is_on_curve());
+}
+
+// Generated by `python3 scripts/hash_to_curve_vectors.py`
+#[test]
+fn test_hash_to_curve_try_and_increment() {
+    use sha2::{Sha256, Sha512};
+
+    let dst = b"BANDERSNATCH-LEGACY-TEST";
+    let vectors: [(&[u8], &str, &str); 3] = [
+        (
+            b"",
+            "863fd73bf76287959a3670cb1bad9ea62a78228a37fc27e35eff6781f6b0302f",
+            "8d8728375153729733f8fec5117be617364fc7a525f2e09b68bc84c993310b6d",
+        ),
+        (
+            b"abc",
+            "601966bc72dfb058490eae99ff98dc34b752418ecaaca8aa121ecb1bb1d6c11c",
+            "f5364e24b5365863841191094ad9a344058ea372413ecb324f11a60005de6507",
+        ),
+        (
+            b"abcdef0123456789",
+            "b4576144733bfb5ba28a6fe24a6c9dca0ef81c1cd8edd695e94d0b9b470d6a5d",
+            "2dbd83b421affa804cc1c4057dab55fe33fd3b41b918111e5ebd0ac2e4519583",
+        ),
+    ];
+    for (msg, sha256, sha512) in vectors.iter() {
+        let p = hash_to_curve_try_and_increment::<Sha256>(dst, msg).unwrap();
+        assert!(crate::is_torsion_free(&p));
+        assert_eq!(crate::to_compressed_bytes(&p)[..], from_hex(sha256)[..]);
+        let p = hash_to_curve_try_and_increment::<Sha512>(dst, msg).unwrap();
+        assert!(crate::is_torsion_free(&p));
+        assert_eq!(crate::to_compressed_bytes(&p)[..], from_hex(sha512)[..]);
+    }
+}
+
+#[test]
+fn test_elligator_squared() {
+    use ark_std::{rand::Rng, test_rng, UniformRand};
+
+    let mut rng = test_rng();
+    let torsion = crate::torsion_points();
+    for i in 0..8 {
+        // points with every torsion component but the ones at infinity of
+        // the twisted Edwards model, and the identity
+        let p: EdwardsAffine = if i == 0 {
+            EdwardsAffine::zero()
+        } else {
+            rng.gen()
+        };
+        let p = crate::from_montgomery(
+            &(crate::to_montgomery(&p) + torsion[i % 4]),
+        )
+        .unwrap_or(p);
+        let bytes = elligator_squared_encode(&p, &mut rng);
+        assert_eq!(elligator_squared_decode(&bytes), Some(p));
+        assert_ne!(elligator_squared_encode(&p, &mut rng)[..], bytes[..]);
+    }
+    // the preimages found by the encoder are exact
+    for _ in 0..8 {
+        let u = Fq::rand(&mut rng);
+        let p = map_to_curve_elligator2(&u);
+        let preimages = elligator2::elligator2_preimages(&p);
+        assert!(preimages.contains(&u) && preimages.contains(&-u));
+        assert!(preimages.len() <= 4);
+        for v in preimages.iter() {
+            assert_eq!(map_to_curve_elligator2(v), p);
+        }
+    }
+}
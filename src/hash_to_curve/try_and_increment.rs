@@ -0,0 +1,55 @@
+//! The try-and-increment map of earlier deployments, which hash directly
+//! to the `y` coordinate of the compressed encoding, for compatibility
+//! with the data they produced. New protocols should use
+//! [`hash_to_curve`](super::hash_to_curve) instead: this map is not
+//! constant time, and its output is only as uniform as the hash.
+
+use crate::{
+    from_compressed_bytes, ClearCofactor, EdwardsAffine, COMPRESSED_SIZE,
+};
+use ark_ec::ProjectiveCurve;
+use ark_ff::Zero;
+use sha2::Digest;
+
+/// Hash `msg` to a point of the prime order subgroup by try-and-increment
+/// on the `y` coordinate, with the hash `D` and the domain separation tag
+/// `dst`.
+///
+/// For `counter = 0, 1, ..., 255`, the candidate is the first 32 bytes of
+/// `D(dst || msg || counter)`, with `counter` a single byte, decoded with
+/// [`from_compressed_bytes`], i.e., `y` in little-endian with the parity
+/// of `x` in the most significant bit. The first candidate that decodes,
+/// multiplied by the cofactor, is returned unless it is the identity, in
+/// which case the next counter is tried.
+///
+/// Returns `None` if no counter succeeds, which happens with probability
+/// about `2^-220`.
+///
+/// # Panics
+///
+/// If the output of `D` is shorter than 32 bytes.
+pub fn hash_to_curve_try_and_increment<D: Digest>(
+    dst: &[u8],
+    msg: &[u8],
+) -> Option<EdwardsAffine> {
+    assert!(
+        <D as Digest>::output_size() >= COMPRESSED_SIZE,
+        "the hash output is shorter than a compressed point"
+    );
+    for counter in 0..=u8::MAX {
+        let digest = D::new()
+            .chain_update(dst)
+            .chain_update(msg)
+            .chain_update([counter])
+            .finalize();
+        let mut candidate = [0u8; COMPRESSED_SIZE];
+        candidate.copy_from_slice(&digest[..COMPRESSED_SIZE]);
+        if let Some(point) = from_compressed_bytes(&candidate) {
+            let point = point.clear_cofactor().into_affine();
+            if !point.is_zero() {
+                return Some(point);
+            }
+        }
+    }
+    None
+}
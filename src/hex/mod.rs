@@ -0,0 +1,166 @@
+//! Hexadecimal formatting and parsing of points and field elements, e.g.,
+//! for logs, command line tools and test vectors, rather than the `Debug`
+//! output of Montgomery limbs.
+//!
+//! Points are written as their 32-byte compressed encoding, see
+//! [`to_compressed_bytes`](crate::to_compressed_bytes), and field elements
+//! as their canonical integers in big-endian, i.e., as `0x` numbers, see
+//! [`FieldBytes`](crate::FieldBytes). The arkworks types already implement
+//! `Display`, and cannot implement `FromStr` outside of arkworks, so their
+//! hexadecimal forms go through [`HexEncoding`]: `format!("{}", x.hex())`
+//! and `Fr::from_hex(s)`. Banderwagon elements also implement `Display`,
+//! `LowerHex` and `FromStr` directly.
+
+use crate::{
+    from_compressed_bytes, to_compressed_bytes, EdwardsAffine,
+    EdwardsProjective, FieldBytes, Fq, Fr,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_std::{fmt, str};
+
+#[cfg(test)]
+mod tests;
+
+/// The size of the encodings written in hexadecimal, in bytes.
+pub const HEX_ENCODING_SIZE: usize = 32;
+
+/// Errors that can occur while parsing hexadecimal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexError {
+    /// The string does not have 64 digits, after the optional `0x`.
+    InvalidLength,
+    /// A character is not a hexadecimal digit.
+    InvalidDigit,
+    /// The bytes are not a valid encoding, e.g., a point off the curve or
+    /// an integer not smaller than the modulus.
+    InvalidEncoding,
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HexError::InvalidLength => write!(
+                f,
+                "expected {} hexadecimal digits",
+                2 * HEX_ENCODING_SIZE
+            ),
+            HexError::InvalidDigit => write!(f, "invalid hexadecimal digit"),
+            HexError::InvalidEncoding => write!(f, "invalid encoding"),
+        }
+    }
+}
+
+/// Hexadecimal forms of points and field elements.
+pub trait HexEncoding: Sized {
+    /// The encoding written in hexadecimal.
+    fn hex_bytes(&self) -> [u8; HEX_ENCODING_SIZE];
+
+    /// Decode the encoding written in hexadecimal, or `None` if it is
+    /// invalid.
+    fn from_hex_bytes(bytes: &[u8; HEX_ENCODING_SIZE]) -> Option<Self>;
+
+    /// A value which formats as `0x` followed by the lowercase digits of
+    /// the encoding with `Display`, and as the digits alone with
+    /// `LowerHex`, unless the `#` flag is set.
+    fn hex(&self) -> Hex {
+        Hex(self.hex_bytes())
+    }
+
+    /// Parse the digits of an encoding, in either case, with an optional
+    /// `0x` prefix.
+    fn from_hex(s: &str) -> Result<Self, HexError> {
+        Self::from_hex_bytes(&decode_hex(s)?).ok_or(HexError::InvalidEncoding)
+    }
+}
+
+impl HexEncoding for EdwardsAffine {
+    fn hex_bytes(&self) -> [u8; HEX_ENCODING_SIZE] {
+        to_compressed_bytes(self)
+    }
+
+    fn from_hex_bytes(bytes: &[u8; HEX_ENCODING_SIZE]) -> Option<Self> {
+        from_compressed_bytes(bytes)
+    }
+}
+
+impl HexEncoding for EdwardsProjective {
+    fn hex_bytes(&self) -> [u8; HEX_ENCODING_SIZE] {
+        to_compressed_bytes(&self.into_affine())
+    }
+
+    fn from_hex_bytes(bytes: &[u8; HEX_ENCODING_SIZE]) -> Option<Self> {
+        from_compressed_bytes(bytes).map(|p| p.into_projective())
+    }
+}
+
+impl HexEncoding for Fq {
+    fn hex_bytes(&self) -> [u8; HEX_ENCODING_SIZE] {
+        self.to_bytes_be()
+    }
+
+    fn from_hex_bytes(bytes: &[u8; HEX_ENCODING_SIZE]) -> Option<Self> {
+        Self::from_bytes_be(bytes)
+    }
+}
+
+impl HexEncoding for Fr {
+    fn hex_bytes(&self) -> [u8; HEX_ENCODING_SIZE] {
+        self.to_bytes_be()
+    }
+
+    fn from_hex_bytes(bytes: &[u8; HEX_ENCODING_SIZE]) -> Option<Self> {
+        Self::from_bytes_be(bytes)
+    }
+}
+
+/// The hexadecimal form of an encoding, see [`HexEncoding::hex`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hex([u8; HEX_ENCODING_SIZE]);
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self)
+    }
+}
+
+impl fmt::LowerHex for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        let mut digits = [0u8; 2 * HEX_ENCODING_SIZE];
+        for (pair, byte) in digits.chunks_mut(2).zip(self.0.iter()) {
+            pair[0] = HEX_DIGITS[(byte >> 4) as usize];
+            pair[1] = HEX_DIGITS[(byte & 0xf) as usize];
+        }
+        f.write_str(str::from_utf8(&digits).unwrap())
+    }
+}
+
+pub(crate) const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+pub(crate) fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Read 32 bytes written as 64 digits, with an optional `0x` prefix.
+fn decode_hex(s: &str) -> Result<[u8; HEX_ENCODING_SIZE], HexError> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    let digits = s.as_bytes();
+    if digits.len() != 2 * HEX_ENCODING_SIZE {
+        return Err(HexError::InvalidLength);
+    }
+    let mut bytes = [0u8; HEX_ENCODING_SIZE];
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+        *byte = match (hex_value(pair[0]), hex_value(pair[1])) {
+            (Some(high), Some(low)) => high << 4 | low,
+            _ => return Err(HexError::InvalidDigit),
+        };
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,74 @@
+use super::*;
+use ark_ff::{One, Zero};
+use ark_std::{string::ToString, test_rng, UniformRand};
+
+#[test]
+fn test_hex_fields() {
+    assert_eq!(
+        Fr::one().hex().to_string(),
+        "0x0000000000000000000000000000000000000000000000000000000000000001"
+    );
+    assert_eq!(
+        format!("{:x}", (-Fq::one()).hex()),
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000"
+    );
+    assert_eq!(
+        format!("{:#x}", Fr::one().hex()),
+        Fr::one().hex().to_string()
+    );
+
+    let mut rng = test_rng();
+    for _ in 0..8 {
+        let x = Fq::rand(&mut rng);
+        let s = Fr::rand(&mut rng);
+        assert_eq!(Fq::from_hex(&x.hex().to_string()), Ok(x));
+        assert_eq!(Fr::from_hex(&format!("{:x}", s.hex())), Ok(s));
+        assert_eq!(
+            Fr::from_hex(&s.hex().to_string().to_uppercase().replace('X', "x")),
+            Ok(s)
+        );
+    }
+}
+
+#[test]
+fn test_hex_points() {
+    assert_eq!(
+        EdwardsAffine::zero().hex().to_string(),
+        "0x0100000000000000000000000000000000000000000000000000000000000000"
+    );
+    let mut rng = test_rng();
+    for _ in 0..8 {
+        let p = EdwardsProjective::rand(&mut rng);
+        let affine = p.into_affine();
+        let hex = affine.hex().to_string();
+        assert_eq!(p.hex().to_string(), hex);
+        assert_eq!(EdwardsAffine::from_hex(&hex), Ok(affine));
+        assert_eq!(EdwardsProjective::from_hex(&hex), Ok(p));
+    }
+}
+
+#[test]
+fn test_hex_errors() {
+    assert_eq!(Fr::from_hex("0x01"), Err(HexError::InvalidLength));
+    assert_eq!(Fr::from_hex(""), Err(HexError::InvalidLength));
+    assert_eq!(
+        Fr::from_hex(
+            "0x000000000000000000000000000000000000000000000000000000000000000g"
+        ),
+        Err(HexError::InvalidDigit)
+    );
+    // the modulus of Fq is not canonical
+    assert_eq!(
+        Fq::from_hex(
+            "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+        ),
+        Err(HexError::InvalidEncoding)
+    );
+    // q - 1 is canonical in Fq, not in Fr
+    let hex = (-Fq::one()).hex().to_string();
+    assert_eq!(Fr::from_hex(&hex), Err(HexError::InvalidEncoding));
+    assert_eq!(
+        HexError::InvalidLength.to_string(),
+        "expected 64 hexadecimal digits"
+    );
+}
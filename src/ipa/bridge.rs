@@ -0,0 +1,84 @@
+use super::Transcript;
+use crate::{batch_inverse, EdwardsAffine, Fq};
+use ark_ff::{Field, Zero};
+use ark_std::vec::Vec;
+
+/// A KZG committer over BLS12-381, supplied by the caller so that this
+/// crate does not depend on a pairing library.
+///
+/// The base field of bandersnatch is the scalar field of BLS12-381, so the
+/// values committed to are elements of [`Fq`].
+pub trait KzgCommitter {
+    /// A commitment, typically a point of BLS12-381 G1.
+    type Commitment;
+
+    /// Commit to `values`.
+    fn commit(&self, values: &[Fq]) -> Self::Commitment;
+
+    /// The canonical encoding of a commitment, e.g., the 48-byte compressed
+    /// G1 point, which is appended to the transcript.
+    fn commitment_bytes(&self, commitment: &Self::Commitment) -> Vec<u8>;
+}
+
+/// Map a bandersnatch commitment to the base field as `x / y`.
+///
+/// Since `-5` is not a square, no point of the curve has `y = 0`.
+///
+/// # Panics
+///
+/// Panics if `y = 0`, i.e., if the commitment is not on the curve:
+/// commitments from untrusted sources must be validated first, see
+/// [`Validate`](crate::Validate).
+pub fn commitment_to_field(commitment: &EdwardsAffine) -> Fq {
+    let y_inv = commitment
+        .y
+        .inverse()
+        .expect("the commitment must be a point of the curve");
+    commitment.x * y_inv
+}
+
+/// Map commitments to the base field as [`commitment_to_field`] does,
+/// sharing a single field inversion among all of them.
+///
+/// # Panics
+///
+/// Panics if a commitment has `y = 0`, as [`commitment_to_field`].
+pub fn commitments_to_field(commitments: &[EdwardsAffine]) -> Vec<Fq> {
+    assert!(
+        commitments.iter().all(|c| !c.y.is_zero()),
+        "the commitments must be points of the curve"
+    );
+    let mut y_inv: Vec<Fq> = commitments.iter().map(|c| c.y).collect();
+    batch_inverse(&mut y_inv);
+    commitments
+        .iter()
+        .zip(y_inv)
+        .map(|(c, y_inv)| c.x * y_inv)
+        .collect()
+}
+
+/// Commit to bandersnatch commitments with a KZG committer: the
+/// commitments are mapped to [`Fq`] with [`commitments_to_field`], and
+/// committed to with `committer`.
+///
+/// The bandersnatch commitments and the resulting KZG commitment are
+/// appended to `transcript`, so that the challenges derived afterwards,
+/// e.g., for opening either layer, bind both layers.
+pub fn commit_to_commitments<K: KzgCommitter>(
+    committer: &K,
+    transcript: &mut Transcript,
+    commitments: &[EdwardsAffine],
+) -> K::Commitment {
+    transcript.domain_sep(b"kzg-bridge");
+    transcript.append_bytes(
+        b"num-commitments",
+        &(commitments.len() as u64).to_le_bytes(),
+    );
+    for commitment in commitments {
+        transcript.append_point(b"C", commitment);
+    }
+    let kzg = committer.commit(&commitments_to_field(commitments));
+    transcript
+        .append_bytes(b"kzg-commitment", &committer.commitment_bytes(&kzg));
+    kzg
+}
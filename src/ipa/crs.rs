@@ -0,0 +1,70 @@
+use crate::{ClearCofactor, EdwardsAffine, Fq};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{PrimeField, Zero};
+use ark_std::vec::Vec;
+use sha2::{Digest, Sha256};
+
+/// The seed of the default common reference string.
+pub const DEFAULT_CRS_SEED: &[u8] = b"bandersnatch_ipa_crs_v01";
+
+/// The common reference string of the inner product argument: the
+/// generators `G_0, ..., G_{n-1}` of the vector commitments, the
+/// auxiliary generator `Q` that binds the inner product, and the generator
+/// `H` of the blinding factors of hiding commitments.
+///
+/// All the generators are derived by hashing a seed, so that nobody knows
+/// a discrete logarithm relation between them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CRS {
+    /// The generators of the vector commitments.
+    pub gs: Vec<EdwardsAffine>,
+    /// The auxiliary generator.
+    pub q: EdwardsAffine,
+    /// The blinding generator.
+    pub h: EdwardsAffine,
+}
+
+impl CRS {
+    /// Derive a CRS with `n` generators from `seed`.
+    pub fn new(n: usize, seed: &[u8]) -> Self {
+        let mut points = generate_points(n + 2, seed);
+        let h = points.pop().unwrap();
+        let q = points.pop().unwrap();
+        Self { gs: points, q, h }
+    }
+
+    /// The number of generators of the vector commitments.
+    pub fn len(&self) -> usize {
+        self.gs.len()
+    }
+
+    /// Whether the CRS has no generator.
+    pub fn is_empty(&self) -> bool {
+        self.gs.is_empty()
+    }
+}
+
+/// Derive `n` points of the prime order subgroup from `seed`, by
+/// hashing `seed || i` to an x-coordinate for `i = 0, 1, ...` and keeping
+/// the candidates that lie on the curve, with the cofactor cleared.
+pub(crate) fn generate_points(n: usize, seed: &[u8]) -> Vec<EdwardsAffine> {
+    let mut points: Vec<EdwardsAffine> = Vec::with_capacity(n);
+    let mut counter = 0u64;
+    while points.len() < n {
+        let hash = Sha256::new()
+            .chain_update(seed)
+            .chain_update(counter.to_be_bytes())
+            .finalize();
+        counter += 1;
+
+        let x = Fq::from_be_bytes_mod_order(&hash);
+        let point = match EdwardsAffine::get_point_from_x(x, true) {
+            Some(point) => point.clear_cofactor().into_affine(),
+            None => continue,
+        };
+        if !point.is_zero() {
+            points.push(point);
+        }
+    }
+    points
+}
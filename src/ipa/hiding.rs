@@ -0,0 +1,120 @@
+use super::{commit, inner_product, IPAProof, ProofError, Transcript, CRS};
+use crate::{EdwardsAffine, EdwardsProjective, Fr};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::UniformRand;
+use ark_std::{rand::Rng, vec::Vec};
+
+/// Commit to `values` with the blinding factor `blinding`:
+/// `C = sum_i v_i G_i + blinding H`.
+///
+/// With a uniformly random blinding factor, the commitment reveals nothing
+/// about the values.
+///
+/// # Panics
+///
+/// Panics if the CRS has fewer generators than there are values.
+pub fn commit_hiding(
+    crs: &CRS,
+    values: &[Fr],
+    blinding: &Fr,
+) -> EdwardsProjective {
+    commit(crs, values) + crs.h.mul(*blinding)
+}
+
+/// An opening of a hiding commitment to an inner product, which reveals
+/// neither the committed vector nor the blinding factor.
+///
+/// The prover commits to a random mask `D = sum_i d_i G_i + r_d H` with
+/// `delta = <d, b>`, and, given a challenge `xi`, reveals the blinding
+/// factor `r + xi r_d` of `C + xi D`. The remaining (non-hiding) opening
+/// of `C + xi D` to `<a + xi d, b> = y + xi delta` is an [`IPAProof`] over
+/// the masked vector `a + xi d`, which is uniformly random.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HidingIPAProof {
+    /// The commitment to the mask.
+    pub d: EdwardsAffine,
+    /// The inner product of the mask with the public vector.
+    pub delta: Fr,
+    /// The blinding factor of the masked commitment.
+    pub blinding: Fr,
+    /// The opening of the masked commitment.
+    pub proof: IPAProof,
+}
+
+impl HidingIPAProof {
+    /// Prove that `commitment = commit_hiding(crs, a, blinding)` opens to
+    /// `<a, b>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths, if the length is not
+    /// a power of two, or if it exceeds the size of the CRS.
+    pub fn create<R: Rng + ?Sized>(
+        transcript: &mut Transcript,
+        crs: &CRS,
+        commitment: &EdwardsAffine,
+        a: &[Fr],
+        blinding: &Fr,
+        b: &[Fr],
+        rng: &mut R,
+    ) -> Self {
+        let mask: Vec<Fr> = (0..a.len()).map(|_| Fr::rand(rng)).collect();
+        let mask_blinding = Fr::rand(rng);
+        let d = commit_hiding(crs, &mask, &mask_blinding).into_affine();
+        let delta = inner_product(&mask, b);
+
+        let xi = bind_mask(transcript, commitment, &d, &delta);
+        let masked: Vec<Fr> =
+            a.iter().zip(&mask).map(|(a, d)| *a + xi * d).collect();
+        let blinding = *blinding + xi * mask_blinding;
+        let masked_commitment = (commitment.into_projective() + d.mul(xi)
+            - crs.h.mul(blinding))
+        .into_affine();
+
+        let proof =
+            IPAProof::create(transcript, crs, &masked_commitment, &masked, b);
+        Self {
+            d,
+            delta,
+            blinding,
+            proof,
+        }
+    }
+
+    /// Verify that the hiding `commitment` opens to `y = <a, b>`, where
+    /// `a` is the committed vector.
+    pub fn verify(
+        &self,
+        transcript: &mut Transcript,
+        crs: &CRS,
+        commitment: &EdwardsAffine,
+        b: &[Fr],
+        y: &Fr,
+    ) -> Result<(), ProofError> {
+        let xi = bind_mask(transcript, commitment, &self.d, &self.delta);
+        let masked_commitment = (commitment.into_projective() + self.d.mul(xi)
+            - crs.h.mul(self.blinding))
+        .into_affine();
+        self.proof.verify(
+            transcript,
+            crs,
+            &masked_commitment,
+            b,
+            &(*y + xi * self.delta),
+        )
+    }
+}
+
+/// Append the mask to the transcript and derive the challenge `xi`.
+fn bind_mask(
+    transcript: &mut Transcript,
+    commitment: &EdwardsAffine,
+    d: &EdwardsAffine,
+    delta: &Fr,
+) -> Fr {
+    transcript.domain_sep(b"hiding-ipa");
+    transcript.append_point(b"C", commitment);
+    transcript.append_point(b"D", d);
+    transcript.append_scalar(b"delta", delta);
+    transcript.challenge_scalar(b"xi")
+}
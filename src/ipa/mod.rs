@@ -0,0 +1,264 @@
+//! This module implements Pedersen vector commitments over bandersnatch,
+//! together with an inner product argument (IPA) in the style of
+//! Bulletproofs.
+//!
+//! Given a commitment `C = sum_i a_i G_i` and a public vector `b`, the
+//! prover convinces the verifier that `<a, b> = y` with `log2(n)` rounds,
+//! each contributing a pair of points `(L, R)` to the proof. The protocol
+//! is made non-interactive with a [`Transcript`].
+//!
+//! Hiding commitments `C = sum_i a_i G_i + r H` are opened with a
+//! [`HidingIPAProof`], which keeps both the vector and `r` secret.
+//!
+//! Commitments can in turn be committed to with a caller-supplied KZG
+//! committer over BLS12-381, see [`commit_to_commitments`].
+//!
+//! Polynomials in evaluation form over the canonical domain
+//! `{0, ..., 255}` are evaluated outside of it and divided by `x - m` with
+//! [`PrecomputedWeights`], as in go-ipa; [`divide_on_domain`] is the
+//! quotient of proofs of evaluation at a point of the domain.
+//!
+//! The [`mutation`] module produces malformed proofs for negative testing.
+
+mod bridge;
+mod crs;
+mod hiding;
+pub mod mutation;
+mod serialization;
+mod slot;
+mod transcript;
+mod weights;
+
+#[cfg(test)]
+mod tests;
+
+pub use bridge::{
+    commit_to_commitments, commitment_to_field, commitments_to_field,
+    KzgCommitter,
+};
+pub(crate) use crs::generate_points;
+pub use crs::{CRS, DEFAULT_CRS_SEED};
+pub use hiding::{commit_hiding, HidingIPAProof};
+pub use serialization::{scalars_from_bytes, scalars_to_bytes, VerifierConfig};
+pub use slot::{commit_sparse, update_commitment, SlotIndex};
+pub use transcript::{sample_eval_point, Transcript, DOMAIN_SIZE};
+pub use weights::{divide_on_domain, precomputed_weights, PrecomputedWeights};
+
+use crate::{
+    batch_inverse, batch_normalize, msm, EdwardsAffine, EdwardsProjective, Fr,
+    SumOfProducts,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, One, Zero};
+use ark_std::{fmt, vec::Vec};
+
+/// Errors that can occur while parsing or verifying an inner product
+/// argument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// The proof does not verify.
+    InvalidProof,
+    /// The number of rounds of the proof does not match the length of the
+    /// committed vector, or the vector length is not a power of two that
+    /// the CRS supports.
+    InvalidLength,
+    /// The encoding is malformed, non-canonical, or contains a point
+    /// outside of the prime order subgroup.
+    InvalidEncoding,
+    /// A length prefix exceeds the limit set in the [`VerifierConfig`].
+    LimitExceeded,
+}
+
+impl fmt::Display for ProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofError::InvalidProof => write!(f, "invalid proof"),
+            ProofError::InvalidLength => write!(f, "invalid proof length"),
+            ProofError::InvalidEncoding => write!(f, "invalid encoding"),
+            ProofError::LimitExceeded => {
+                write!(f, "length exceeds the configured limit")
+            }
+        }
+    }
+}
+
+/// An inner product argument: one pair `(L, R)` per round and the final
+/// folded scalar `a`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IPAProof {
+    /// The left cross terms.
+    pub l: Vec<EdwardsAffine>,
+    /// The right cross terms.
+    pub r: Vec<EdwardsAffine>,
+    /// The folded vector.
+    pub a: Fr,
+}
+
+/// Commit to `values` with the first `values.len()` generators of `crs`.
+///
+/// # Panics
+///
+/// Panics if the CRS has fewer generators than there are values.
+pub fn commit(crs: &CRS, values: &[Fr]) -> EdwardsProjective {
+    assert!(values.len() <= crs.len(), "too many values for the CRS");
+    msm(&crs.gs[..values.len()], values)
+}
+
+/// The inner product `<a, b>`, with a single modular reduction, see
+/// [`SumOfProducts`].
+pub fn inner_product(a: &[Fr], b: &[Fr]) -> Fr {
+    Fr::sum_of_products(a, b)
+}
+
+impl IPAProof {
+    /// Prove that `commitment = commit(crs, a)` opens to `<a, b>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths, if the length is not
+    /// a power of two, or if it exceeds the size of the CRS.
+    pub fn create(
+        transcript: &mut Transcript,
+        crs: &CRS,
+        commitment: &EdwardsAffine,
+        a: &[Fr],
+        b: &[Fr],
+    ) -> Self {
+        let n = a.len();
+        assert_eq!(n, b.len(), "the two vectors must have the same length");
+        assert!(n.is_power_of_two(), "the length must be a power of two");
+        assert!(n <= crs.len(), "the vectors are too long for the CRS");
+
+        let y = inner_product(a, b);
+        let w = bind_statement(transcript, commitment, b, &y);
+        let q = crs.q.mul(w).into_affine();
+
+        let mut a = a.to_vec();
+        let mut b = b.to_vec();
+        let mut gs = crs.gs[..n].to_vec();
+        let mut ls = Vec::new();
+        let mut rs = Vec::new();
+
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_l, a_r) = a.split_at(half);
+            let (b_l, b_r) = b.split_at(half);
+            let (g_l, g_r) = gs.split_at(half);
+
+            let l = msm(g_l, a_r) + q.mul(inner_product(a_r, b_l));
+            let r = msm(g_r, a_l) + q.mul(inner_product(a_l, b_r));
+            let lr = batch_normalize(&[l, r]);
+            transcript.append_point(b"L", &lr[0]);
+            transcript.append_point(b"R", &lr[1]);
+            ls.push(lr[0]);
+            rs.push(lr[1]);
+
+            let x = transcript.challenge_scalar(b"x");
+            let x_inv = x.inverse().unwrap();
+
+            a = a_l.iter().zip(a_r).map(|(l, r)| *l + x * r).collect();
+            b = b_l.iter().zip(b_r).map(|(l, r)| *l + x_inv * r).collect();
+            let folded: Vec<EdwardsProjective> = g_l
+                .iter()
+                .zip(g_r)
+                .map(|(l, r)| l.into_projective() + r.mul(x_inv))
+                .collect();
+            gs = batch_normalize(&folded);
+        }
+
+        Self {
+            l: ls,
+            r: rs,
+            a: a[0],
+        }
+    }
+
+    /// The number of rounds of the proof.
+    pub fn num_rounds(&self) -> usize {
+        self.l.len()
+    }
+
+    /// Verify that `commitment` opens to `y = <a, b>`, where `a` is the
+    /// committed vector.
+    pub fn verify(
+        &self,
+        transcript: &mut Transcript,
+        crs: &CRS,
+        commitment: &EdwardsAffine,
+        b: &[Fr],
+        y: &Fr,
+    ) -> Result<(), ProofError> {
+        let n = b.len();
+        if !n.is_power_of_two()
+            || n > crs.len()
+            || self.l.len() != self.r.len()
+            || n.trailing_zeros() as usize != self.l.len()
+        {
+            return Err(ProofError::InvalidLength);
+        }
+
+        let w = bind_statement(transcript, commitment, b, y);
+
+        let mut xs = Vec::with_capacity(self.l.len());
+        for (l, r) in self.l.iter().zip(&self.r) {
+            transcript.append_point(b"L", l);
+            transcript.append_point(b"R", r);
+            xs.push(transcript.challenge_scalar(b"x"));
+        }
+        let mut x_invs = xs.clone();
+        batch_inverse(&mut x_invs);
+
+        // s_i is the coefficient of G_i in the folded generator: the
+        // product of x_j^{-1} over the rounds j in which i was on the
+        // right-hand side.
+        let mut s = ark_std::vec![Fr::one(); n];
+        for (j, x_inv) in x_invs.iter().enumerate() {
+            let half = n >> (j + 1);
+            for (i, s_i) in s.iter_mut().enumerate() {
+                if i & half != 0 {
+                    *s_i *= x_inv;
+                }
+            }
+        }
+        let b_folded = inner_product(&s, b);
+
+        // C + y w Q + sum_j (x_j L_j + x_j^{-1} R_j)
+        //     - a sum_i s_i G_i - a b w Q = 0
+        let rounds = self.l.len();
+        let mut bases = Vec::with_capacity(n + 2 * rounds + 2);
+        let mut scalars = Vec::with_capacity(n + 2 * rounds + 2);
+        bases.push(*commitment);
+        scalars.push(Fr::one());
+        bases.extend_from_slice(&self.l);
+        scalars.extend_from_slice(&xs);
+        bases.extend_from_slice(&self.r);
+        scalars.extend_from_slice(&x_invs);
+        bases.extend_from_slice(&crs.gs[..n]);
+        scalars.extend(s.iter().map(|s_i| -self.a * s_i));
+        bases.push(crs.q);
+        scalars.push(w * (*y - self.a * b_folded));
+
+        if msm(&bases, &scalars).is_zero() {
+            Ok(())
+        } else {
+            Err(ProofError::InvalidProof)
+        }
+    }
+}
+
+/// Append the statement to the transcript and derive the challenge `w`
+/// that scales the auxiliary generator.
+fn bind_statement(
+    transcript: &mut Transcript,
+    commitment: &EdwardsAffine,
+    b: &[Fr],
+    y: &Fr,
+) -> Fr {
+    transcript.domain_sep(b"ipa");
+    transcript.append_point(b"C", commitment);
+    for b_i in b {
+        transcript.append_scalar(b"b", b_i);
+    }
+    transcript.append_scalar(b"y", y);
+    transcript.challenge_scalar(b"w")
+}
@@ -0,0 +1,186 @@
+//! Structured mutations of inner product arguments, for negative testing.
+//!
+//! Every mutation turns a proof into another proof that is structurally
+//! valid (all its points are on the curve and in the prime order subgroup)
+//! but that must be rejected by a verifier, for the reason given by
+//! [`ProofMutation::expected_error`]. A verifier stack that accepts a
+//! mutated proof, or rejects it for another reason, is broken.
+//!
+//! ```
+//! use bandersnatch::ipa::{mutation::ProofMutation, *};
+//! use bandersnatch::Fr;
+//! use ark_ec::ProjectiveCurve;
+//!
+//! let crs = CRS::new(4, DEFAULT_CRS_SEED);
+//! let a: Vec<Fr> = (1..=4u64).map(Fr::from).collect();
+//! let b: Vec<Fr> = (5..=8u64).map(Fr::from).collect();
+//! let c = commit(&crs, &a).into_affine();
+//! let proof = IPAProof::create(&mut Transcript::new(b"test"), &crs, &c, &a, &b);
+//!
+//! for mutation in ProofMutation::all(&proof) {
+//!     let bad = mutation.apply(&proof).unwrap();
+//!     let res = bad.verify(
+//!         &mut Transcript::new(b"test"),
+//!         &crs,
+//!         &c,
+//!         &b,
+//!         &inner_product(&a, &b),
+//!     );
+//!     assert_eq!(res, Err(mutation.expected_error()));
+//! }
+//! ```
+
+use super::{IPAProof, ProofError};
+use crate::{EdwardsAffine, Fr};
+use ark_ec::AffineCurve;
+use ark_ff::PrimeField;
+use ark_std::vec::Vec;
+
+/// Which cross term of a round a mutation targets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// The `L` point.
+    Left,
+    /// The `R` point.
+    Right,
+}
+
+/// A structured mutation of an [`IPAProof`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofMutation {
+    /// Flip the given bit of the final scalar `a`.
+    FlipScalarBit {
+        /// The bit index, below the bit length of the scalar field.
+        bit: usize,
+    },
+    /// Add the prime subgroup generator to the `L` or `R` point of a round.
+    PerturbPoint {
+        /// The round index.
+        round: usize,
+        /// The targeted point.
+        side: Side,
+    },
+    /// Negate the `L` or `R` point of a round.
+    NegatePoint {
+        /// The round index.
+        round: usize,
+        /// The targeted point.
+        side: Side,
+    },
+    /// Swap the `L` and `R` points of a round.
+    SwapLR {
+        /// The round index.
+        round: usize,
+    },
+    /// Swap the rounds `round` and `round + 1` (an off-by-one in the round
+    /// index).
+    SwapRounds {
+        /// The index of the first swapped round.
+        round: usize,
+    },
+    /// Remove the last round.
+    DropRound,
+    /// Repeat the last round.
+    DuplicateRound,
+}
+
+impl ProofMutation {
+    /// The error a verifier must return for a proof with this mutation.
+    pub fn expected_error(&self) -> ProofError {
+        match self {
+            ProofMutation::DropRound | ProofMutation::DuplicateRound => {
+                ProofError::InvalidLength
+            }
+            _ => ProofError::InvalidProof,
+        }
+    }
+
+    /// Apply the mutation to `proof`. Returns `None` if the mutation does
+    /// not apply to this proof (e.g., an out-of-range round), or if it
+    /// leaves the proof unchanged (e.g., swapping two equal points).
+    pub fn apply(&self, proof: &IPAProof) -> Option<IPAProof> {
+        let rounds = proof.num_rounds();
+        let mut mutated = proof.clone();
+        match *self {
+            ProofMutation::FlipScalarBit { bit } => {
+                if bit >= Fr::size_in_bits() {
+                    return None;
+                }
+                let mut repr = proof.a.into_repr();
+                repr.0[bit / 64] ^= 1 << (bit % 64);
+                // flipping a high bit may exceed the modulus
+                mutated.a = Fr::from_repr(repr)?;
+            }
+            ProofMutation::PerturbPoint { round, side } => {
+                let point = point_mut(&mut mutated, round, side)?;
+                *point += EdwardsAffine::prime_subgroup_generator();
+            }
+            ProofMutation::NegatePoint { round, side } => {
+                let point = point_mut(&mut mutated, round, side)?;
+                *point = -*point;
+            }
+            ProofMutation::SwapLR { round } => {
+                if round >= rounds {
+                    return None;
+                }
+                ark_std::mem::swap(
+                    &mut mutated.l[round],
+                    &mut mutated.r[round],
+                );
+            }
+            ProofMutation::SwapRounds { round } => {
+                if round + 1 >= rounds {
+                    return None;
+                }
+                mutated.l.swap(round, round + 1);
+                mutated.r.swap(round, round + 1);
+            }
+            ProofMutation::DropRound => {
+                mutated.l.truncate(rounds.checked_sub(1)?);
+                mutated.r.truncate(rounds - 1);
+            }
+            ProofMutation::DuplicateRound => {
+                mutated.l.push(*proof.l.last()?);
+                mutated.r.push(*proof.r.last()?);
+            }
+        }
+        if mutated == *proof {
+            None
+        } else {
+            Some(mutated)
+        }
+    }
+
+    /// All the mutations that apply to `proof`, with the scalar bit flips
+    /// restricted to the lowest and highest bits.
+    pub fn all(proof: &IPAProof) -> Vec<ProofMutation> {
+        let rounds = proof.num_rounds();
+        let mut mutations = Vec::new();
+        for bit in [0, 1, Fr::size_in_bits() - 2] {
+            mutations.push(ProofMutation::FlipScalarBit { bit });
+        }
+        for round in 0..rounds {
+            for side in [Side::Left, Side::Right] {
+                mutations.push(ProofMutation::PerturbPoint { round, side });
+                mutations.push(ProofMutation::NegatePoint { round, side });
+            }
+            mutations.push(ProofMutation::SwapLR { round });
+            mutations.push(ProofMutation::SwapRounds { round });
+        }
+        mutations.push(ProofMutation::DropRound);
+        mutations.push(ProofMutation::DuplicateRound);
+        mutations.retain(|m| m.apply(proof).is_some());
+        mutations
+    }
+}
+
+fn point_mut(
+    proof: &mut IPAProof,
+    round: usize,
+    side: Side,
+) -> Option<&mut EdwardsAffine> {
+    match side {
+        Side::Left => proof.l.get_mut(round),
+        Side::Right => proof.r.get_mut(round),
+    }
+}
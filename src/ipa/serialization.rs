@@ -0,0 +1,151 @@
+use super::{IPAProof, ProofError, CRS};
+use crate::{EdwardsAffine, Fr, Validate};
+use ark_ff::{FromBytes, ToBytes};
+use ark_std::{io::Read, vec::Vec};
+
+/// Limits enforced while parsing untrusted proofs, CRSs and vectors.
+///
+/// Every length prefix is checked against its limit before anything is
+/// allocated or read, so that a malicious encoding cannot trigger large
+/// allocations or long parsing times by claiming absurd sizes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifierConfig {
+    /// The maximum number of generators of a CRS.
+    pub max_generators: usize,
+    /// The maximum number of rounds of a proof.
+    pub max_rounds: usize,
+    /// The maximum length of a vector of scalars.
+    pub max_vector_length: usize,
+}
+
+impl Default for VerifierConfig {
+    /// Limits suited to vectors of up to 2^16 elements.
+    fn default() -> Self {
+        Self {
+            max_generators: 1 << 16,
+            max_rounds: 16,
+            max_vector_length: 1 << 16,
+        }
+    }
+}
+
+impl IPAProof {
+    /// Serialize the proof as the number of rounds (`u32`, little-endian),
+    /// the `L` points, the `R` points and the final scalar.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + 128 * self.l.len() + 32);
+        (self.l.len() as u32).write(&mut bytes).unwrap();
+        for point in self.l.iter().chain(&self.r) {
+            point.write(&mut bytes).unwrap();
+        }
+        self.a.write(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// Parse a proof serialized with [`IPAProof::to_bytes`], rejecting
+    /// proofs with more than `config.max_rounds` rounds, points outside of
+    /// the prime order subgroup, and trailing bytes.
+    pub fn from_bytes(
+        mut bytes: &[u8],
+        config: &VerifierConfig,
+    ) -> Result<Self, ProofError> {
+        let rounds = read_len(&mut bytes, config.max_rounds)?;
+        let l = read_points(&mut bytes, rounds)?;
+        let r = read_points(&mut bytes, rounds)?;
+        let a =
+            Fr::read(&mut bytes).map_err(|_| ProofError::InvalidEncoding)?;
+        if !bytes.is_empty() {
+            return Err(ProofError::InvalidEncoding);
+        }
+        Ok(Self { l, r, a })
+    }
+}
+
+impl CRS {
+    /// Serialize the CRS as the number of generators (`u32`,
+    /// little-endian), the generators, the auxiliary generator and the
+    /// blinding generator.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + 64 * (self.gs.len() + 2));
+        (self.gs.len() as u32).write(&mut bytes).unwrap();
+        for point in self.gs.iter().chain([&self.q, &self.h]) {
+            point.write(&mut bytes).unwrap();
+        }
+        bytes
+    }
+
+    /// Parse a CRS serialized with [`CRS::to_bytes`], rejecting CRSs with
+    /// more than `config.max_generators` generators, points outside of the
+    /// prime order subgroup, and trailing bytes.
+    pub fn from_bytes(
+        mut bytes: &[u8],
+        config: &VerifierConfig,
+    ) -> Result<Self, ProofError> {
+        let n = read_len(&mut bytes, config.max_generators)?;
+        let mut gs = read_points(&mut bytes, n + 2)?;
+        if !bytes.is_empty() {
+            return Err(ProofError::InvalidEncoding);
+        }
+        let h = gs.pop().unwrap();
+        let q = gs.pop().unwrap();
+        Ok(Self { gs, q, h })
+    }
+}
+
+/// Serialize a vector of scalars as its length (`u32`, little-endian)
+/// followed by the scalars.
+pub fn scalars_to_bytes(scalars: &[Fr]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(4 + 32 * scalars.len());
+    (scalars.len() as u32).write(&mut bytes).unwrap();
+    for scalar in scalars {
+        scalar.write(&mut bytes).unwrap();
+    }
+    bytes
+}
+
+/// Parse a vector of scalars serialized with [`scalars_to_bytes`],
+/// rejecting vectors longer than `config.max_vector_length`, non-canonical
+/// scalars, and trailing bytes.
+pub fn scalars_from_bytes(
+    mut bytes: &[u8],
+    config: &VerifierConfig,
+) -> Result<Vec<Fr>, ProofError> {
+    let n = read_len(&mut bytes, config.max_vector_length)?;
+    let mut scalars = Vec::with_capacity(n);
+    for _ in 0..n {
+        scalars.push(
+            Fr::read(&mut bytes).map_err(|_| ProofError::InvalidEncoding)?,
+        );
+    }
+    if !bytes.is_empty() {
+        return Err(ProofError::InvalidEncoding);
+    }
+    Ok(scalars)
+}
+
+/// Read a length prefix and check it against `max`.
+fn read_len<R: Read>(reader: R, max: usize) -> Result<usize, ProofError> {
+    let len =
+        u32::read(reader).map_err(|_| ProofError::InvalidEncoding)? as usize;
+    if len > max {
+        return Err(ProofError::LimitExceeded);
+    }
+    Ok(len)
+}
+
+/// Read `n` points of the prime order subgroup.
+fn read_points<R: Read>(
+    mut reader: R,
+    n: usize,
+) -> Result<Vec<EdwardsAffine>, ProofError> {
+    let mut points = Vec::with_capacity(n);
+    for _ in 0..n {
+        let point = EdwardsAffine::read(&mut reader)
+            .map_err(|_| ProofError::InvalidEncoding)?;
+        if point.validate().is_err() {
+            return Err(ProofError::InvalidEncoding);
+        }
+        points.push(point);
+    }
+    Ok(points)
+}
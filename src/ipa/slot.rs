@@ -0,0 +1,93 @@
+use super::CRS;
+use crate::{EdwardsAffine, EdwardsProjective, Fr};
+use ark_ec::AffineCurve;
+use ark_std::fmt;
+
+/// The index of a slot of a vector commitment, checked against the width
+/// of the committer (the number of generators of the [`CRS`]) when it is
+/// built.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SlotIndex(usize);
+
+impl SlotIndex {
+    /// The slot `index` of a committer with `width` slots, or `None` if the
+    /// index is out of range.
+    pub fn from_usize(index: usize, width: usize) -> Option<Self> {
+        if index < width {
+            Some(Self(index))
+        } else {
+            None
+        }
+    }
+
+    /// The slot `index` of a committer with `width` slots, or `None` if the
+    /// index is out of range.
+    pub fn from_u8(index: u8, width: usize) -> Option<Self> {
+        Self::from_usize(index as usize, width)
+    }
+
+    /// The index as a `usize`.
+    pub fn as_usize(&self) -> usize {
+        self.0
+    }
+}
+
+impl From<SlotIndex> for usize {
+    fn from(slot: SlotIndex) -> usize {
+        slot.0
+    }
+}
+
+impl fmt::Display for SlotIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl CRS {
+    /// The slot `index` of commitments with this CRS, or `None` if the
+    /// index is out of range.
+    pub fn slot(&self, index: usize) -> Option<SlotIndex> {
+        SlotIndex::from_usize(index, self.len())
+    }
+
+    /// The generator of `slot`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot was checked against a wider committer.
+    pub fn generator(&self, slot: SlotIndex) -> &EdwardsAffine {
+        &self.gs[slot.0]
+    }
+}
+
+/// Commit to the vector that is zero everywhere but at the given slots.
+///
+/// # Panics
+///
+/// Panics if a slot was checked against a wider committer.
+pub fn commit_sparse(
+    crs: &CRS,
+    values: &[(SlotIndex, Fr)],
+) -> EdwardsProjective {
+    values
+        .iter()
+        .map(|(slot, value)| crs.generator(*slot).mul(*value))
+        .sum()
+}
+
+/// Update `commitment` after the value at `slot` changed from `old` to
+/// `new`.
+///
+/// # Panics
+///
+/// Panics if the slot was checked against a wider committer.
+pub fn update_commitment(
+    crs: &CRS,
+    commitment: &EdwardsProjective,
+    slot: SlotIndex,
+    old: &Fr,
+    new: &Fr,
+) -> EdwardsProjective {
+    *commitment + crs.generator(slot).mul(*new - old)
+}
@@ -0,0 +1,484 @@
+use super::{mutation::*, *};
+use crate::poly::DensePolynomial;
+use ark_std::{rand::Rng, test_rng, vec, vec::Vec};
+
+fn setup(n: usize) -> (CRS, Vec<Fr>, Vec<Fr>, EdwardsAffine) {
+    let mut rng = test_rng();
+    let crs = CRS::new(n, DEFAULT_CRS_SEED);
+    let a: Vec<Fr> = (0..n).map(|_| rng.gen()).collect();
+    let b: Vec<Fr> = (0..n).map(|_| rng.gen()).collect();
+    let c = commit(&crs, &a).into_affine();
+    (crs, a, b, c)
+}
+
+#[test]
+fn test_crs() {
+    let crs = CRS::new(16, DEFAULT_CRS_SEED);
+    assert_eq!(crs.len(), 16);
+    assert_eq!(crs, CRS::new(16, DEFAULT_CRS_SEED));
+    assert_ne!(crs, CRS::new(16, b"another seed"));
+    // a shorter CRS is a prefix of a longer one
+    assert_eq!(CRS::new(8, DEFAULT_CRS_SEED).gs[..], crs.gs[..8]);
+    for g in crs.gs.iter().chain([&crs.q, &crs.h]) {
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(!g.is_zero());
+    }
+}
+
+#[test]
+fn test_ipa() {
+    for n in [1, 2, 8, 32] {
+        let (crs, a, b, c) = setup(n);
+        let y = inner_product(&a, &b);
+
+        let proof =
+            IPAProof::create(&mut Transcript::new(b"test"), &crs, &c, &a, &b);
+        assert_eq!(proof.num_rounds(), n.trailing_zeros() as usize);
+        assert_eq!(
+            proof.verify(&mut Transcript::new(b"test"), &crs, &c, &b, &y),
+            Ok(())
+        );
+
+        // wrong evaluation
+        assert_eq!(
+            proof.verify(
+                &mut Transcript::new(b"test"),
+                &crs,
+                &c,
+                &b,
+                &(y + Fr::one())
+            ),
+            Err(ProofError::InvalidProof)
+        );
+        // wrong transcript; a single-element proof reveals the committed
+        // value and does not depend on the challenges
+        if n > 1 {
+            assert_eq!(
+                proof.verify(&mut Transcript::new(b"other"), &crs, &c, &b, &y),
+                Err(ProofError::InvalidProof)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_ipa_lengths() {
+    let (crs, a, b, c) = setup(8);
+    let y = inner_product(&a, &b);
+    let proof =
+        IPAProof::create(&mut Transcript::new(b"test"), &crs, &c, &a, &b);
+    assert_eq!(
+        proof.verify(&mut Transcript::new(b"test"), &crs, &c, &b[..4], &y),
+        Err(ProofError::InvalidLength)
+    );
+    assert_eq!(
+        proof.verify(&mut Transcript::new(b"test"), &crs, &c, &b[..5], &y),
+        Err(ProofError::InvalidLength)
+    );
+    let small_crs = CRS::new(4, DEFAULT_CRS_SEED);
+    assert_eq!(
+        proof.verify(&mut Transcript::new(b"test"), &small_crs, &c, &b, &y),
+        Err(ProofError::InvalidLength)
+    );
+}
+
+#[test]
+fn test_mutations() {
+    let (crs, a, b, c) = setup(8);
+    let y = inner_product(&a, &b);
+    let proof =
+        IPAProof::create(&mut Transcript::new(b"test"), &crs, &c, &a, &b);
+
+    let mutations = ProofMutation::all(&proof);
+    // 3 bit flips, 3 rounds of 6 mutations each, minus the last round
+    // swap, and 2 length mutations
+    assert_eq!(mutations.len(), 3 + 3 * 6 - 1 + 2);
+    for mutation in mutations {
+        let bad = mutation.apply(&proof).unwrap();
+        assert_ne!(bad, proof);
+        for (l, r) in bad.l.iter().zip(bad.r.iter()) {
+            assert!(l.is_in_correct_subgroup_assuming_on_curve());
+            assert!(r.is_in_correct_subgroup_assuming_on_curve());
+        }
+        assert_eq!(
+            bad.verify(&mut Transcript::new(b"test"), &crs, &c, &b, &y),
+            Err(mutation.expected_error()),
+            "{:?}",
+            mutation
+        );
+    }
+
+    assert!(ProofMutation::SwapLR { round: 3 }.apply(&proof).is_none());
+    assert!(ProofMutation::SwapRounds { round: 2 }
+        .apply(&proof)
+        .is_none());
+    assert!(ProofMutation::FlipScalarBit { bit: 256 }
+        .apply(&proof)
+        .is_none());
+}
+
+#[test]
+fn test_transcript() {
+    let mut t1 = Transcript::new(b"test");
+    let mut t2 = Transcript::new(b"test");
+    t1.append_scalar(b"s", &Fr::one());
+    t2.append_scalar(b"s", &Fr::one());
+    assert_eq!(t1.challenge_scalar(b"c"), t2.challenge_scalar(b"c"));
+
+    // challenges are chained
+    let c1 = t1.challenge_scalar(b"c");
+    assert_ne!(c1, Transcript::new(b"test").challenge_scalar(b"c"));
+
+    // labels are bound
+    let mut t3 = Transcript::new(b"test");
+    t3.append_bytes(b"a", b"bc");
+    let mut t4 = Transcript::new(b"test");
+    t4.append_bytes(b"ab", b"c");
+    assert_ne!(t3.challenge_scalar(b"c"), t4.challenge_scalar(b"c"));
+}
+
+#[test]
+fn test_sample_eval_point() {
+    use ark_ff::PrimeField;
+
+    let domain_size = Fr::from(DOMAIN_SIZE as u64).into_repr();
+    for i in 0..32u64 {
+        let mut t1 = Transcript::new(b"test");
+        t1.append_scalar(b"s", &Fr::from(i));
+        let mut t2 = t1.clone();
+        let z = sample_eval_point(&mut t1);
+        assert!(z.into_repr() >= domain_size);
+        assert_eq!(z, sample_eval_point(&mut t2));
+        // the attempt counter is recorded in the transcript
+        assert_eq!(t1, t2);
+    }
+
+    let mut t1 = Transcript::new(b"test");
+    let mut t2 = Transcript::new(b"test");
+    assert_ne!(sample_eval_point(&mut t1), t2.challenge_scalar(b"z"));
+}
+
+#[test]
+fn test_proof_serialization() {
+    let (crs, a, b, c) = setup(8);
+    let proof =
+        IPAProof::create(&mut Transcript::new(b"test"), &crs, &c, &a, &b);
+    let config = VerifierConfig::default();
+
+    let bytes = proof.to_bytes();
+    assert_eq!(bytes.len(), 4 + 3 * 128 + 32);
+    assert_eq!(IPAProof::from_bytes(&bytes, &config), Ok(proof.clone()));
+
+    // truncated and trailing bytes
+    assert_eq!(
+        IPAProof::from_bytes(&bytes[..bytes.len() - 1], &config),
+        Err(ProofError::InvalidEncoding)
+    );
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        IPAProof::from_bytes(&trailing, &config),
+        Err(ProofError::InvalidEncoding)
+    );
+
+    // a point off the curve
+    let mut corrupted = bytes.clone();
+    corrupted[4] ^= 1;
+    assert_eq!(
+        IPAProof::from_bytes(&corrupted, &config),
+        Err(ProofError::InvalidEncoding)
+    );
+
+    // an absurd number of rounds is rejected before reading the points
+    let mut absurd = bytes.clone();
+    absurd[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(
+        IPAProof::from_bytes(&absurd, &config),
+        Err(ProofError::LimitExceeded)
+    );
+    let strict = VerifierConfig {
+        max_rounds: 2,
+        ..config
+    };
+    assert_eq!(
+        IPAProof::from_bytes(&bytes, &strict),
+        Err(ProofError::LimitExceeded)
+    );
+}
+
+#[test]
+fn test_crs_serialization() {
+    let crs = CRS::new(8, DEFAULT_CRS_SEED);
+    let config = VerifierConfig::default();
+    let bytes = crs.to_bytes();
+    assert_eq!(CRS::from_bytes(&bytes, &config), Ok(crs));
+
+    let strict = VerifierConfig {
+        max_generators: 4,
+        ..config
+    };
+    assert_eq!(
+        CRS::from_bytes(&bytes, &strict),
+        Err(ProofError::LimitExceeded)
+    );
+    assert_eq!(
+        CRS::from_bytes(&bytes[..10], &config),
+        Err(ProofError::InvalidEncoding)
+    );
+}
+
+#[test]
+fn test_scalars_serialization() {
+    let (_, a, _, _) = setup(8);
+    let config = VerifierConfig::default();
+    let bytes = scalars_to_bytes(&a);
+    assert_eq!(scalars_from_bytes(&bytes, &config), Ok(a));
+
+    let strict = VerifierConfig {
+        max_vector_length: 7,
+        ..config
+    };
+    assert_eq!(
+        scalars_from_bytes(&bytes, &strict),
+        Err(ProofError::LimitExceeded)
+    );
+
+    // non-canonical scalar
+    let mut bytes = scalars_to_bytes(&[Fr::zero()]);
+    for byte in bytes[4..].iter_mut() {
+        *byte = 0xff;
+    }
+    assert_eq!(
+        scalars_from_bytes(&bytes, &config),
+        Err(ProofError::InvalidEncoding)
+    );
+}
+
+#[test]
+fn test_slot_index() {
+    let (crs, a, _, c) = setup(8);
+    assert_eq!(crs.slot(7).map(usize::from), Some(7));
+    assert_eq!(crs.slot(8), None);
+    assert_eq!(SlotIndex::from_u8(3, 8), crs.slot(3));
+    assert_eq!(SlotIndex::from_u8(255, 8), None);
+    assert_eq!(SlotIndex::from_usize(0, 0), None);
+
+    let values: Vec<(SlotIndex, Fr)> = a
+        .iter()
+        .enumerate()
+        .map(|(i, a_i)| (crs.slot(i).unwrap(), *a_i))
+        .collect();
+    assert_eq!(commit_sparse(&crs, &values).into_affine(), c);
+    assert!(commit_sparse(&crs, &[]).is_zero());
+
+    let slot = crs.slot(5).unwrap();
+    let mut b = a.clone();
+    b[5] = Fr::from(42u64);
+    let updated =
+        update_commitment(&crs, &c.into_projective(), slot, &a[5], &b[5]);
+    assert_eq!(updated, commit(&crs, &b));
+}
+
+#[test]
+fn test_hiding_ipa() {
+    let mut rng = test_rng();
+    for n in [1, 4, 16] {
+        let (crs, a, b, c) = setup(n);
+        let r: Fr = rng.gen();
+        let hidden = commit_hiding(&crs, &a, &r).into_affine();
+        assert_ne!(hidden, c);
+        assert_eq!(commit_hiding(&crs, &a, &Fr::zero()).into_affine(), c);
+
+        let y = inner_product(&a, &b);
+        let proof = HidingIPAProof::create(
+            &mut Transcript::new(b"test"),
+            &crs,
+            &hidden,
+            &a,
+            &r,
+            &b,
+            &mut rng,
+        );
+        assert_ne!(proof.blinding, r);
+        assert_eq!(
+            proof.verify(&mut Transcript::new(b"test"), &crs, &hidden, &b, &y),
+            Ok(())
+        );
+        assert_eq!(
+            proof.verify(
+                &mut Transcript::new(b"test"),
+                &crs,
+                &hidden,
+                &b,
+                &(y + Fr::one())
+            ),
+            Err(ProofError::InvalidProof)
+        );
+        assert_eq!(
+            proof.verify(&mut Transcript::new(b"test"), &crs, &c, &b, &y),
+            Err(ProofError::InvalidProof)
+        );
+
+        let mut bad = proof.clone();
+        bad.blinding += Fr::one();
+        assert_eq!(
+            bad.verify(&mut Transcript::new(b"test"), &crs, &hidden, &b, &y),
+            Err(ProofError::InvalidProof)
+        );
+    }
+}
+
+#[test]
+fn test_kzg_bridge() {
+    use crate::Fq;
+    use ark_ff::{Field, ToBytes};
+
+    /// A stand-in for KZG: the evaluation at a fixed point of the
+    /// polynomial with coefficients `values`.
+    struct Evaluation(Fq);
+    impl KzgCommitter for Evaluation {
+        type Commitment = Fq;
+        fn commit(&self, values: &[Fq]) -> Fq {
+            values
+                .iter()
+                .rev()
+                .fold(Fq::from(0u64), |acc, v| acc * self.0 + v)
+        }
+        fn commitment_bytes(&self, commitment: &Fq) -> Vec<u8> {
+            let mut bytes = Vec::new();
+            commitment.write(&mut bytes).unwrap();
+            bytes
+        }
+    }
+
+    let mut rng = test_rng();
+    let commitments: Vec<EdwardsAffine> = (0..5).map(|_| rng.gen()).collect();
+    let values = commitments_to_field(&commitments);
+    for (c, v) in commitments.iter().zip(&values) {
+        assert_eq!(commitment_to_field(c), *v);
+        assert_eq!(*v * c.y, c.x);
+    }
+    assert_eq!(commitment_to_field(&EdwardsAffine::zero()), Fq::from(0u64));
+
+    let committer = Evaluation(Fq::from(7u64));
+    let mut transcript = Transcript::new(b"bridge");
+    let kzg = commit_to_commitments(&committer, &mut transcript, &commitments);
+    assert_eq!(kzg, committer.commit(&values));
+    assert_eq!(
+        kzg,
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| *v * Fq::from(7u64).pow([i as u64]))
+            .sum::<Fq>()
+    );
+
+    // the transcript binds the commitments
+    let mut other = Transcript::new(b"bridge");
+    let mut swapped = commitments.clone();
+    swapped.swap(0, 1);
+    commit_to_commitments(&committer, &mut other, &swapped);
+    assert_ne!(
+        transcript.challenge_scalar(b"x"),
+        other.challenge_scalar(b"x")
+    );
+}
+
+#[test]
+#[should_panic(expected = "must be a point of the curve")]
+fn test_commitment_to_field_off_curve() {
+    use crate::Fq;
+
+    commitment_to_field(&EdwardsAffine::new(Fq::from(1u64), Fq::from(0u64)));
+}
+
+#[test]
+#[should_panic(expected = "must be points of the curve")]
+fn test_commitments_to_field_off_curve() {
+    use crate::Fq;
+
+    let off_curve = EdwardsAffine::new(Fq::from(1u64), Fq::from(0u64));
+    commitments_to_field(&[EdwardsAffine::zero(), off_curve]);
+}
+
+#[test]
+fn test_precomputed_weights() {
+    let weights = PrecomputedWeights::new();
+    // A'(0) = -255!, A'(255) = 255!
+    let factorial: Fr = (1..DOMAIN_SIZE as u64).map(Fr::from).product();
+    assert_eq!(weights.barycentric_weight(0), -factorial);
+    assert_eq!(weights.barycentric_weight(DOMAIN_SIZE - 1), factorial);
+    for i in [0, 1, 127, 200, 255] {
+        let expected: Fr = (0..DOMAIN_SIZE)
+            .filter(|j| *j != i)
+            .map(|j| Fr::from(i as u64) - Fr::from(j as u64))
+            .product();
+        assert_eq!(weights.barycentric_weight(i), expected);
+        assert!((weights.barycentric_weight(i)
+            * weights.inverse_barycentric_weight(i))
+        .is_one());
+    }
+    for k in [1, 2, 100, 255] {
+        let k_fr = Fr::from(k as u64);
+        assert!((weights.inverted_element(k, false) * k_fr).is_one());
+        assert_eq!(weights.inverted_element(k, true) * k_fr, -Fr::one());
+    }
+    assert_eq!(
+        weights.ratio_of_barycentric_weights(3, 7)
+            * weights.barycentric_weight(7),
+        weights.barycentric_weight(3)
+    );
+}
+
+#[test]
+fn test_precomputed_weights_evaluation() {
+    let mut rng = test_rng();
+    let weights = PrecomputedWeights::new();
+    let evals: Vec<Fr> = (0..DOMAIN_SIZE).map(|_| rng.gen()).collect();
+    let xs: Vec<Fr> = (0..DOMAIN_SIZE as u64).map(Fr::from).collect();
+    let f = DensePolynomial::interpolate(&xs, &evals).unwrap();
+
+    let z: Fr = rng.gen();
+    let coefficients = weights.compute_barycentric_coefficients(&z);
+    assert_eq!(inner_product(&evals, &coefficients), f.evaluate(&z));
+    assert_eq!(weights.evaluate_outside_domain(&evals, &z), f.evaluate(&z));
+    // the Lagrange basis sums to one
+    assert!(coefficients.iter().sum::<Fr>().is_one());
+
+    for m in [0u8, 1, 128, 255] {
+        let quotient = weights.divide_on_domain(m, &evals);
+        let x_m = Fr::from(m as u64);
+        let numerator = &f
+            - &DensePolynomial::from_coefficients_vec(vec![evals[m as usize]]);
+        let divisor =
+            DensePolynomial::from_coefficients_vec(vec![-x_m, Fr::one()]);
+        let (q, r) = numerator.divide_with_remainder(&divisor).unwrap();
+        assert!(r.is_zero());
+        for (i, x) in xs.iter().enumerate() {
+            assert_eq!(quotient[i], q.evaluate(x), "m = {}, i = {}", m, i);
+        }
+    }
+}
+
+#[test]
+fn test_divide_on_domain() {
+    let mut rng = test_rng();
+    let weights = precomputed_weights();
+    assert_eq!(*weights, PrecomputedWeights::new());
+    let f: Vec<Fr> = (0..DOMAIN_SIZE).map(|_| rng.gen()).collect();
+    let z: Fr = rng.gen();
+    for index in [0u8, 7, 255] {
+        let quotient = divide_on_domain(index, &f);
+        assert_eq!(quotient, weights.divide_on_domain(index, &f));
+        // q(z) (z - x_m) = f(z) - f(x_m) outside of the domain
+        let x_m = Fr::from(index as u64);
+        assert_eq!(
+            weights.evaluate_outside_domain(&quotient, &z) * (z - x_m),
+            weights.evaluate_outside_domain(&f, &z) - f[index as usize]
+        );
+    }
+    // a constant has a zero quotient
+    let constant = vec![Fr::from(5u64); DOMAIN_SIZE];
+    assert!(divide_on_domain(3, &constant).iter().all(|q| q.is_zero()));
+}
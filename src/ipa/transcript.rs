@@ -0,0 +1,86 @@
+use crate::{hash_to_curve::hash_to_scalar, EdwardsAffine, Fr};
+use ark_ff::{to_bytes, PrimeField, ToBytes};
+use ark_std::vec::Vec;
+
+/// Domain separation tag of the transcript challenges.
+const TRANSCRIPT_DST: &[u8] = b"BANDERSNATCH-IPA-V01-TRANSCRIPT";
+
+/// The size of the canonical evaluation domain `{0, ..., 255}`.
+pub const DOMAIN_SIZE: usize = 256;
+
+/// A Fiat-Shamir transcript.
+///
+/// Every message is appended together with a label; a challenge is the
+/// hash of all the messages since the previous challenge, and is itself
+/// appended to the transcript so that challenges are chained.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transcript {
+    state: Vec<u8>,
+}
+
+impl Transcript {
+    /// Start a transcript for the protocol `label`.
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Self { state: Vec::new() };
+        transcript.domain_sep(label);
+        transcript
+    }
+
+    /// Append a domain separator.
+    pub fn domain_sep(&mut self, label: &[u8]) {
+        self.append_label(label);
+    }
+
+    /// Append raw bytes.
+    pub fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.append_label(label);
+        (bytes.len() as u64).write(&mut self.state).unwrap();
+        self.state.extend_from_slice(bytes);
+    }
+
+    /// Append a scalar.
+    pub fn append_scalar(&mut self, label: &[u8], scalar: &Fr) {
+        self.append_label(label);
+        scalar.write(&mut self.state).unwrap();
+    }
+
+    /// Append a point.
+    pub fn append_point(&mut self, label: &[u8], point: &EdwardsAffine) {
+        self.append_label(label);
+        point.write(&mut self.state).unwrap();
+    }
+
+    /// Derive a challenge scalar.
+    pub fn challenge_scalar(&mut self, label: &[u8]) -> Fr {
+        self.append_label(label);
+        let challenge = hash_to_scalar(TRANSCRIPT_DST, &[&self.state]);
+        self.state.clear();
+        self.state.extend_from_slice(&to_bytes![challenge].unwrap());
+        challenge
+    }
+
+    fn append_label(&mut self, label: &[u8]) {
+        (label.len() as u64).write(&mut self.state).unwrap();
+        self.state.extend_from_slice(label);
+    }
+}
+
+/// Sample an evaluation point outside of the canonical domain
+/// `{0, ..., DOMAIN_SIZE - 1}`.
+///
+/// Evaluating at a point of the domain would let a prover open a
+/// polynomial through its value at that point instead of its quotient, so
+/// the challenge is resampled until it falls outside of the domain. Every
+/// attempt appends its counter to the transcript, so that the prover and
+/// the verifier agree on the number of attempts.
+pub fn sample_eval_point(transcript: &mut Transcript) -> Fr {
+    let mut counter = 0u64;
+    loop {
+        transcript.append_bytes(b"eval-point-counter", &counter.to_le_bytes());
+        let point = transcript.challenge_scalar(b"z");
+        if point.into_repr() >= (DOMAIN_SIZE as u64).into() {
+            return point;
+        }
+        counter += 1;
+    }
+}
@@ -0,0 +1,188 @@
+use super::DOMAIN_SIZE;
+use crate::{batch_inverse, Fr};
+use ark_ff::{One, Zero};
+use ark_std::{boxed::Box, vec, vec::Vec};
+use once_cell::race::OnceBox;
+
+/// Precomputed values for polynomials in evaluation form over the
+/// canonical domain `{0, ..., 255}`, as the `PrecomputedWeights` of go-ipa:
+/// the barycentric weights `A'(i) = prod_{j != i} (i - j)`, where
+/// `A(x) = prod_i (x - i)`, their inverses, and the inverses of the
+/// differences `±k` of two elements of the domain.
+///
+/// With them, evaluating outside of the domain costs a batch inversion and
+/// an inner product, and dividing by `x - m` on the domain costs
+/// multiplications only.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecomputedWeights {
+    /// `A'(i)` for `i < 256`, followed by `1 / A'(i)`.
+    barycentric_weights: Vec<Fr>,
+    /// `1 / k` for `0 < k < 256`, followed by `-1 / k`.
+    inverted_domain: Vec<Fr>,
+}
+
+impl Default for PrecomputedWeights {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrecomputedWeights {
+    /// Compute the weights and inverses, with two batch inversions.
+    pub fn new() -> Self {
+        // factorials[k] = k!
+        let mut factorials = vec![Fr::one(); DOMAIN_SIZE];
+        for k in 1..DOMAIN_SIZE {
+            factorials[k] = factorials[k - 1] * Fr::from(k as u64);
+        }
+        // A'(i) = prod_{j < i} (i - j) prod_{j > i} (i - j)
+        //       = i! (-1)^(255 - i) (255 - i)!
+        let mut barycentric_weights: Vec<Fr> = (0..DOMAIN_SIZE)
+            .map(|i| {
+                let weight = factorials[i] * factorials[DOMAIN_SIZE - 1 - i];
+                if (DOMAIN_SIZE - 1 - i) % 2 == 1 {
+                    -weight
+                } else {
+                    weight
+                }
+            })
+            .collect();
+        let mut inverses = barycentric_weights.clone();
+        batch_inverse(&mut inverses);
+        barycentric_weights.extend_from_slice(&inverses);
+
+        let mut inverted_domain: Vec<Fr> =
+            (1..DOMAIN_SIZE as u64).map(Fr::from).collect();
+        batch_inverse(&mut inverted_domain);
+        let negated: Vec<Fr> = inverted_domain.iter().map(|k| -*k).collect();
+        inverted_domain.extend_from_slice(&negated);
+
+        Self {
+            barycentric_weights,
+            inverted_domain,
+        }
+    }
+
+    /// `A'(i)`.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is not in the domain.
+    pub fn barycentric_weight(&self, i: usize) -> Fr {
+        assert!(i < DOMAIN_SIZE, "{} is not in the domain", i);
+        self.barycentric_weights[i]
+    }
+
+    /// `1 / A'(i)`.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is not in the domain.
+    pub fn inverse_barycentric_weight(&self, i: usize) -> Fr {
+        assert!(i < DOMAIN_SIZE, "{} is not in the domain", i);
+        self.barycentric_weights[i + DOMAIN_SIZE]
+    }
+
+    /// `A'(m) / A'(j)`.
+    pub fn ratio_of_barycentric_weights(&self, m: usize, j: usize) -> Fr {
+        self.barycentric_weight(m) * self.inverse_barycentric_weight(j)
+    }
+
+    /// `1 / k`, or `-1 / k` if `negative` is set.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is 0 or not smaller than 256.
+    pub fn inverted_element(&self, k: usize, negative: bool) -> Fr {
+        assert!(
+            k > 0 && k < DOMAIN_SIZE,
+            "{} is not a nonzero difference of the domain",
+            k
+        );
+        let index = k - 1;
+        if negative {
+            self.inverted_domain[index + DOMAIN_SIZE - 1]
+        } else {
+            self.inverted_domain[index]
+        }
+    }
+
+    /// The values `L_i(z)` of the Lagrange basis polynomials of the domain
+    /// at `z`, `A(z) / (A'(i) (z - i))`, so that the value at `z` of a
+    /// polynomial in evaluation form is the inner product of its
+    /// evaluations with them.
+    ///
+    /// `z` must not be in the domain, see
+    /// [`sample_eval_point`](super::sample_eval_point); if it is, the
+    /// coefficients are all zero, as in go-ipa.
+    pub fn compute_barycentric_coefficients(&self, z: &Fr) -> Vec<Fr> {
+        let differences: Vec<Fr> =
+            (0..DOMAIN_SIZE).map(|i| *z - Fr::from(i as u64)).collect();
+        let a_z: Fr = differences.iter().product();
+        let mut coefficients: Vec<Fr> = differences
+            .iter()
+            .zip(&self.barycentric_weights[..DOMAIN_SIZE])
+            .map(|(d, w)| *d * w)
+            .collect();
+        batch_inverse(&mut coefficients);
+        coefficients.iter_mut().for_each(|c| *c *= a_z);
+        coefficients
+    }
+
+    /// The value at `z`, outside of the domain, of the polynomial with
+    /// evaluations `evals` on the domain.
+    pub fn evaluate_outside_domain(&self, evals: &[Fr], z: &Fr) -> Fr {
+        super::inner_product(evals, &self.compute_barycentric_coefficients(z))
+    }
+
+    /// The evaluations on the domain of the quotient
+    /// `q(x) = (f(x) - f(m)) / (x - m)`, where `f` has evaluations `f` on
+    /// the domain, as in go-ipa: `q(i) = (f(i) - f(m)) / (i - m)` for
+    /// `i != m`, and `q(m) = -sum_{i != m} A'(m) / A'(i) q(i)`.
+    ///
+    /// # Panics
+    ///
+    /// If `f` does not have 256 evaluations.
+    pub fn divide_on_domain(&self, m: u8, f: &[Fr]) -> Vec<Fr> {
+        assert_eq!(f.len(), DOMAIN_SIZE, "one evaluation per domain element");
+        let m = m as usize;
+        let mut quotient = vec![Fr::zero(); DOMAIN_SIZE];
+        let y = f[m];
+        for i in (0..DOMAIN_SIZE).filter(|i| *i != m) {
+            let den_inv = if i > m {
+                self.inverted_element(i - m, false)
+            } else {
+                self.inverted_element(m - i, true)
+            };
+            quotient[i] = (f[i] - y) * den_inv;
+            let ratio = self.ratio_of_barycentric_weights(m, i);
+            let q_i = quotient[i];
+            quotient[m] -= ratio * q_i;
+        }
+        quotient
+    }
+}
+
+static PRECOMPUTED_WEIGHTS: OnceBox<PrecomputedWeights> = OnceBox::new();
+
+/// The [`PrecomputedWeights`] of the canonical domain, computed on first
+/// use.
+pub fn precomputed_weights() -> &'static PrecomputedWeights {
+    PRECOMPUTED_WEIGHTS.get_or_init(|| Box::new(PrecomputedWeights::new()))
+}
+
+/// The evaluations on the canonical domain of
+/// `(f(X) - f(z)) / (X - z)`, where `z` is the domain element
+/// `eval_point_index` and `f` the polynomial with evaluations `values`, as
+/// `DivideOnDomain` in go-ipa, with the shared
+/// [`precomputed_weights`].
+///
+/// This is the quotient that proofs of evaluation at a point of the domain
+/// commit to, e.g., in the multiproofs of Verkle trees.
+///
+/// # Panics
+///
+/// If `values` does not have 256 evaluations.
+pub fn divide_on_domain(eval_point_index: u8, values: &[Fr]) -> Vec<Fr> {
+    precomputed_weights().divide_on_domain(eval_point_index, values)
+}
@@ -7,7 +7,16 @@
     nonstandard_style,
     rust_2018_idioms
 )]
-#![forbid(unsafe_code)]
+// `unsafe` is confined to the C ABI of the `ffi` feature and to the code
+// generated for the `wasm` and `python` bindings.
+#![cfg_attr(
+    not(any(feature = "ffi", feature = "wasm", feature = "python")),
+    forbid(unsafe_code)
+)]
+#![cfg_attr(
+    any(feature = "ffi", feature = "wasm", feature = "python"),
+    deny(unsafe_code)
+)]
 
 //! This library implements a twisted Edwards curve whose base field is the scalar field of the
 //! curve BLS12-377. This allows defining cryptographic primitives that use elliptic curves over
@@ -23,14 +32,52 @@
 //!    * a = -1
 //!    * d = -(10240/10241)
 
+extern crate alloc;
+
 #[cfg(test)]
 #[macro_use]
 extern crate std;
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
 
+#[cfg(feature = "ark-ed-on-bls12-381-bandersnatch")]
+pub mod arkworks;
+pub mod banderwagon;
+mod capabilities;
+pub mod commit_reveal;
+#[cfg(feature = "conformance")]
+pub mod conformance;
 // #[cfg(feature = "r1cs")]
 pub mod constraints;
 mod curves;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 mod fields;
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
+mod group;
+pub mod hash_to_curve;
+mod hex;
+pub mod ipa;
+pub mod poly;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod schnorr;
+#[cfg(feature = "serde")]
+pub mod serialization;
+#[cfg(feature = "ssz")]
+pub mod ssz;
+pub mod storage;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+#[cfg(feature = "std")]
+pub mod tune;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
+pub use capabilities::{capabilities, Capabilities};
 pub use curves::*;
 pub use fields::*;
+pub use group::PrimeOrderGroup;
+pub use hex::{Hex, HexEncoding, HexError, HEX_ENCODING_SIZE};
+pub use subtle::{Choice, CtOption};
@@ -0,0 +1,225 @@
+//! Radix-2 evaluation domains of `Fr`, the subgroups of `2^k`-th roots of
+//! unity and their cosets, with the FFT between coefficients and
+//! evaluations.
+//!
+//! The two-adicity of `r - 1` is only 5, see [`crate::roots`], so domains
+//! have at most [`MAX_DOMAIN_SIZE`] elements: FFTs speed up products and
+//! evaluations of polynomials of degree smaller than 32, and larger ones
+//! need the quadratic algorithms of [`DensePolynomial`].
+
+use super::DensePolynomial;
+use crate::{
+    roots::{
+        inverse_root_of_unity, root_of_unity, MAX_DOMAIN_SIZE,
+        MAX_ROOT_OF_UNITY_LOG_SIZE,
+    },
+    Fr,
+};
+use ark_ff::{FftField, Field, One, Zero};
+use ark_std::vec::Vec;
+
+/// The subgroup of order `size` of `Fr*`, generated by a primitive
+/// `size`-th root of unity `w`, and its coset by the multiplicative
+/// generator `g` of `Fr*`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Radix2EvaluationDomain {
+    log_size: u32,
+    group_gen: Fr,
+    group_gen_inv: Fr,
+    size_inv: Fr,
+    coset_offset: Fr,
+    coset_offset_inv: Fr,
+}
+
+impl Radix2EvaluationDomain {
+    /// The smallest domain with at least `num_coeffs` elements, or `None`
+    /// if it would exceed [`MAX_DOMAIN_SIZE`].
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        if num_coeffs > MAX_DOMAIN_SIZE {
+            return None;
+        }
+        let log_size = num_coeffs.max(1).next_power_of_two().trailing_zeros();
+        debug_assert!(log_size <= MAX_ROOT_OF_UNITY_LOG_SIZE);
+        let coset_offset = Fr::multiplicative_generator();
+        Some(Self {
+            log_size,
+            group_gen: root_of_unity(log_size)?,
+            group_gen_inv: inverse_root_of_unity(log_size)?,
+            size_inv: Fr::from(1u64 << log_size).inverse().unwrap(),
+            coset_offset,
+            coset_offset_inv: coset_offset.inverse().unwrap(),
+        })
+    }
+
+    /// The number of elements of the domain.
+    pub fn size(&self) -> usize {
+        1 << self.log_size
+    }
+
+    /// `log2` of the size.
+    pub fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// The generator `w` of the domain.
+    pub fn group_gen(&self) -> Fr {
+        self.group_gen
+    }
+
+    /// The offset `g` of the coset of the domain.
+    pub fn coset_offset(&self) -> Fr {
+        self.coset_offset
+    }
+
+    /// The `i`-th element `w^i` of the domain.
+    pub fn element(&self, i: usize) -> Fr {
+        self.group_gen.pow([i as u64])
+    }
+
+    /// The elements `1, w, ..., w^(size - 1)` of the domain.
+    pub fn elements(&self) -> Vec<Fr> {
+        powers(&self.group_gen, self.size())
+    }
+
+    /// The value at `x` of the vanishing polynomial `x^size - 1` of the
+    /// domain.
+    pub fn evaluate_vanishing_polynomial(&self, x: &Fr) -> Fr {
+        x.pow([self.size() as u64]) - Fr::one()
+    }
+
+    /// The evaluations of the polynomial with coefficients `coeffs` on the
+    /// domain, i.e., at `w^i` for `i < size`.
+    ///
+    /// # Panics
+    ///
+    /// If there are more coefficients than elements in the domain.
+    pub fn fft(&self, coeffs: &[Fr]) -> Vec<Fr> {
+        let mut values = self.padded(coeffs);
+        fft_in_place(&mut values, &self.group_gen);
+        values
+    }
+
+    /// The coefficients of the polynomial of degree smaller than `size`
+    /// with these evaluations on the domain.
+    ///
+    /// # Panics
+    ///
+    /// If there are more evaluations than elements in the domain.
+    pub fn ifft(&self, evals: &[Fr]) -> Vec<Fr> {
+        let mut values = self.padded(evals);
+        fft_in_place(&mut values, &self.group_gen_inv);
+        values.iter_mut().for_each(|v| *v *= self.size_inv);
+        values
+    }
+
+    /// The evaluations of the polynomial on the coset, i.e., at `g w^i`,
+    /// e.g., to divide by the vanishing polynomial, which is the constant
+    /// `g^size - 1` there.
+    ///
+    /// # Panics
+    ///
+    /// If there are more coefficients than elements in the domain.
+    pub fn coset_fft(&self, coeffs: &[Fr]) -> Vec<Fr> {
+        let mut values = self.padded(coeffs);
+        scale_by_powers(&mut values, &self.coset_offset);
+        fft_in_place(&mut values, &self.group_gen);
+        values
+    }
+
+    /// The inverse of [`Radix2EvaluationDomain::coset_fft`].
+    ///
+    /// # Panics
+    ///
+    /// If there are more evaluations than elements in the domain.
+    pub fn coset_ifft(&self, evals: &[Fr]) -> Vec<Fr> {
+        let mut values = self.ifft(evals);
+        scale_by_powers(&mut values, &self.coset_offset_inv);
+        values
+    }
+
+    /// The product of two polynomials with pointwise products of their
+    /// evaluations, or `None` if the product has more coefficients than
+    /// the domain has elements.
+    pub fn mul_polynomials(
+        &self,
+        a: &DensePolynomial,
+        b: &DensePolynomial,
+    ) -> Option<DensePolynomial> {
+        if a.is_zero() || b.is_zero() {
+            return Some(DensePolynomial::zero());
+        }
+        if a.coeffs().len() + b.coeffs().len() - 1 > self.size() {
+            return None;
+        }
+        let mut evals = self.fft(a.coeffs());
+        for (x, y) in evals.iter_mut().zip(self.fft(b.coeffs())) {
+            *x *= y;
+        }
+        Some(DensePolynomial::from_coefficients_vec(self.ifft(&evals)))
+    }
+
+    fn padded(&self, values: &[Fr]) -> Vec<Fr> {
+        assert!(
+            values.len() <= self.size(),
+            "{} values do not fit in a domain of size {}",
+            values.len(),
+            self.size()
+        );
+        let mut padded = values.to_vec();
+        padded.resize(self.size(), Fr::zero());
+        padded
+    }
+}
+
+/// `1, x, ..., x^(n - 1)`.
+fn powers(x: &Fr, n: usize) -> Vec<Fr> {
+    let mut powers = Vec::with_capacity(n);
+    let mut power = Fr::one();
+    for _ in 0..n {
+        powers.push(power);
+        power *= x;
+    }
+    powers
+}
+
+/// Multiply the `i`-th value by `x^i`.
+fn scale_by_powers(values: &mut [Fr], x: &Fr) {
+    let mut power = Fr::one();
+    for v in values.iter_mut() {
+        *v *= power;
+        power *= x;
+    }
+}
+
+/// The iterative Cooley-Tukey FFT, in place: `values[i]` is replaced with
+/// `sum_j values[j] w^(i j)`, where `w` has order `values.len()`, a power
+/// of two.
+fn fft_in_place(values: &mut [Fr], w: &Fr) {
+    let n = values.len();
+    let log_n = n.trailing_zeros();
+    if n <= 1 {
+        return;
+    }
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut half = 1;
+    while half < n {
+        // a primitive (2 half)-th root of unity
+        let step = w.pow([(n / (2 * half)) as u64]);
+        for chunk in values.chunks_mut(2 * half) {
+            let mut twiddle = Fr::one();
+            let (low, high) = chunk.split_at_mut(half);
+            for (a, b) in low.iter_mut().zip(high.iter_mut()) {
+                let t = *b * twiddle;
+                *b = *a - t;
+                *a += t;
+                twiddle *= step;
+            }
+        }
+        half *= 2;
+    }
+}
@@ -0,0 +1,254 @@
+//! Dense univariate polynomials over `Fr`, the scalar field of
+//! bandersnatch, e.g., for the polynomial commitments of Verkle proofs.
+//!
+//! A [`DensePolynomial`] stores its coefficients from the constant term
+//! up, without trailing zeros, so that the zero polynomial has no
+//! coefficients and two equal polynomials have equal representations.
+//! Multiplication is schoolbook, division is long division, and
+//! interpolation is Lagrange interpolation through the product of the
+//! `x - x_i`, all in quadratic time.
+//!
+//! Products and evaluations of polynomials of degree smaller than 32 can
+//! also go through the FFT of a [`Radix2EvaluationDomain`].
+
+use crate::{batch_inverse, Fr};
+use ark_ff::{Field, One, Zero};
+use ark_std::{
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    vec,
+    vec::Vec,
+};
+
+mod domain;
+#[cfg(test)]
+mod tests;
+
+pub use domain::Radix2EvaluationDomain;
+
+/// A polynomial `sum_i coeffs[i] x^i` over `Fr`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DensePolynomial {
+    coeffs: Vec<Fr>,
+}
+
+impl DensePolynomial {
+    /// The zero polynomial.
+    pub fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    /// The polynomial with these coefficients, from the constant term up;
+    /// trailing zeros are dropped.
+    pub fn from_coefficients_vec(coeffs: Vec<Fr>) -> Self {
+        let mut p = Self { coeffs };
+        p.truncate_leading_zeros();
+        p
+    }
+
+    /// Same as [`DensePolynomial::from_coefficients_vec`], from a slice.
+    pub fn from_coefficients_slice(coeffs: &[Fr]) -> Self {
+        Self::from_coefficients_vec(coeffs.to_vec())
+    }
+
+    /// The coefficients, from the constant term up, without trailing
+    /// zeros.
+    pub fn coeffs(&self) -> &[Fr] {
+        &self.coeffs
+    }
+
+    /// Whether this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// The degree, where the zero polynomial has degree 0, as in arkworks.
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// The leading coefficient, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<&Fr> {
+        self.coeffs.last()
+    }
+
+    /// Evaluate the polynomial at `x` with Horner's rule.
+    pub fn evaluate(&self, x: &Fr) -> Fr {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, c| acc * x + c)
+    }
+
+    /// Multiply every coefficient by `scalar`.
+    pub fn mul_by_scalar(&self, scalar: &Fr) -> Self {
+        Self::from_coefficients_vec(
+            self.coeffs.iter().map(|c| *c * scalar).collect(),
+        )
+    }
+
+    /// The quotient and remainder of the division by `divisor`, such that
+    /// `self = quotient * divisor + remainder` and the degree of the
+    /// remainder is smaller than the one of the divisor, or `None` if the
+    /// divisor is zero.
+    pub fn divide_with_remainder(
+        &self,
+        divisor: &Self,
+    ) -> Option<(Self, Self)> {
+        let lead_inv = divisor.leading_coefficient()?.inverse().unwrap();
+        if self.coeffs.len() < divisor.coeffs.len() {
+            return Some((Self::zero(), self.clone()));
+        }
+        let divisor_degree = divisor.degree();
+        let mut remainder = self.coeffs.clone();
+        let mut quotient =
+            vec![Fr::zero(); self.coeffs.len() - divisor.coeffs.len() + 1];
+        for i in (0..quotient.len()).rev() {
+            let q = remainder[i + divisor_degree] * lead_inv;
+            quotient[i] = q;
+            for (r, d) in remainder[i..].iter_mut().zip(&divisor.coeffs) {
+                *r -= q * d;
+            }
+        }
+        remainder.truncate(divisor_degree);
+        Some((
+            Self::from_coefficients_vec(quotient),
+            Self::from_coefficients_vec(remainder),
+        ))
+    }
+
+    /// The polynomial of degree smaller than `xs.len()` with
+    /// `p(xs[i]) = ys[i]`, by Lagrange interpolation, or `None` if the
+    /// slices have different lengths or two `xs` are equal.
+    pub fn interpolate(xs: &[Fr], ys: &[Fr]) -> Option<Self> {
+        if xs.len() != ys.len() {
+            return None;
+        }
+        // z(x) = prod_i (x - xs[i])
+        let mut z = vec![Fr::one()];
+        for x in xs {
+            z.push(Fr::zero());
+            for k in (1..z.len()).rev() {
+                let lower = z[k - 1];
+                z[k] = lower - *x * z[k];
+            }
+            z[0] = -*x * z[0];
+        }
+        let z = Self::from_coefficients_vec(z);
+
+        // the basis polynomials z(x) / (x - xs[i]), and their values at
+        // xs[i], which vanish if and only if xs[i] is repeated
+        let basis: Vec<Vec<Fr>> =
+            xs.iter().map(|x| divide_by_linear(&z.coeffs, x)).collect();
+        let mut denominators: Vec<Fr> = basis
+            .iter()
+            .zip(xs)
+            .map(|(b, x)| Self::from_coefficients_slice(b).evaluate(x))
+            .collect();
+        if denominators.iter().any(Fr::is_zero) {
+            return None;
+        }
+        batch_inverse(&mut denominators);
+
+        let mut coeffs = vec![Fr::zero(); xs.len()];
+        for ((b, y), den_inv) in basis.iter().zip(ys).zip(&denominators) {
+            let weight = *y * den_inv;
+            for (c, b) in coeffs.iter_mut().zip(b) {
+                *c += weight * b;
+            }
+        }
+        Some(Self::from_coefficients_vec(coeffs))
+    }
+
+    fn truncate_leading_zeros(&mut self) {
+        while self.coeffs.last().is_some_and(Fr::is_zero) {
+            self.coeffs.pop();
+        }
+    }
+}
+
+/// The quotient of the division of `sum_i coeffs[i] x^i` by `x - a`, by
+/// synthetic division; the remainder is dropped.
+fn divide_by_linear(coeffs: &[Fr], a: &Fr) -> Vec<Fr> {
+    if coeffs.is_empty() {
+        return Vec::new();
+    }
+    let mut quotient = vec![Fr::zero(); coeffs.len() - 1];
+    let mut carry = Fr::zero();
+    for (q, c) in quotient.iter_mut().zip(coeffs[1..].iter()).rev() {
+        carry = carry * a + c;
+        *q = carry;
+    }
+    quotient
+}
+
+impl Add<&DensePolynomial> for &DensePolynomial {
+    type Output = DensePolynomial;
+
+    fn add(self, other: &DensePolynomial) -> DensePolynomial {
+        let mut sum = self.clone();
+        sum += other;
+        sum
+    }
+}
+
+impl AddAssign<&DensePolynomial> for DensePolynomial {
+    fn add_assign(&mut self, other: &DensePolynomial) {
+        if self.coeffs.len() < other.coeffs.len() {
+            self.coeffs.resize(other.coeffs.len(), Fr::zero());
+        }
+        for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
+            *a += b;
+        }
+        self.truncate_leading_zeros();
+    }
+}
+
+impl Sub<&DensePolynomial> for &DensePolynomial {
+    type Output = DensePolynomial;
+
+    fn sub(self, other: &DensePolynomial) -> DensePolynomial {
+        let mut difference = self.clone();
+        difference -= other;
+        difference
+    }
+}
+
+impl SubAssign<&DensePolynomial> for DensePolynomial {
+    fn sub_assign(&mut self, other: &DensePolynomial) {
+        if self.coeffs.len() < other.coeffs.len() {
+            self.coeffs.resize(other.coeffs.len(), Fr::zero());
+        }
+        for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
+            *a -= b;
+        }
+        self.truncate_leading_zeros();
+    }
+}
+
+impl Neg for &DensePolynomial {
+    type Output = DensePolynomial;
+
+    fn neg(self) -> DensePolynomial {
+        DensePolynomial {
+            coeffs: self.coeffs.iter().map(|c| -*c).collect(),
+        }
+    }
+}
+
+impl Mul<&DensePolynomial> for &DensePolynomial {
+    type Output = DensePolynomial;
+
+    fn mul(self, other: &DensePolynomial) -> DensePolynomial {
+        if self.is_zero() || other.is_zero() {
+            return DensePolynomial::zero();
+        }
+        let mut coeffs =
+            vec![Fr::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (c, b) in coeffs[i..].iter_mut().zip(&other.coeffs) {
+                *c += *a * b;
+            }
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+}
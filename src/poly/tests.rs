@@ -0,0 +1,171 @@
+use super::*;
+use ark_std::{test_rng, UniformRand};
+
+fn poly(coeffs: &[u64]) -> DensePolynomial {
+    DensePolynomial::from_coefficients_vec(
+        coeffs.iter().map(|c| Fr::from(*c)).collect(),
+    )
+}
+
+fn random_poly(len: usize) -> DensePolynomial {
+    let mut rng = test_rng();
+    DensePolynomial::from_coefficients_vec(
+        (0..len).map(|_| Fr::rand(&mut rng)).collect(),
+    )
+}
+
+#[test]
+fn test_normalization() {
+    let p = poly(&[1, 2, 0, 0]);
+    assert_eq!(p.coeffs(), &[Fr::from(1u64), Fr::from(2u64)]);
+    assert_eq!(p.degree(), 1);
+    assert!(poly(&[0, 0]).is_zero());
+    assert_eq!(poly(&[0, 0]), DensePolynomial::zero());
+    assert_eq!(DensePolynomial::zero().degree(), 0);
+    assert_eq!(DensePolynomial::zero().leading_coefficient(), None);
+}
+
+#[test]
+fn test_evaluate() {
+    // 3 + 2 x + x^2 at 5
+    assert_eq!(poly(&[3, 2, 1]).evaluate(&Fr::from(5u64)), Fr::from(38u64));
+    assert!(DensePolynomial::zero().evaluate(&Fr::from(5u64)).is_zero());
+}
+
+#[test]
+fn test_arithmetic() {
+    let mut rng = test_rng();
+    let a = random_poly(7);
+    let b = random_poly(4);
+    let x = Fr::rand(&mut rng);
+    let (ax, bx) = (a.evaluate(&x), b.evaluate(&x));
+    assert_eq!((&a + &b).evaluate(&x), ax + bx);
+    assert_eq!((&a - &b).evaluate(&x), ax - bx);
+    assert_eq!((&a * &b).evaluate(&x), ax * bx);
+    assert_eq!((-&a).evaluate(&x), -ax);
+    assert_eq!(a.mul_by_scalar(&x).evaluate(&x), ax * x);
+    assert_eq!((&a * &b).degree(), a.degree() + b.degree());
+    assert!((&a - &a).is_zero());
+    assert!((&a * &DensePolynomial::zero()).is_zero());
+    // (1 + x) (1 - x) = 1 - x^2
+    let minus_one = -Fr::from(1u64);
+    let one_minus_x =
+        DensePolynomial::from_coefficients_vec(vec![Fr::from(1u64), minus_one]);
+    assert_eq!(
+        &poly(&[1, 1]) * &one_minus_x,
+        DensePolynomial::from_coefficients_vec(vec![
+            Fr::from(1u64),
+            Fr::zero(),
+            minus_one
+        ])
+    );
+}
+
+#[test]
+fn test_divide_with_remainder() {
+    let a = random_poly(9);
+    let b = random_poly(4);
+    let (q, r) = a.divide_with_remainder(&b).unwrap();
+    assert!(r.degree() < b.degree());
+    assert_eq!(&(&q * &b) + &r, a);
+    // exact division
+    let (q, r) = (&a * &b).divide_with_remainder(&b).unwrap();
+    assert_eq!(q, a);
+    assert!(r.is_zero());
+    // a divisor of larger degree
+    let (q, r) = b.divide_with_remainder(&a).unwrap();
+    assert!(q.is_zero());
+    assert_eq!(r, b);
+    assert_eq!(a.divide_with_remainder(&DensePolynomial::zero()), None);
+}
+
+#[test]
+fn test_interpolate() {
+    let mut rng = test_rng();
+    let p = random_poly(8);
+    let xs: Vec<Fr> = (0..8).map(|_| Fr::rand(&mut rng)).collect();
+    let ys: Vec<Fr> = xs.iter().map(|x| p.evaluate(x)).collect();
+    assert_eq!(DensePolynomial::interpolate(&xs, &ys), Some(p));
+
+    // through (0, 1), (1, 3), (2, 7): 1 + x + x^2
+    let xs = [Fr::from(0u64), Fr::from(1u64), Fr::from(2u64)];
+    let ys = [Fr::from(1u64), Fr::from(3u64), Fr::from(7u64)];
+    assert_eq!(
+        DensePolynomial::interpolate(&xs, &ys),
+        Some(poly(&[1, 1, 1]))
+    );
+
+    assert_eq!(
+        DensePolynomial::interpolate(&[], &[]),
+        Some(DensePolynomial::zero())
+    );
+    assert_eq!(DensePolynomial::interpolate(&xs, &ys[..2]), None);
+    let repeated = [Fr::from(0u64), Fr::from(1u64), Fr::from(1u64)];
+    assert_eq!(DensePolynomial::interpolate(&repeated, &ys), None);
+}
+
+#[test]
+fn test_domain() {
+    assert_eq!(Radix2EvaluationDomain::new(0).unwrap().size(), 1);
+    assert_eq!(Radix2EvaluationDomain::new(5).unwrap().size(), 8);
+    assert_eq!(Radix2EvaluationDomain::new(32).unwrap().size(), 32);
+    assert_eq!(Radix2EvaluationDomain::new(33), None);
+
+    let domain = Radix2EvaluationDomain::new(16).unwrap();
+    assert_eq!(domain.log_size(), 4);
+    let elements = domain.elements();
+    assert_eq!(elements, crate::roots::domain_elements(16).unwrap());
+    assert_eq!(domain.element(3), elements[3]);
+    for x in &elements {
+        assert!(domain.evaluate_vanishing_polynomial(x).is_zero());
+    }
+    let g = domain.coset_offset();
+    assert!(!domain.evaluate_vanishing_polynomial(&g).is_zero());
+}
+
+#[test]
+fn test_fft() {
+    for log_size in 0..=5 {
+        let domain = Radix2EvaluationDomain::new(1 << log_size).unwrap();
+        let p = random_poly(domain.size());
+        let evals = domain.fft(p.coeffs());
+        for (x, y) in domain.elements().iter().zip(&evals) {
+            assert_eq!(p.evaluate(x), *y);
+        }
+        assert_eq!(
+            DensePolynomial::from_coefficients_vec(domain.ifft(&evals)),
+            p
+        );
+
+        let coset_evals = domain.coset_fft(p.coeffs());
+        let g = domain.coset_offset();
+        for (x, y) in domain.elements().iter().zip(&coset_evals) {
+            assert_eq!(p.evaluate(&(g * x)), *y);
+        }
+        assert_eq!(
+            DensePolynomial::from_coefficients_vec(
+                domain.coset_ifft(&coset_evals)
+            ),
+            p
+        );
+    }
+    // fewer coefficients than elements are padded
+    let domain = Radix2EvaluationDomain::new(8).unwrap();
+    let p = random_poly(3);
+    let evals = domain.fft(p.coeffs());
+    assert_eq!(evals.len(), 8);
+    assert_eq!(evals[5], p.evaluate(&domain.element(5)));
+}
+
+#[test]
+fn test_mul_polynomials() {
+    let domain = Radix2EvaluationDomain::new(32).unwrap();
+    let a = random_poly(20);
+    let b = random_poly(13);
+    assert_eq!(domain.mul_polynomials(&a, &b), Some(&a * &b));
+    assert_eq!(domain.mul_polynomials(&a, &random_poly(14)), None);
+    assert_eq!(
+        domain.mul_polynomials(&a, &DensePolynomial::zero()),
+        Some(DensePolynomial::zero())
+    );
+}
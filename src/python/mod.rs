@@ -0,0 +1,324 @@
+//! Python bindings with pyo3, behind the `python` feature, so that
+//! protocols can be prototyped in Python on top of this implementation.
+//!
+//! The extension module is named `bandersnatch`. It is built with
+//!
+//! ```text
+//! cargo rustc --release --features python,pyo3/extension-module \
+//!     --crate-type cdylib
+//! ```
+//!
+//! and imported once `target/release/libbandersnatch.so` is copied to
+//! `bandersnatch.so` on the Python path.
+//!
+//! It provides two classes: `Scalar`, an element of the scalar field
+//! `Fr`, and `Point`, a point of the prime order subgroup of bandersnatch.
+//! Both support the arithmetic operators, equality and hashing, and
+//! serialize to 32 bytes: scalars as their canonical little-endian
+//! integers and points as their compressed encoding, see
+//! [`to_compressed_bytes`]. Python integers are accepted wherever a scalar
+//! is expected and are reduced modulo `r`. Invalid inputs raise
+//! `ValueError`.
+//!
+//! ```python
+//! from bandersnatch import Point, Scalar, msm
+//!
+//! k = Scalar(42)
+//! p = k * Point.generator()
+//! assert Point.from_bytes(p.to_bytes()) == p
+//! assert msm([p, Point.generator()], [2, -k]) == p
+//! ```
+
+use crate::{
+    msm as msm_affine, to_compressed_bytes, EdwardsAffine, EdwardsProjective,
+    FieldBytes, Fq, Fr, FrParameters, HexEncoding, ValidatedPoint,
+    COMPRESSED_SIZE,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, FpParameters, PrimeField, Zero};
+use ark_std::{
+    format,
+    hash::{Hash, Hasher},
+    string::{String, ToString},
+    vec::Vec,
+};
+use num_bigint::{BigInt, BigUint, Sign};
+use pyo3::{
+    exceptions::{PyValueError, PyZeroDivisionError},
+    prelude::*,
+    types::PyBytes,
+};
+use std::collections::hash_map::DefaultHasher;
+
+#[cfg(test)]
+mod tests;
+
+/// An element of the scalar field `Fr`.
+#[pyclass(module = "bandersnatch", frozen, eq, from_py_object)]
+#[derive(Clone, PartialEq)]
+pub struct Scalar(pub Fr);
+
+/// A point of the prime order subgroup of bandersnatch.
+#[pyclass(module = "bandersnatch", frozen, eq, from_py_object)]
+#[derive(Clone, PartialEq)]
+pub struct Point(pub EdwardsProjective);
+
+/// A scalar or a Python integer.
+#[derive(FromPyObject)]
+enum ScalarLike {
+    Scalar(Scalar),
+    Int(BigInt),
+}
+
+impl ScalarLike {
+    fn into_fr(self) -> Fr {
+        match self {
+            ScalarLike::Scalar(s) => s.0,
+            ScalarLike::Int(n) => fr_from_int(&n),
+        }
+    }
+}
+
+#[pymethods]
+impl Scalar {
+    /// The order `r` of the scalar field.
+    #[classattr]
+    #[allow(non_snake_case)]
+    fn MODULUS() -> BigUint {
+        modulus()
+    }
+
+    #[new]
+    fn new(value: BigInt) -> Self {
+        Self(fr_from_int(&value))
+    }
+
+    /// Decode a canonical little-endian scalar.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        Fr::from_bytes_le(array(bytes)?)
+            .map(Self)
+            .ok_or_else(|| PyValueError::new_err("non-canonical scalar"))
+    }
+
+    /// The canonical little-endian encoding.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.to_bytes_le())
+    }
+
+    /// The multiplicative inverse; raises `ZeroDivisionError` on zero.
+    fn inverse(&self) -> PyResult<Self> {
+        self.0
+            .inverse()
+            .map(Self)
+            .ok_or_else(|| PyZeroDivisionError::new_err("zero has no inverse"))
+    }
+
+    fn __int__(&self) -> BigUint {
+        BigUint::from_bytes_le(&self.0.to_bytes_le())
+    }
+
+    fn __add__(&self, other: ScalarLike) -> Self {
+        Self(self.0 + other.into_fr())
+    }
+
+    fn __radd__(&self, other: ScalarLike) -> Self {
+        self.__add__(other)
+    }
+
+    fn __sub__(&self, other: ScalarLike) -> Self {
+        Self(self.0 - other.into_fr())
+    }
+
+    fn __rsub__(&self, other: ScalarLike) -> Self {
+        Self(other.into_fr() - self.0)
+    }
+
+    fn __mul__(&self, other: ScalarLike) -> Self {
+        Self(self.0 * other.into_fr())
+    }
+
+    fn __rmul__(&self, other: ScalarLike) -> Self {
+        self.__mul__(other)
+    }
+
+    fn __truediv__(&self, other: ScalarLike) -> PyResult<Self> {
+        Ok(Self(self.0 * Self(other.into_fr()).inverse()?.0))
+    }
+
+    fn __pow__(&self, exponent: BigUint, _modulo: Option<Py<PyAny>>) -> Self {
+        Self(self.0.pow(exponent.to_u64_digits()))
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(-self.0)
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_bytes(&self.0.to_bytes_le())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Scalar({})", self.__int__())
+    }
+}
+
+#[pymethods]
+impl Point {
+    /// The generator of the prime order subgroup.
+    #[staticmethod]
+    fn generator() -> Self {
+        Self(EdwardsProjective::prime_subgroup_generator())
+    }
+
+    /// The identity.
+    #[staticmethod]
+    fn identity() -> Self {
+        Self(EdwardsProjective::zero())
+    }
+
+    /// Decode a compressed point, checking that it is in the prime order
+    /// subgroup.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        ValidatedPoint::from_compressed_bytes(array(bytes)?)
+            .map(|p| Self(p.into_affine().into_projective()))
+            .map_err(value_error)
+    }
+
+    /// Build a point from its affine coordinates, checking that it is in
+    /// the prime order subgroup.
+    #[staticmethod]
+    fn from_coordinates(x: BigUint, y: BigUint) -> PyResult<Self> {
+        let p = EdwardsAffine::new(fq_from_int(&x)?, fq_from_int(&y)?);
+        ValidatedPoint::new(p)
+            .map(|p| Self(p.into_affine().into_projective()))
+            .map_err(value_error)
+    }
+
+    /// The compressed encoding.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &to_compressed_bytes(&self.0.into_affine()))
+    }
+
+    /// The affine `x` coordinate.
+    #[getter]
+    fn x(&self) -> BigUint {
+        BigUint::from_bytes_le(&self.0.into_affine().x.to_bytes_le())
+    }
+
+    /// The affine `y` coordinate.
+    #[getter]
+    fn y(&self) -> BigUint {
+        BigUint::from_bytes_le(&self.0.into_affine().y.to_bytes_le())
+    }
+
+    fn __add__(&self, other: Point) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    fn __sub__(&self, other: Point) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(-self.0)
+    }
+
+    fn __mul__(&self, scalar: ScalarLike) -> Self {
+        Self(self.0.mul(scalar.into_fr().into_repr()))
+    }
+
+    fn __rmul__(&self, scalar: ScalarLike) -> Self {
+        self.__mul__(scalar)
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_bytes(&to_compressed_bytes(&self.0.into_affine()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Point({})", self.0.into_affine().hex())
+    }
+}
+
+/// `sum_i scalars[i] * points[i]`.
+#[pyfunction]
+fn msm(points: Vec<Point>, scalars: Vec<ScalarLike>) -> PyResult<Point> {
+    if points.len() != scalars.len() {
+        return Err(PyValueError::new_err(
+            "mismatched numbers of points and scalars",
+        ));
+    }
+    let points: Vec<EdwardsProjective> = points.iter().map(|p| p.0).collect();
+    let bases = EdwardsProjective::batch_normalization_into_affine(&points);
+    let scalars: Vec<Fr> = scalars.into_iter().map(|s| s.into_fr()).collect();
+    Ok(Point(msm_affine(&bases, &scalars)))
+}
+
+/// The `bandersnatch` Python module.
+#[pymodule]
+#[pyo3(name = "bandersnatch")]
+pub fn bandersnatch_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Scalar>()?;
+    m.add_class::<Point>()?;
+    m.add_function(wrap_pyfunction!(msm, m)?)?;
+    Ok(())
+}
+
+fn modulus() -> BigUint {
+    let modulus = <FrParameters as FpParameters>::MODULUS;
+    BigUint::from_slice(
+        &modulus
+            .0
+            .iter()
+            .flat_map(|limb| [*limb as u32, (*limb >> 32) as u32])
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn fr_from_int(n: &BigInt) -> Fr {
+    let (sign, bytes) = n.to_bytes_le();
+    let s = Fr::from_le_bytes_mod_order(&bytes);
+    if sign == Sign::Minus {
+        -s
+    } else {
+        s
+    }
+}
+
+fn fq_from_int(n: &BigUint) -> PyResult<Fq> {
+    let bytes = n.to_bytes_le();
+    let mut array = [0u8; 32];
+    if bytes.len() > array.len() {
+        return Err(PyValueError::new_err("non-canonical coordinate"));
+    }
+    array[..bytes.len()].copy_from_slice(&bytes);
+    Fq::from_bytes_le(&array)
+        .ok_or_else(|| PyValueError::new_err("non-canonical coordinate"))
+}
+
+fn array(bytes: &[u8]) -> PyResult<&[u8; COMPRESSED_SIZE]> {
+    use ark_std::convert::TryInto;
+    bytes
+        .try_into()
+        .map_err(|_| PyValueError::new_err("expected 32 bytes"))
+}
+
+fn value_error<E: ark_std::fmt::Display>(e: E) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,73 @@
+use super::*;
+use pyo3::{ffi::c_str, types::PyDict};
+
+fn run(code: &core::ffi::CStr) {
+    Python::initialize();
+    Python::attach(|py| {
+        let module = pyo3::wrap_pymodule!(bandersnatch_module)(py);
+        let globals = PyDict::new(py);
+        globals.set_item("bandersnatch", module).unwrap();
+        // (0, -1) is on the curve, but of order 2.
+        let low_order = EdwardsAffine::new(Fq::zero(), -Fq::from(1u64));
+        let low_order = PyBytes::new(py, &to_compressed_bytes(&low_order));
+        globals.set_item("low_order", low_order).unwrap();
+        py.run(code, Some(&globals), None).unwrap();
+    });
+}
+
+#[test]
+fn test_scalar() {
+    run(c_str!(
+        r#"
+Scalar = bandersnatch.Scalar
+r = Scalar.MODULUS
+a, b = Scalar(5), Scalar(-3)
+assert int(a + b) == 2 and int(b) == r - 3
+assert a - 7 == Scalar(-2) and 7 - a == Scalar(2)
+assert int(a * b) == r - 15 and 2 * a == Scalar(10)
+assert (a / b) * b == a and a * a.inverse() == Scalar(1)
+assert a ** 3 == Scalar(125) and -a == Scalar(r - 5)
+assert Scalar(r) == Scalar(0) and not Scalar(0) and a
+assert Scalar.from_bytes(b.to_bytes()) == b
+assert len({a, Scalar(5), b}) == 2
+for bad in [b"\x00" * 31, r.to_bytes(32, "little")]:
+    try:
+        Scalar.from_bytes(bad)
+        assert False
+    except ValueError:
+        pass
+try:
+    Scalar(0).inverse()
+    assert False
+except ZeroDivisionError:
+    pass
+"#
+    ));
+}
+
+#[test]
+fn test_point() {
+    run(c_str!(
+        r#"
+Point, Scalar, msm = bandersnatch.Point, bandersnatch.Scalar, bandersnatch.msm
+g, o = Point.generator(), Point.identity()
+assert g + o == g and g - g == o and not o and g
+assert Scalar(3) * g == g + g + g and g * 3 == 3 * g
+assert Scalar.MODULUS * g == o and -g == Scalar(-1) * g
+assert Point.from_bytes(g.to_bytes()) == g
+assert Point.from_coordinates(g.x, g.y) == g
+assert len({g, Point.generator(), o}) == 2
+p = Scalar(42) * g
+assert msm([p, g], [2, -Scalar(42)]) == p and msm([], []) == o
+for bad in [lambda: Point.from_bytes(b"\x00" * 31),
+            lambda: Point.from_bytes(low_order),
+            lambda: Point.from_coordinates(0, 2 ** 255),
+            lambda: msm([g], [])]:
+    try:
+        bad()
+        assert False
+    except ValueError:
+        pass
+"#
+    ));
+}
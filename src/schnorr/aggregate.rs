@@ -0,0 +1,142 @@
+use super::{
+    challenge, write_with_len, Signature, SignatureError, VerifyingKey,
+};
+use crate::{hash_to_curve::hash_to_scalar, msm, EdwardsAffine, Fr, Validate};
+use ark_ec::AffineCurve;
+use ark_ff::{to_bytes, ToBytes, Zero};
+use ark_std::vec::Vec;
+
+/// Domain separation tag of the aggregation coefficients.
+const AGGREGATE_DST: &[u8] = b"BANDERSNATCH-SCHNORR-V01-HALF-AGGREGATE";
+
+/// A half-aggregate of `n` Schnorr signatures: the `n` commitments `R_i`
+/// are kept, while the responses are combined into a single scalar
+/// `s = sum_i z_i s_i`. The coefficients `z_i` are hashed from all the
+/// keys, messages and commitments, following Chalkias et al.,
+/// "Non-interactive half-aggregation of EdDSA and variants of Schnorr
+/// signatures".
+///
+/// Aggregation does not check the individual signatures, only that their
+/// commitments are in the prime order subgroup; as verification checks it
+/// again, an aggregate verifies if and only if (with overwhelming
+/// probability) every signature it was built from does.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HalfAggregateSignature {
+    /// The commitments of the aggregated signatures, in order.
+    pub rs: Vec<EdwardsAffine>,
+    /// The aggregated response.
+    pub s: Fr,
+}
+
+impl HalfAggregateSignature {
+    /// Aggregate `sigs[i]`, a signature on `msgs[i]` under `keys[i]`;
+    /// fails if a commitment `R_i` is not in the prime order subgroup.
+    pub fn aggregate(
+        keys: &[VerifyingKey],
+        msgs: &[&[u8]],
+        sigs: &[Signature],
+    ) -> Result<Self, SignatureError> {
+        if keys.len() != msgs.len() || keys.len() != sigs.len() {
+            return Err(SignatureError::LengthMismatch);
+        }
+        let rs: Vec<EdwardsAffine> = sigs.iter().map(|sig| sig.r).collect();
+        check_commitments(&rs)?;
+        let zs = coefficients(keys, msgs, &rs);
+        let s = zs
+            .iter()
+            .zip(sigs)
+            .fold(Fr::zero(), |acc, (z, sig)| acc + *z * sig.s);
+        Ok(Self { rs, s })
+    }
+
+    /// The number of aggregated signatures.
+    pub fn len(&self) -> usize {
+        self.rs.len()
+    }
+
+    /// Whether no signature was aggregated.
+    pub fn is_empty(&self) -> bool {
+        self.rs.is_empty()
+    }
+
+    /// Verify the aggregate against `keys[i]` and `msgs[i]`, checking
+    /// `sum_i z_i R_i + sum_i z_i c_i P_i - s G = 0` with a single MSM,
+    /// and that every `R_i` is in the prime order subgroup.
+    pub fn verify(
+        &self,
+        keys: &[VerifyingKey],
+        msgs: &[&[u8]],
+    ) -> Result<(), SignatureError> {
+        let (bases, scalars) = self.terms(keys, msgs)?;
+        if msm(&bases, &scalars).is_zero() {
+            Ok(())
+        } else {
+            Err(SignatureError::InvalidSignature)
+        }
+    }
+
+    /// The terms of the MSM `sum_i z_i R_i + sum_i z_i c_i P_i - s G`,
+    /// which is zero if and only if the aggregate verifies, once the `R_i`
+    /// are checked to be in the prime order subgroup.
+    pub(crate) fn terms(
+        &self,
+        keys: &[VerifyingKey],
+        msgs: &[&[u8]],
+    ) -> Result<(Vec<EdwardsAffine>, Vec<Fr>), SignatureError> {
+        if keys.len() != msgs.len() || keys.len() != self.rs.len() {
+            return Err(SignatureError::LengthMismatch);
+        }
+        check_commitments(&self.rs)?;
+        let zs = coefficients(keys, msgs, &self.rs);
+
+        let n = keys.len();
+        let mut bases = Vec::with_capacity(2 * n + 1);
+        let mut scalars = Vec::with_capacity(2 * n + 1);
+        for ((key, msg), (r, z)) in
+            keys.iter().zip(msgs).zip(self.rs.iter().zip(zs))
+        {
+            let c = challenge(r, &key.point, msg);
+            bases.push(*r);
+            scalars.push(z);
+            bases.push(key.point);
+            scalars.push(z * c);
+        }
+        bases.push(EdwardsAffine::prime_subgroup_generator());
+        scalars.push(-self.s);
+        Ok((bases, scalars))
+    }
+}
+
+/// Check that the commitments are in the prime order subgroup: otherwise,
+/// their small order components could cancel out in the aggregate, or
+/// make it fail for signatures that verify on their own.
+fn check_commitments(rs: &[EdwardsAffine]) -> Result<(), SignatureError> {
+    if rs.iter().all(|r| r.validate().is_ok()) {
+        Ok(())
+    } else {
+        Err(SignatureError::InvalidSignature)
+    }
+}
+
+/// The aggregation coefficients `z_i = H(seed || i)`, where `seed` binds
+/// all the keys, messages and commitments.
+fn coefficients(
+    keys: &[VerifyingKey],
+    msgs: &[&[u8]],
+    rs: &[EdwardsAffine],
+) -> Vec<Fr> {
+    let mut transcript = Vec::new();
+    (keys.len() as u64).write(&mut transcript).unwrap();
+    for ((key, msg), r) in keys.iter().zip(msgs).zip(rs) {
+        transcript.extend_from_slice(&to_bytes![key.point, r].unwrap());
+        write_with_len(&mut transcript, msg);
+    }
+    let seed =
+        to_bytes![hash_to_scalar(AGGREGATE_DST, &[&transcript])].unwrap();
+
+    (0..keys.len() as u64)
+        .map(|i| {
+            hash_to_scalar(AGGREGATE_DST, &[&seed, &to_bytes![i].unwrap()])
+        })
+        .collect()
+}
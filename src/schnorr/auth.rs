@@ -0,0 +1,144 @@
+//! A challenge-response authentication protocol.
+//!
+//! The server sends a fresh random [`Challenge`]; the client answers with
+//! a [`Response`], a signature on the challenge and on a channel binding
+//! value, e.g., a hash of the TLS session or of a pairing code displayed
+//! on both devices. The server accepts if the signature verifies under the
+//! expected key, for the challenge it sent and its own view of the channel
+//! binding.
+//!
+//! Both sides are one-shot state machines: [`ServerHandshake::verify`] and
+//! [`ClientHandshake::respond`] consume the handshake, so that a challenge
+//! cannot be answered or accepted twice.
+
+use super::{
+    write_with_len, Signature, SignatureError, SigningKey, VerifyingKey,
+};
+use crate::{EdwardsAffine, Fr, Validate};
+use ark_ff::{FromBytes, ToBytes};
+use ark_std::{rand::Rng, vec::Vec};
+
+/// Domain separation tag of the signed authentication messages.
+const AUTH_DST: &[u8] = b"BANDERSNATCH-SCHNORR-V01-AUTH";
+
+/// The size of a challenge, in bytes.
+pub const CHALLENGE_SIZE: usize = 32;
+
+/// The size of a serialized response, in bytes.
+pub const RESPONSE_SIZE: usize = 96;
+
+/// The random nonce sent by the server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Challenge(pub [u8; CHALLENGE_SIZE]);
+
+/// The answer of the client: a signature on the challenge and the channel
+/// binding value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Response(pub Signature);
+
+/// The server side of a handshake, which holds the challenge it sent.
+#[derive(Debug)]
+pub struct ServerHandshake {
+    challenge: Challenge,
+}
+
+/// The client side of a handshake, which holds the key to authenticate
+/// with and the channel binding value.
+#[derive(Clone, Debug)]
+pub struct ClientHandshake<'a> {
+    key: &'a SigningKey,
+    channel_binding: &'a [u8],
+}
+
+impl Challenge {
+    /// Sample a fresh challenge.
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut nonce = [0u8; CHALLENGE_SIZE];
+        rng.fill_bytes(&mut nonce);
+        Self(nonce)
+    }
+}
+
+impl Response {
+    /// Serialize the response as the commitment `R` and the response `s`.
+    pub fn to_bytes(&self) -> [u8; RESPONSE_SIZE] {
+        let mut bytes = Vec::with_capacity(RESPONSE_SIZE);
+        self.0.r.write(&mut bytes).unwrap();
+        self.0.s.write(&mut bytes).unwrap();
+        let mut out = [0u8; RESPONSE_SIZE];
+        out.copy_from_slice(&bytes);
+        out
+    }
+
+    /// Parse a response serialized with [`Response::to_bytes`], rejecting
+    /// commitments outside of the prime order subgroup and non-canonical
+    /// scalars.
+    pub fn from_bytes(
+        bytes: &[u8; RESPONSE_SIZE],
+    ) -> Result<Self, SignatureError> {
+        let mut reader = &bytes[..];
+        let r = EdwardsAffine::read(&mut reader)
+            .map_err(|_| SignatureError::InvalidEncoding)?;
+        if r.validate().is_err() {
+            return Err(SignatureError::InvalidEncoding);
+        }
+        let s = Fr::read(&mut reader)
+            .map_err(|_| SignatureError::InvalidEncoding)?;
+        Ok(Self(Signature { r, s }))
+    }
+}
+
+impl ServerHandshake {
+    /// Start a handshake; the returned challenge is sent to the client.
+    pub fn new<R: Rng + ?Sized>(rng: &mut R) -> (Self, Challenge) {
+        let challenge = Challenge::generate(rng);
+        (Self { challenge }, challenge)
+    }
+
+    /// The challenge sent to the client.
+    pub fn challenge(&self) -> &Challenge {
+        &self.challenge
+    }
+
+    /// Check that the client holds the secret of `key`, and that it
+    /// answered this handshake over the channel identified by
+    /// `channel_binding`.
+    pub fn verify(
+        self,
+        key: &VerifyingKey,
+        channel_binding: &[u8],
+        response: &Response,
+    ) -> Result<(), SignatureError> {
+        key.verify(&auth_message(&self.challenge, channel_binding), &response.0)
+    }
+}
+
+impl<'a> ClientHandshake<'a> {
+    /// Prepare to authenticate with `key` over the channel identified by
+    /// `channel_binding`.
+    pub fn new(key: &'a SigningKey, channel_binding: &'a [u8]) -> Self {
+        Self {
+            key,
+            channel_binding,
+        }
+    }
+
+    /// Answer the challenge of the server.
+    pub fn respond(self, challenge: &Challenge) -> Response {
+        Response(
+            self.key
+                .sign(&auth_message(challenge, self.channel_binding)),
+        )
+    }
+}
+
+/// The signed message `DST || challenge || len(binding) || binding`.
+fn auth_message(challenge: &Challenge, channel_binding: &[u8]) -> Vec<u8> {
+    let mut msg = Vec::with_capacity(
+        AUTH_DST.len() + CHALLENGE_SIZE + 8 + channel_binding.len(),
+    );
+    msg.extend_from_slice(AUTH_DST);
+    msg.extend_from_slice(&challenge.0);
+    write_with_len(&mut msg, channel_binding);
+    msg
+}
@@ -0,0 +1,193 @@
+use super::{HalfAggregateSignature, Signature, SignatureError, VerifyingKey};
+use crate::{BatchVerifier, EdwardsAffine, Fr, Validate};
+use ark_ff::{FromBytes, ToBytes, Zero};
+use ark_std::{rand::Rng, vec, vec::Vec};
+
+/// The signatures of a subset of a committee on a common message, stored
+/// as the bitmap of the signers and the half-aggregate of their
+/// signatures, as committee attestations are.
+///
+/// Bit `i` of the bitmap, i.e., bit `i % 8` of byte `i / 8`, is set if the
+/// `i`-th member of the committee signed; the commitments of the
+/// aggregate are in the order of the signers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregateSignatureWithBitmap {
+    committee_size: usize,
+    bitmap: Vec<u8>,
+    aggregate: HalfAggregateSignature,
+}
+
+impl AggregateSignatureWithBitmap {
+    /// Aggregate the signatures of a committee on `msg`, where `sigs[i]`
+    /// is the signature of `committee[i]`, or `None` if it did not sign.
+    pub fn aggregate(
+        committee: &[VerifyingKey],
+        msg: &[u8],
+        sigs: &[Option<Signature>],
+    ) -> Result<Self, SignatureError> {
+        if committee.len() != sigs.len() {
+            return Err(SignatureError::LengthMismatch);
+        }
+        let mut bitmap = vec![0u8; committee.len().div_ceil(8)];
+        let mut keys = Vec::new();
+        let mut signatures = Vec::new();
+        for (i, (key, sig)) in committee.iter().zip(sigs).enumerate() {
+            if let Some(sig) = sig {
+                bitmap[i / 8] |= 1 << (i % 8);
+                keys.push(key.clone());
+                signatures.push(*sig);
+            }
+        }
+        let msgs = vec![msg; keys.len()];
+        let aggregate =
+            HalfAggregateSignature::aggregate(&keys, &msgs, &signatures)?;
+        Ok(Self {
+            committee_size: committee.len(),
+            bitmap,
+            aggregate,
+        })
+    }
+
+    /// The size of the committee.
+    pub fn committee_size(&self) -> usize {
+        self.committee_size
+    }
+
+    /// The bitmap of the signers.
+    pub fn bitmap(&self) -> &[u8] {
+        &self.bitmap
+    }
+
+    /// Whether the `i`-th member of the committee signed.
+    pub fn is_signer(&self, i: usize) -> bool {
+        i < self.committee_size && self.bitmap[i / 8] & (1 << (i % 8)) != 0
+    }
+
+    /// The indices of the signers, in increasing order.
+    pub fn signers(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.committee_size).filter(move |&i| self.is_signer(i))
+    }
+
+    /// The number of signers.
+    pub fn num_signers(&self) -> usize {
+        self.aggregate.len()
+    }
+
+    /// The half-aggregate of the signatures of the signers.
+    pub fn aggregate_signature(&self) -> &HalfAggregateSignature {
+        &self.aggregate
+    }
+
+    /// Verify the aggregate against the committee and the message.
+    pub fn verify(
+        &self,
+        committee: &[VerifyingKey],
+        msg: &[u8],
+    ) -> Result<(), SignatureError> {
+        let (keys, msgs) = self.signer_keys(committee, msg)?;
+        self.aggregate.verify(&keys, &msgs)
+    }
+
+    /// Verify `aggregates[i]` against `committees[i]` and `msgs[i]` for all
+    /// `i` at once with a [`BatchVerifier`].
+    pub fn verify_batch<R: Rng + ?Sized>(
+        aggregates: &[Self],
+        committees: &[&[VerifyingKey]],
+        msgs: &[&[u8]],
+        rng: &mut R,
+    ) -> Result<(), SignatureError> {
+        if aggregates.len() != committees.len()
+            || aggregates.len() != msgs.len()
+        {
+            return Err(SignatureError::LengthMismatch);
+        }
+        let mut batch = BatchVerifier::new();
+        for ((aggregate, committee), msg) in
+            aggregates.iter().zip(committees).zip(msgs)
+        {
+            let (keys, msgs) = aggregate.signer_keys(committee, msg)?;
+            let (bases, scalars) = aggregate.aggregate.terms(&keys, &msgs)?;
+            batch.queue(&bases, &scalars, EdwardsAffine::zero());
+        }
+        if batch.verify(rng) {
+            Ok(())
+        } else {
+            Err(SignatureError::InvalidSignature)
+        }
+    }
+
+    /// Serialize the aggregate as the size of the committee (`u32`,
+    /// little-endian), the bitmap, the commitments and the aggregated
+    /// response.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            4 + self.bitmap.len() + 64 * self.aggregate.len() + 32,
+        );
+        (self.committee_size as u32).write(&mut bytes).unwrap();
+        bytes.extend_from_slice(&self.bitmap);
+        for r in &self.aggregate.rs {
+            r.write(&mut bytes).unwrap();
+        }
+        self.aggregate.s.write(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// Parse an aggregate serialized with
+    /// [`AggregateSignatureWithBitmap::to_bytes`], rejecting bitmaps with
+    /// bits set past the size of the committee, commitments outside of the
+    /// prime order subgroup, and trailing bytes.
+    ///
+    /// All the lengths are checked against the size of the input before
+    /// anything is allocated.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, SignatureError> {
+        let committee_size = u32::read(&mut bytes)
+            .map_err(|_| SignatureError::InvalidEncoding)?
+            as usize;
+        let bitmap_len = committee_size.div_ceil(8);
+        if bytes.len() < bitmap_len {
+            return Err(SignatureError::InvalidEncoding);
+        }
+        let (bitmap, mut bytes) = bytes.split_at(bitmap_len);
+        let tail = committee_size % 8;
+        if tail != 0 && bitmap[bitmap_len - 1] >> tail != 0 {
+            return Err(SignatureError::InvalidEncoding);
+        }
+        let num_signers: usize =
+            bitmap.iter().map(|b| b.count_ones() as usize).sum();
+        if bytes.len() != 64 * num_signers + 32 {
+            return Err(SignatureError::InvalidEncoding);
+        }
+
+        let mut rs = Vec::with_capacity(num_signers);
+        for _ in 0..num_signers {
+            let r = EdwardsAffine::read(&mut bytes)
+                .map_err(|_| SignatureError::InvalidEncoding)?;
+            if r.validate().is_err() {
+                return Err(SignatureError::InvalidEncoding);
+            }
+            rs.push(r);
+        }
+        let s = Fr::read(&mut bytes)
+            .map_err(|_| SignatureError::InvalidEncoding)?;
+        Ok(Self {
+            committee_size,
+            bitmap: bitmap.to_vec(),
+            aggregate: HalfAggregateSignature { rs, s },
+        })
+    }
+
+    /// The keys of the signers, and as many copies of the message.
+    fn signer_keys<'a>(
+        &self,
+        committee: &[VerifyingKey],
+        msg: &'a [u8],
+    ) -> Result<(Vec<VerifyingKey>, Vec<&'a [u8]>), SignatureError> {
+        if committee.len() != self.committee_size {
+            return Err(SignatureError::LengthMismatch);
+        }
+        let keys: Vec<VerifyingKey> =
+            self.signers().map(|i| committee[i].clone()).collect();
+        let msgs = vec![msg; keys.len()];
+        Ok((keys, msgs))
+    }
+}
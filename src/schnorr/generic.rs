@@ -0,0 +1,102 @@
+//! Schnorr signatures over any [`PrimeOrderGroup`].
+//!
+//! This is the definition of the scheme of the parent module, with the
+//! challenge and the nonces hashed to the scalar field of the group:
+//! [`SigningKey`] instantiates it with [`ValidatedPoint`], and verifies
+//! with the tuned multiplications of bandersnatch, with the precomputations
+//! of the keys cached.
+//!
+//! The secret key and the nonces are only multiplied with
+//! [`PrimeOrderGroup::mul_secret`], in constant time.
+//!
+//! [`SigningKey`]: super::SigningKey
+//! [`ValidatedPoint`]: crate::ValidatedPoint
+
+use super::{SignatureError, CHALLENGE_DST, NONCE_DST};
+use crate::{hash_to_curve::hash_to_prime_field, wipe_bytes, PrimeOrderGroup};
+use ark_ff::{to_bytes, PrimeField, Zero};
+use ark_std::vec::Vec;
+
+/// A Schnorr signature `(R, s)` over the group `G`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GenericSignature<G: PrimeOrderGroup> {
+    /// The commitment to the nonce, `R = r G`.
+    pub r: G,
+    /// The response, `s = r + c x`.
+    pub s: G::Scalar,
+}
+
+/// The public key `x G` of the secret scalar `x`.
+pub fn public_key<G: PrimeOrderGroup>(secret: &G::Scalar) -> G {
+    G::generator().mul_secret(secret)
+}
+
+/// Sign a message with the secret scalar `secret`; returns `None` if the
+/// scalar is zero.
+pub fn sign<G: PrimeOrderGroup>(
+    secret: &G::Scalar,
+    msg: &[u8],
+) -> Option<GenericSignature<G>> {
+    if secret.is_zero() {
+        return None;
+    }
+    Some(sign_with_public_key(secret, &public_key(secret), msg))
+}
+
+/// Sign a message with the non-zero secret scalar `secret`, whose public
+/// key is `public_key`.
+pub(crate) fn sign_with_public_key<G: PrimeOrderGroup>(
+    secret: &G::Scalar,
+    public_key: &G,
+    msg: &[u8],
+) -> GenericSignature<G> {
+    let mut secret_bytes = to_bytes![secret.into_repr()].unwrap();
+    let mut nonce: G::Scalar =
+        hash_to_prime_field(NONCE_DST, &[&secret_bytes, msg]);
+    wipe_bytes(&mut secret_bytes);
+    let r = G::generator().mul_secret(&nonce);
+    let c = challenge(&r, public_key, msg);
+    let s = nonce + c * secret;
+    G::wipe_scalar(&mut nonce);
+    GenericSignature { r, s }
+}
+
+/// Verify a signature on a message under `public_key`, which must not be
+/// the identity.
+pub fn verify<G: PrimeOrderGroup>(
+    public_key: &G,
+    msg: &[u8],
+    sig: &GenericSignature<G>,
+) -> Result<(), SignatureError> {
+    if public_key.is_identity() {
+        return Err(SignatureError::InvalidPublicKey);
+    }
+    let c = challenge(&sig.r, public_key, msg);
+    // R =? s G - c P
+    if G::mul_by_generator(&sig.s) - public_key.mul(&c) == sig.r {
+        Ok(())
+    } else {
+        Err(SignatureError::InvalidSignature)
+    }
+}
+
+/// The challenge `c = H(R || P || m)`.
+fn challenge<G: PrimeOrderGroup>(
+    r: &G,
+    public_key: &G,
+    msg: &[u8],
+) -> G::Scalar {
+    let mut points = Vec::with_capacity(2 * G::ENCODED_SIZE);
+    points.extend_from_slice(&r.to_bytes());
+    points.extend_from_slice(&public_key.to_bytes());
+    challenge_from_encodings(&points, msg)
+}
+
+/// The challenge `c = H(R || P || m)`, from the concatenated encodings of
+/// `R` and `P`.
+pub(crate) fn challenge_from_encodings<F: PrimeField>(
+    points: &[u8],
+    msg: &[u8],
+) -> F {
+    hash_to_prime_field(CHALLENGE_DST, &[points, msg])
+}
@@ -0,0 +1,113 @@
+use super::{SigningKey, VerifyingKey};
+use crate::{EdwardsAffine, EdwardsParameters, Fr, GLVParameters, LAMBDA};
+use ark_ec::AffineCurve;
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_std::fmt;
+
+/// Secret scalars `s` with `s` or `-s` below `2^SMALL_SCALAR_BITS` are
+/// weak: they can be found by a brute force search.
+const SMALL_SCALAR_BITS: u32 = 64;
+
+/// The reasons for which a key fails a health check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyHealthError {
+    /// The secret scalar is zero.
+    ZeroScalar,
+    /// The secret scalar or its negation is small, e.g., `1`, `-1`, or the
+    /// near-modulus encoding `r - k` of a small `-k`.
+    SmallScalar,
+    /// The secret scalar is `lambda` or `-lambda`, the eigenvalue of the
+    /// endomorphism.
+    EndomorphismScalar,
+    /// The public key is the identity.
+    Identity,
+    /// The public key is not on the curve.
+    NotOnCurve,
+    /// The public key is a point of small order.
+    SmallOrder,
+    /// The public key is not in the prime order subgroup.
+    NotInSubgroup,
+    /// The public key is `+-G` or `+-lambda G`, whose secret is known.
+    KnownSecret,
+}
+
+impl fmt::Display for KeyHealthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyHealthError::ZeroScalar => write!(f, "zero secret scalar"),
+            KeyHealthError::SmallScalar => write!(f, "small secret scalar"),
+            KeyHealthError::EndomorphismScalar => {
+                write!(f, "secret scalar is an eigenvalue of the endomorphism")
+            }
+            KeyHealthError::Identity => write!(f, "public key is the identity"),
+            KeyHealthError::NotOnCurve => {
+                write!(f, "public key is not on the curve")
+            }
+            KeyHealthError::SmallOrder => {
+                write!(f, "public key has a small order")
+            }
+            KeyHealthError::NotInSubgroup => {
+                write!(f, "public key is not in the prime order subgroup")
+            }
+            KeyHealthError::KnownSecret => {
+                write!(f, "public key has a known secret")
+            }
+        }
+    }
+}
+
+/// Check that a secret scalar is not trivially recoverable from its public
+/// key: it must not be zero, small (in absolute value), or `+-lambda`.
+pub fn check_secret_scalar(secret: &Fr) -> Result<(), KeyHealthError> {
+    if secret.is_zero() {
+        return Err(KeyHealthError::ZeroScalar);
+    }
+    if secret.into_repr().num_bits() <= SMALL_SCALAR_BITS
+        || (-*secret).into_repr().num_bits() <= SMALL_SCALAR_BITS
+    {
+        return Err(KeyHealthError::SmallScalar);
+    }
+    if *secret == LAMBDA || *secret == -LAMBDA {
+        return Err(KeyHealthError::EndomorphismScalar);
+    }
+    Ok(())
+}
+
+/// Check that a public key is a point of the prime order subgroup other
+/// than the identity, and that its secret is not one of the known scalars
+/// `+-1` and `+-lambda`.
+pub fn check_public_key(point: &EdwardsAffine) -> Result<(), KeyHealthError> {
+    if point.is_zero() {
+        return Err(KeyHealthError::Identity);
+    }
+    if !point.is_on_curve() {
+        return Err(KeyHealthError::NotOnCurve);
+    }
+    if point.mul_by_cofactor().is_zero() {
+        return Err(KeyHealthError::SmallOrder);
+    }
+    if !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(KeyHealthError::NotInSubgroup);
+    }
+    let g = EdwardsAffine::prime_subgroup_generator();
+    let lambda_g = EdwardsParameters::endomorphism(&g);
+    if [g, -g, lambda_g, -lambda_g].contains(point) {
+        return Err(KeyHealthError::KnownSecret);
+    }
+    Ok(())
+}
+
+impl SigningKey {
+    /// Whether the secret scalar fails [`check_secret_scalar`]. Such keys
+    /// should be rejected on import.
+    pub fn is_weak(&self) -> bool {
+        check_secret_scalar(&self.secret).is_err()
+    }
+}
+
+impl VerifyingKey {
+    /// Run [`check_public_key`] on this key.
+    pub fn health_check(&self) -> Result<(), KeyHealthError> {
+        check_public_key(&self.point)
+    }
+}
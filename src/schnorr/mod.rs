@@ -0,0 +1,312 @@
+//! This module implements Schnorr signatures over the prime order subgroup
+//! of bandersnatch.
+//!
+//! A signature on a message `m` under the key `P = x G` is a pair `(R, s)`
+//! with `s G = R + c P`, where the challenge `c = H(R || P || m)` is hashed
+//! to `Fr` with `expand_message_xmd` (SHA-256). Nonces are derived
+//! deterministically from the secret key and the message.
+//!
+//! Signatures can be half-aggregated, see [`HalfAggregateSignature`], or
+//! batch verified, see [`verify_batch`]. The signatures of a committee are
+//! stored compactly as an [`AggregateSignatureWithBitmap`].
+//!
+//! The [`auth`] module builds a challenge-response authentication protocol
+//! on top of the signatures.
+//!
+//! A [`VrfSignature`] is a VRF proof under a signing key that is also a
+//! signature on a message.
+//!
+//! The scheme is defined over any [`PrimeOrderGroup`](crate::PrimeOrderGroup)
+//! in the [`generic`] module, which [`SigningKey`] instantiates with
+//! [`ValidatedPoint`].
+
+mod aggregate;
+pub mod auth;
+mod bitmap;
+pub mod generic;
+mod health;
+mod vrf;
+
+#[cfg(test)]
+mod tests;
+
+pub use aggregate::HalfAggregateSignature;
+pub use bitmap::AggregateSignatureWithBitmap;
+pub use health::{check_public_key, check_secret_scalar, KeyHealthError};
+pub use vrf::{VrfSignature, VRF_OUTPUT_SIZE};
+
+use crate::{
+    double_base_mul, mul_by_generator, BatchVerifier, CombTable, EdwardsAffine,
+    Fr, Validate, ValidatedPoint,
+};
+use alloc::sync::Arc;
+use ark_ec::AffineCurve;
+use ark_ff::{to_bytes, ToBytes, Zero};
+use ark_std::{fmt, rand::Rng, vec::Vec, UniformRand};
+
+/// Domain separation tag of the challenge hash.
+const CHALLENGE_DST: &[u8] = b"BANDERSNATCH-SCHNORR-V01-CHALLENGE";
+
+/// Domain separation tag of the nonce derivation.
+const NONCE_DST: &[u8] = b"BANDERSNATCH-SCHNORR-V01-NONCE";
+
+/// The number of teeth of the comb precomputed for a verifying key.
+const VERIFYING_KEY_COMB_TEETH: usize = 8;
+
+/// Errors that can occur while verifying Schnorr signatures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The signature does not verify.
+    InvalidSignature,
+    /// The public key is not a non-identity point of the prime order subgroup.
+    InvalidPublicKey,
+    /// The numbers of keys, messages and signatures do not match.
+    LengthMismatch,
+    /// A serialized signature is malformed.
+    InvalidEncoding,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureError::InvalidSignature => write!(f, "invalid signature"),
+            SignatureError::InvalidPublicKey => write!(f, "invalid public key"),
+            SignatureError::LengthMismatch => {
+                write!(f, "mismatched numbers of keys, messages and signatures")
+            }
+            SignatureError::InvalidEncoding => {
+                write!(f, "invalid signature encoding")
+            }
+        }
+    }
+}
+
+/// A Schnorr secret key.
+///
+/// The secret scalar and the nonces are multiplied with the constant time
+/// [`ladder_mul`](crate::ladder_mul), through [`generic`]; the comb table
+/// of [`mul_by_generator`], which runs in variable time, only serves
+/// verification.
+#[derive(Clone)]
+pub struct SigningKey {
+    secret: Fr,
+    verifying_key: VerifyingKey,
+}
+
+/// A Schnorr public key, i.e., a non-identity point of the prime order
+/// subgroup.
+///
+/// A key can carry a precomputed comb table, see
+/// [`VerifyingKey::with_precomputation`]; the table is shared between
+/// clones of the key.
+#[derive(Clone)]
+pub struct VerifyingKey {
+    point: EdwardsAffine,
+    table: Option<Arc<CombTable>>,
+}
+
+/// A Schnorr signature `(R, s)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    /// The commitment to the nonce, `R = r G`.
+    pub r: EdwardsAffine,
+    /// The response, `s = r + c x`.
+    pub s: Fr,
+}
+
+impl fmt::Debug for VerifyingKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerifyingKey")
+            .field("point", &self.point)
+            .field("precomputed", &self.table.is_some())
+            .finish()
+    }
+}
+
+impl PartialEq for VerifyingKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl Eq for VerifyingKey {}
+
+/// Erase the secret scalar; the verifying key is public.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SigningKey {
+    fn zeroize(&mut self) {
+        crate::wipe(&mut self.secret);
+    }
+}
+
+/// Signing keys are erased on drop with the `zeroize` feature.
+#[cfg(feature = "zeroize")]
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        crate::wipe(&mut self.secret);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SigningKey {}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("verifying_key", &self.verifying_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SigningKey {
+    /// Sample a fresh signing key.
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let secret = Fr::rand(rng);
+            if let Some(key) = Self::from_scalar(secret) {
+                return key;
+            }
+        }
+    }
+
+    /// Build a signing key from a secret scalar; returns `None` if the
+    /// scalar is zero.
+    pub fn from_scalar(secret: Fr) -> Option<Self> {
+        if secret.is_zero() {
+            return None;
+        }
+        let point =
+            generic::public_key::<ValidatedPoint>(&secret).into_affine();
+        Some(Self {
+            secret,
+            verifying_key: VerifyingKey { point, table: None },
+        })
+    }
+
+    /// The secret scalar.
+    pub fn as_scalar(&self) -> &Fr {
+        &self.secret
+    }
+
+    /// The matching public key.
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Sign a message.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        let sig = generic::sign_with_public_key(
+            &self.secret,
+            &ValidatedPoint::new_unchecked(self.verifying_key.point),
+            msg,
+        );
+        Signature {
+            r: sig.r.into_affine(),
+            s: sig.s,
+        }
+    }
+}
+
+impl VerifyingKey {
+    /// Build a public key from a point, checking that it is a non-identity
+    /// point of the prime order subgroup.
+    pub fn from_affine(point: EdwardsAffine) -> Result<Self, SignatureError> {
+        if point.is_zero() || point.validate().is_err() {
+            return Err(SignatureError::InvalidPublicKey);
+        }
+        Ok(Self { point, table: None })
+    }
+
+    /// Precompute a comb table for this key, so that every subsequent
+    /// verification replaces the variable-base multiplication `c P` with
+    /// a much cheaper fixed-base one. This pays off for keys that verify
+    /// many signatures.
+    pub fn with_precomputation(mut self) -> Self {
+        if self.table.is_none() {
+            self.table = Some(Arc::new(CombTable::new(
+                &self.point,
+                VERIFYING_KEY_COMB_TEETH,
+            )));
+        }
+        self
+    }
+
+    /// Whether a comb table was precomputed for this key.
+    pub fn has_precomputation(&self) -> bool {
+        self.table.is_some()
+    }
+
+    /// The underlying point.
+    pub fn as_affine(&self) -> &EdwardsAffine {
+        &self.point
+    }
+
+    /// Verify a signature on a message.
+    pub fn verify(
+        &self,
+        msg: &[u8],
+        sig: &Signature,
+    ) -> Result<(), SignatureError> {
+        let c = challenge(&sig.r, &self.point, msg);
+        // R =? s G - c P
+        let r = match &self.table {
+            Some(table) => mul_by_generator(&sig.s) - table.mul(&c),
+            None => double_base_mul(
+                &sig.s,
+                &EdwardsAffine::prime_subgroup_generator(),
+                &-c,
+                &self.point,
+            ),
+        };
+        if r == sig.r.into_projective() {
+            Ok(())
+        } else {
+            Err(SignatureError::InvalidSignature)
+        }
+    }
+}
+
+/// Verify `sigs[i]`, a signature on `msgs[i]` under `keys[i]`, for all
+/// `i` at once with a [`BatchVerifier`], which is much faster than
+/// verifying them one by one.
+///
+/// A signature whose commitment `R` only differs from a valid one by a
+/// point of small order may be accepted, although [`VerifyingKey::verify`]
+/// rejects it.
+pub fn verify_batch<R: Rng + ?Sized>(
+    keys: &[VerifyingKey],
+    msgs: &[&[u8]],
+    sigs: &[Signature],
+    rng: &mut R,
+) -> Result<(), SignatureError> {
+    if keys.len() != msgs.len() || keys.len() != sigs.len() {
+        return Err(SignatureError::LengthMismatch);
+    }
+    let g = EdwardsAffine::prime_subgroup_generator();
+    let mut batch = BatchVerifier::new();
+    for ((key, msg), sig) in keys.iter().zip(msgs).zip(sigs) {
+        // s G - c P == R
+        let c = challenge(&sig.r, &key.point, msg);
+        batch.queue(&[g, key.point], &[sig.s, -c], sig.r);
+    }
+    if batch.verify(rng) {
+        Ok(())
+    } else {
+        Err(SignatureError::InvalidSignature)
+    }
+}
+
+/// The challenge `c = H(R || P || m)`.
+pub(crate) fn challenge(
+    r: &EdwardsAffine,
+    public_key: &EdwardsAffine,
+    msg: &[u8],
+) -> Fr {
+    generic::challenge_from_encodings(&to_bytes![r, public_key].unwrap(), msg)
+}
+
+/// Append `msg` prefixed with its length to `out`.
+pub(crate) fn write_with_len(out: &mut Vec<u8>, msg: &[u8]) {
+    (msg.len() as u64).write(&mut *out).unwrap();
+    out.extend_from_slice(msg);
+}
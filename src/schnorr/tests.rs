@@ -0,0 +1,541 @@
+use super::*;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::One;
+use ark_std::{test_rng, vec::Vec};
+
+#[test]
+fn test_sign_verify() {
+    let mut rng = test_rng();
+    let key = SigningKey::generate(&mut rng);
+    let vk = key.verifying_key();
+
+    let sig = key.sign(b"message");
+    assert_eq!(vk.verify(b"message", &sig), Ok(()));
+    assert_eq!(key.sign(b"message"), sig);
+    assert_eq!(
+        vk.verify(b"other message", &sig),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    let mut bad = sig;
+    bad.s += Fr::one();
+    assert_eq!(
+        vk.verify(b"message", &bad),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    let other = SigningKey::generate(&mut rng);
+    assert_eq!(
+        other.verifying_key().verify(b"message", &sig),
+        Err(SignatureError::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_keys() {
+    assert!(SigningKey::from_scalar(Fr::zero()).is_none());
+    let key = SigningKey::from_scalar(Fr::one()).unwrap();
+    assert_eq!(
+        key.verifying_key().as_affine(),
+        &EdwardsAffine::prime_subgroup_generator()
+    );
+    assert_eq!(
+        VerifyingKey::from_affine(*key.verifying_key().as_affine()),
+        Ok(key.verifying_key().clone())
+    );
+    assert_eq!(
+        VerifyingKey::from_affine(EdwardsAffine::zero()),
+        Err(SignatureError::InvalidPublicKey)
+    );
+}
+
+#[test]
+fn test_half_aggregate() {
+    let mut rng = test_rng();
+    let signers: Vec<SigningKey> =
+        (0..8).map(|_| SigningKey::generate(&mut rng)).collect();
+    let keys: Vec<VerifyingKey> =
+        signers.iter().map(|k| k.verifying_key().clone()).collect();
+    let msgs: Vec<Vec<u8>> =
+        (0..8u8).map(|i| ark_std::vec![i; i as usize]).collect();
+    let msgs: Vec<&[u8]> = msgs.iter().map(|m| m.as_slice()).collect();
+    let sigs: Vec<Signature> = signers
+        .iter()
+        .zip(msgs.iter())
+        .map(|(k, m)| k.sign(m))
+        .collect();
+
+    let agg = HalfAggregateSignature::aggregate(&keys, &msgs, &sigs).unwrap();
+    assert_eq!(agg.len(), 8);
+    assert_eq!(agg.verify(&keys, &msgs), Ok(()));
+
+    // swapping two messages breaks verification
+    let mut swapped = msgs.clone();
+    swapped.swap(0, 1);
+    assert_eq!(
+        agg.verify(&keys, &swapped),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    // an invalid signature invalidates the aggregate
+    let mut bad_sigs = sigs.clone();
+    bad_sigs[3].s += Fr::one();
+    let bad =
+        HalfAggregateSignature::aggregate(&keys, &msgs, &bad_sigs).unwrap();
+    assert_eq!(
+        bad.verify(&keys, &msgs),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    // two invalid signatures cannot cancel each other out
+    let mut bad_sigs = sigs.clone();
+    bad_sigs[0].s += Fr::one();
+    bad_sigs[1].s -= Fr::one();
+    let bad =
+        HalfAggregateSignature::aggregate(&keys, &msgs, &bad_sigs).unwrap();
+    assert_eq!(
+        bad.verify(&keys, &msgs),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    assert_eq!(
+        HalfAggregateSignature::aggregate(&keys[1..], &msgs, &sigs),
+        Err(SignatureError::LengthMismatch)
+    );
+    assert_eq!(
+        agg.verify(&keys[1..], &msgs[1..]),
+        Err(SignatureError::LengthMismatch)
+    );
+
+    // commitments with a small order component are rejected, even if
+    // the components cancel out
+    let t = EdwardsAffine::new(crate::Fq::zero(), -crate::Fq::one());
+    let mut torsion_sigs = sigs.clone();
+    torsion_sigs[0].r += t;
+    torsion_sigs[1].r += t;
+    assert_eq!(
+        HalfAggregateSignature::aggregate(&keys, &msgs, &torsion_sigs),
+        Err(SignatureError::InvalidSignature)
+    );
+    let mut torsion = agg.clone();
+    torsion.rs[0] += t;
+    torsion.rs[1] += t;
+    assert_eq!(
+        torsion.verify(&keys, &msgs),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    let empty = HalfAggregateSignature::aggregate(&[], &[], &[]).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(empty.verify(&[], &[]), Ok(()));
+}
+
+#[test]
+fn test_precomputed_verifying_key() {
+    let mut rng = test_rng();
+    let key = SigningKey::generate(&mut rng);
+    let vk = key.verifying_key().clone();
+    assert!(!vk.has_precomputation());
+
+    let precomputed = vk.clone().with_precomputation();
+    assert!(precomputed.has_precomputation());
+    assert_eq!(precomputed, vk);
+
+    for i in 0..10u8 {
+        let msg = [i; 4];
+        let sig = key.sign(&msg);
+        assert_eq!(precomputed.verify(&msg, &sig), Ok(()));
+        assert_eq!(
+            precomputed.verify(&[i; 5], &sig),
+            Err(SignatureError::InvalidSignature)
+        );
+    }
+}
+
+#[test]
+fn test_key_health() {
+    use crate::{EdwardsParameters, Fq, GLVParameters, LAMBDA};
+
+    let mut rng = test_rng();
+    let key = SigningKey::generate(&mut rng);
+    assert!(!key.is_weak());
+    assert_eq!(key.verifying_key().health_check(), Ok(()));
+
+    // lambda is the eigenvalue of the endomorphism
+    let g = EdwardsAffine::prime_subgroup_generator();
+    assert_eq!(
+        g.mul(LAMBDA).into_affine(),
+        EdwardsParameters::endomorphism(&g)
+    );
+
+    for (scalar, err) in [
+        (Fr::zero(), KeyHealthError::ZeroScalar),
+        (Fr::one(), KeyHealthError::SmallScalar),
+        (-Fr::one(), KeyHealthError::SmallScalar),
+        (Fr::from(u64::MAX), KeyHealthError::SmallScalar),
+        (-Fr::from(12345u64), KeyHealthError::SmallScalar),
+        (LAMBDA, KeyHealthError::EndomorphismScalar),
+        (-LAMBDA, KeyHealthError::EndomorphismScalar),
+    ] {
+        assert_eq!(check_secret_scalar(&scalar), Err(err));
+        if let Some(key) = SigningKey::from_scalar(scalar) {
+            assert!(key.is_weak());
+        }
+    }
+    assert_eq!(check_secret_scalar(&Fr::from(u64::MAX as u128 + 1)), Ok(()));
+
+    for scalar in [Fr::one(), -Fr::one(), LAMBDA, -LAMBDA] {
+        let key = SigningKey::from_scalar(scalar).unwrap();
+        assert_eq!(
+            key.verifying_key().health_check(),
+            Err(KeyHealthError::KnownSecret)
+        );
+    }
+
+    let two_torsion = EdwardsAffine::new(Fq::zero(), -Fq::one());
+    let p = key.verifying_key().as_affine();
+    for (point, err) in [
+        (EdwardsAffine::zero(), KeyHealthError::Identity),
+        (two_torsion, KeyHealthError::SmallOrder),
+        (*p + two_torsion, KeyHealthError::NotInSubgroup),
+        (EdwardsAffine::new(p.x, p.x), KeyHealthError::NotOnCurve),
+    ] {
+        assert_eq!(check_public_key(&point), Err(err));
+    }
+}
+
+#[test]
+fn test_verify_batch() {
+    let mut rng = test_rng();
+    let keys: Vec<SigningKey> =
+        (0..5).map(|_| SigningKey::generate(&mut rng)).collect();
+    let vks: Vec<VerifyingKey> =
+        keys.iter().map(|k| k.verifying_key().clone()).collect();
+    let msgs: Vec<&[u8]> = vec![b"a", b"b", b"c", b"d", b""];
+    let mut sigs: Vec<Signature> =
+        keys.iter().zip(&msgs).map(|(k, m)| k.sign(m)).collect();
+
+    assert_eq!(verify_batch(&vks, &msgs, &sigs, &mut rng), Ok(()));
+    assert_eq!(verify_batch(&[], &[], &[], &mut rng), Ok(()));
+    assert_eq!(
+        verify_batch(&vks[..1], &msgs[..1], &sigs[..1], &mut rng),
+        Ok(())
+    );
+    assert_eq!(
+        verify_batch(&vks, &msgs[1..], &sigs, &mut rng),
+        Err(SignatureError::LengthMismatch)
+    );
+
+    sigs.swap(0, 1);
+    assert_eq!(
+        verify_batch(&vks, &msgs, &sigs, &mut rng),
+        Err(SignatureError::InvalidSignature)
+    );
+    sigs.swap(0, 1);
+    sigs[4].s += Fr::one();
+    assert_eq!(
+        verify_batch(&vks, &msgs, &sigs, &mut rng),
+        Err(SignatureError::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_aggregate_with_bitmap() {
+    let mut rng = test_rng();
+    let keys: Vec<SigningKey> =
+        (0..11).map(|_| SigningKey::generate(&mut rng)).collect();
+    let committee: Vec<VerifyingKey> =
+        keys.iter().map(|k| k.verifying_key().clone()).collect();
+    let msg = b"attestation";
+    let sigs: Vec<Option<Signature>> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, k)| if i % 3 != 1 { Some(k.sign(msg)) } else { None })
+        .collect();
+
+    let agg = AggregateSignatureWithBitmap::aggregate(&committee, msg, &sigs)
+        .unwrap();
+    assert_eq!(agg.committee_size(), 11);
+    assert_eq!(agg.num_signers(), 7);
+    assert_eq!(agg.bitmap(), &[0b0110_1101, 0b0000_0011]);
+    assert_eq!(agg.signers().collect::<Vec<_>>(), vec![0, 2, 3, 5, 6, 8, 9]);
+    assert!(!agg.is_signer(1) && !agg.is_signer(11));
+    assert_eq!(agg.verify(&committee, msg), Ok(()));
+    assert_eq!(
+        agg.verify(&committee, b"other"),
+        Err(SignatureError::InvalidSignature)
+    );
+    assert_eq!(
+        agg.verify(&committee[1..], msg),
+        Err(SignatureError::LengthMismatch)
+    );
+    let mut swapped = committee.clone();
+    swapped.swap(0, 1);
+    assert_eq!(
+        agg.verify(&swapped, msg),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    // encoding
+    let bytes = agg.to_bytes();
+    assert_eq!(bytes.len(), 4 + 2 + 64 * 7 + 32);
+    assert_eq!(
+        AggregateSignatureWithBitmap::from_bytes(&bytes),
+        Ok(agg.clone())
+    );
+    for len in 0..bytes.len() {
+        assert_eq!(
+            AggregateSignatureWithBitmap::from_bytes(&bytes[..len]),
+            Err(SignatureError::InvalidEncoding)
+        );
+    }
+    let mut padded = bytes.clone();
+    padded[5] |= 0x80;
+    assert_eq!(
+        AggregateSignatureWithBitmap::from_bytes(&padded),
+        Err(SignatureError::InvalidEncoding)
+    );
+    let mut huge = bytes.clone();
+    huge[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(
+        AggregateSignatureWithBitmap::from_bytes(&huge),
+        Err(SignatureError::InvalidEncoding)
+    );
+
+    // batch verification
+    let empty = AggregateSignatureWithBitmap::aggregate(
+        &committee[..3],
+        b"other",
+        &[None, None, None],
+    )
+    .unwrap();
+    let aggs = [agg.clone(), empty];
+    let committees: [&[VerifyingKey]; 2] = [&committee, &committee[..3]];
+    assert_eq!(
+        AggregateSignatureWithBitmap::verify_batch(
+            &aggs,
+            &committees,
+            &[msg, b"other"],
+            &mut rng
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        AggregateSignatureWithBitmap::verify_batch(
+            &aggs,
+            &committees,
+            &[b"other", b"other"],
+            &mut rng
+        ),
+        Err(SignatureError::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_auth() {
+    use auth::*;
+
+    let mut rng = test_rng();
+    let key = SigningKey::generate(&mut rng);
+    let vk = key.verifying_key();
+
+    let (server, challenge) = ServerHandshake::new(&mut rng);
+    assert_eq!(server.challenge(), &challenge);
+    let response = ClientHandshake::new(&key, b"session").respond(&challenge);
+    let bytes = response.to_bytes();
+    assert_eq!(Response::from_bytes(&bytes), Ok(response));
+    assert_eq!(server.verify(vk, b"session", &response), Ok(()));
+
+    // wrong channel binding, key, or challenge
+    let (server, challenge) = ServerHandshake::new(&mut rng);
+    let response = ClientHandshake::new(&key, b"session").respond(&challenge);
+    assert_eq!(
+        server.verify(vk, b"other session", &response),
+        Err(SignatureError::InvalidSignature)
+    );
+    let (server, challenge) = ServerHandshake::new(&mut rng);
+    let other = SigningKey::generate(&mut rng);
+    let response = ClientHandshake::new(&other, b"session").respond(&challenge);
+    assert_eq!(
+        server.verify(vk, b"session", &response),
+        Err(SignatureError::InvalidSignature)
+    );
+    let (server, _) = ServerHandshake::new(&mut rng);
+    let response = ClientHandshake::new(&key, b"session").respond(&challenge);
+    assert_eq!(
+        server.verify(vk, b"session", &response),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    // a plain signature on the challenge is not a response
+    let (server, challenge) = ServerHandshake::new(&mut rng);
+    let plain = Response(key.sign(&challenge.0));
+    assert_eq!(
+        server.verify(vk, b"", &plain),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    let mut bad = bytes;
+    bad[0] ^= 1;
+    assert_eq!(
+        Response::from_bytes(&bad),
+        Err(SignatureError::InvalidEncoding)
+    );
+}
+
+#[test]
+fn test_generic_schnorr() {
+    use crate::{PrimeOrderGroup, ValidatedPoint};
+
+    let mut rng = test_rng();
+    let key = SigningKey::generate(&mut rng);
+    let msg = b"generic message";
+
+    // the generic scheme instantiated with the subgroup is the tuned one
+    let pk = generic::public_key::<ValidatedPoint>(key.as_scalar());
+    assert_eq!(pk.into_affine(), *key.verifying_key().as_affine());
+    let sig = generic::sign::<ValidatedPoint>(key.as_scalar(), msg).unwrap();
+    let expected = key.sign(msg);
+    assert_eq!(sig.r.into_affine(), expected.r);
+    assert_eq!(sig.s, expected.s);
+
+    assert_eq!(generic::verify(&pk, msg, &sig), Ok(()));
+    assert_eq!(
+        generic::verify(&pk, b"other message", &sig),
+        Err(SignatureError::InvalidSignature)
+    );
+    assert_eq!(
+        generic::verify(&ValidatedPoint::identity(), msg, &sig),
+        Err(SignatureError::InvalidPublicKey)
+    );
+    assert!(generic::sign::<ValidatedPoint>(&Fr::zero(), msg).is_none());
+
+    // the encoding rejects points outside of the prime order subgroup
+    let bytes = pk.to_bytes();
+    assert_eq!(bytes.len(), ValidatedPoint::ENCODED_SIZE);
+    assert_eq!(ValidatedPoint::from_bytes(&bytes), Some(pk));
+    let t = EdwardsAffine::new(crate::Fq::zero(), -crate::Fq::one());
+    let mut outside = Vec::new();
+    (*pk.as_affine() + t).write(&mut outside).unwrap();
+    assert!(ValidatedPoint::from_bytes(&outside).is_none());
+    assert!(ValidatedPoint::from_bytes(&bytes[1..]).is_none());
+
+    // the constant time multiplication agrees with the GLV one
+    let scalar: Fr = rng.gen();
+    assert_eq!(pk.mul_secret(&scalar), pk.mul(&scalar));
+    assert_eq!(
+        ValidatedPoint::generator().mul_secret(&scalar),
+        ValidatedPoint::mul_by_generator(&scalar)
+    );
+}
+
+#[test]
+fn test_vrf_signature() {
+    let mut rng = test_rng();
+    let key = SigningKey::generate(&mut rng);
+    let vk = key.verifying_key();
+
+    let sig = VrfSignature::sign(&key, b"slot 42", b"block");
+    let output = sig.verify(vk, b"slot 42", b"block", &mut rng).unwrap();
+    assert_eq!(output, sig.output());
+
+    // the output only depends on the key and the input
+    let other = VrfSignature::sign(&key, b"slot 42", b"other block");
+    assert_ne!(other, sig);
+    assert_eq!(other.output(), output);
+    assert_ne!(
+        VrfSignature::sign(&key, b"slot 43", b"block").output(),
+        output
+    );
+    let other_key = SigningKey::generate(&mut rng);
+    assert_ne!(
+        VrfSignature::sign(&other_key, b"slot 42", b"block").output(),
+        output
+    );
+
+    // wrong message, input or key
+    assert_eq!(
+        sig.verify(vk, b"slot 42", b"other block", &mut rng),
+        Err(SignatureError::InvalidSignature)
+    );
+    assert_eq!(
+        sig.verify(vk, b"slot 43", b"block", &mut rng),
+        Err(SignatureError::InvalidSignature)
+    );
+    assert_eq!(
+        sig.verify(other_key.verifying_key(), b"slot 42", b"block", &mut rng),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    // a forged pre-output
+    let mut bad = sig;
+    bad.gamma = (bad.gamma + EdwardsAffine::prime_subgroup_generator())
+        .into_projective()
+        .into_affine();
+    assert_eq!(
+        bad.verify(vk, b"slot 42", b"block", &mut rng),
+        Err(SignatureError::InvalidSignature)
+    );
+
+    // commitments with a small order component
+    let t = EdwardsAffine::new(crate::Fq::zero(), -crate::Fq::one());
+    let u = VrfSignature {
+        u: sig.u + t,
+        ..sig
+    };
+    let v = VrfSignature {
+        v: sig.v + t,
+        ..sig
+    };
+    for bad in [u, v] {
+        assert_eq!(
+            bad.verify(vk, b"slot 42", b"block", &mut rng),
+            Err(SignatureError::InvalidSignature)
+        );
+    }
+
+    // batch
+    let keys: Vec<SigningKey> =
+        (0..4).map(|_| SigningKey::generate(&mut rng)).collect();
+    let vks: Vec<VerifyingKey> =
+        keys.iter().map(|k| k.verifying_key().clone()).collect();
+    let inputs: Vec<&[u8]> = vec![b"a", b"b", b"c", b"d"];
+    let msgs: Vec<&[u8]> = vec![b"1", b"2", b"3", b"4"];
+    let mut sigs: Vec<VrfSignature> = keys
+        .iter()
+        .zip(&inputs)
+        .zip(&msgs)
+        .map(|((k, i), m)| VrfSignature::sign(k, i, m))
+        .collect();
+    assert_eq!(
+        VrfSignature::verify_batch(&sigs, &vks, &inputs, &msgs, &mut rng),
+        Ok(())
+    );
+    sigs[2].s += Fr::one();
+    assert_eq!(
+        VrfSignature::verify_batch(&sigs, &vks, &inputs, &msgs, &mut rng),
+        Err(SignatureError::InvalidSignature)
+    );
+    assert_eq!(
+        VrfSignature::verify_batch(&sigs, &vks[1..], &inputs, &msgs, &mut rng),
+        Err(SignatureError::LengthMismatch)
+    );
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize_signing_key() {
+    use zeroize::Zeroize;
+
+    let mut rng = test_rng();
+    let mut key = SigningKey::generate(&mut rng);
+    let verifying_key = key.verifying_key().clone();
+    let sig = key.sign(b"message");
+    assert!(verifying_key.verify(b"message", &sig).is_ok());
+    key.zeroize();
+    assert!(key.as_scalar().is_zero());
+    assert_eq!(key.verifying_key(), &verifying_key);
+
+    fn erased_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+    erased_on_drop::<SigningKey>();
+}
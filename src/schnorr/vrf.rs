@@ -0,0 +1,176 @@
+//! A verifiable random function (VRF) whose proof doubles as a Schnorr
+//! signature, for consensus designs where a leader proves its eligibility
+//! with a VRF and signs the block with the same key.
+//!
+//! The VRF input is hashed to a point `H` of the prime order subgroup with
+//! [`hash_to_curve`] and the suite `bandersnatch_XMD:SHA-256_ELL2_RO_`,
+//! and the output is a hash of `Gamma = x H`. A single
+//! Chaum-Pedersen proof `(U, V, s)` shows that `log_G P = log_H Gamma`,
+//! with `U = k G`, `V = k H`, `s = k + c x`, and the challenge
+//! `c = H(P || H || Gamma || U || V || m)` covers the message `m`, so that
+//! the proof is also a signature on `m`.
+//!
+//! Keeping `U` and `V` rather than `c` makes the two verification
+//! equations `s G - c P = U` and `s H - c Gamma = V` linear, so that they
+//! are checked in a single MSM, and batch with other signatures.
+
+use super::{SignatureError, SigningKey, VerifyingKey};
+use crate::{
+    hash_to_curve::{
+        hash_to_curve, hash_to_scalar, Encoding, Expander, ExpanderXmdSha256,
+        ELL2_MAP_ID,
+    },
+    ladder_mul, to_compressed_bytes, wipe, wipe_bytes, BatchVerifier,
+    EdwardsAffine, Fr, Validate,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::to_bytes;
+use ark_std::{rand::Rng, vec::Vec};
+use sha2::{Digest, Sha256};
+
+/// Domain separation tag of the hash of the input to a point.
+const VRF_INPUT_DST: &[u8] = b"BANDERSNATCH-SCHNORR-V01-VRF-INPUT";
+
+/// Domain separation tag of the challenge hash.
+const VRF_CHALLENGE_DST: &[u8] = b"BANDERSNATCH-SCHNORR-V01-VRF-CHALLENGE";
+
+/// Domain separation tag of the nonce derivation.
+const VRF_NONCE_DST: &[u8] = b"BANDERSNATCH-SCHNORR-V01-VRF-NONCE";
+
+/// Domain separation tag of the output hash.
+const VRF_OUTPUT_DST: &[u8] = b"BANDERSNATCH-SCHNORR-V01-VRF-OUTPUT";
+
+/// The size of a VRF output, in bytes.
+pub const VRF_OUTPUT_SIZE: usize = 32;
+
+/// A VRF proof on an input, which is also a signature on a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VrfSignature {
+    /// The VRF pre-output `Gamma = x H`.
+    pub gamma: EdwardsAffine,
+    /// The commitment `U = k G`.
+    pub u: EdwardsAffine,
+    /// The commitment `V = k H`.
+    pub v: EdwardsAffine,
+    /// The response `s = k + c x`.
+    pub s: Fr,
+}
+
+impl VrfSignature {
+    /// Evaluate the VRF of `key` on `input`, and sign `msg` with the
+    /// proof.
+    pub fn sign(key: &SigningKey, input: &[u8], msg: &[u8]) -> Self {
+        let h = hash_to_point(input);
+        // the key and the nonce are secret: multiply in constant time
+        let gamma = ladder_mul(&h, &key.secret).into_affine();
+        let mut secret_bytes = to_bytes![key.secret, h].unwrap();
+        let mut nonce = hash_to_scalar(VRF_NONCE_DST, &[&secret_bytes, msg]);
+        wipe_bytes(&mut secret_bytes);
+        let u = ladder_mul(&EdwardsAffine::prime_subgroup_generator(), &nonce)
+            .into_affine();
+        let v = ladder_mul(&h, &nonce).into_affine();
+        let c =
+            vrf_challenge(&key.verifying_key.point, &h, &gamma, &u, &v, msg);
+        let s = nonce + c * key.secret;
+        wipe(&mut nonce);
+        Self { gamma, u, v, s }
+    }
+
+    /// The VRF output, which is only meaningful once the proof verifies.
+    pub fn output(&self) -> [u8; VRF_OUTPUT_SIZE] {
+        let mut output = [0u8; VRF_OUTPUT_SIZE];
+        output.copy_from_slice(
+            &Sha256::new()
+                .chain_update(VRF_OUTPUT_DST)
+                .chain_update(to_compressed_bytes(&self.gamma))
+                .finalize(),
+        );
+        output
+    }
+
+    /// Verify the proof on `input` and the signature on `msg` under
+    /// `key`, and return the VRF output.
+    pub fn verify<R: Rng + ?Sized>(
+        &self,
+        key: &VerifyingKey,
+        input: &[u8],
+        msg: &[u8],
+        rng: &mut R,
+    ) -> Result<[u8; VRF_OUTPUT_SIZE], SignatureError> {
+        Self::verify_batch(
+            &[*self],
+            ark_std::slice::from_ref(key),
+            &[input],
+            &[msg],
+            rng,
+        )?;
+        Ok(self.output())
+    }
+
+    /// Verify `sigs[i]` on `inputs[i]` and `msgs[i]` under `keys[i]` for
+    /// all `i` at once with a [`BatchVerifier`].
+    ///
+    /// The pre-outputs are checked to be in the prime order subgroup, so
+    /// that the outputs are unique, and so are the commitments `U` and
+    /// `V`, so that no small order component can cancel out in the batch.
+    pub fn verify_batch<R: Rng + ?Sized>(
+        sigs: &[Self],
+        keys: &[VerifyingKey],
+        inputs: &[&[u8]],
+        msgs: &[&[u8]],
+        rng: &mut R,
+    ) -> Result<(), SignatureError> {
+        if sigs.len() != keys.len()
+            || sigs.len() != inputs.len()
+            || sigs.len() != msgs.len()
+        {
+            return Err(SignatureError::LengthMismatch);
+        }
+        let g = EdwardsAffine::prime_subgroup_generator();
+        let mut batch = BatchVerifier::new();
+        for (((sig, key), input), msg) in
+            sigs.iter().zip(keys).zip(inputs).zip(msgs)
+        {
+            if [sig.gamma, sig.u, sig.v]
+                .iter()
+                .any(|p| p.validate().is_err())
+            {
+                return Err(SignatureError::InvalidSignature);
+            }
+            let h = hash_to_point(input);
+            let c =
+                vrf_challenge(&key.point, &h, &sig.gamma, &sig.u, &sig.v, msg);
+            // s G - c P == U and s H - c Gamma == V
+            batch.queue(&[g, key.point], &[sig.s, -c], sig.u);
+            batch.queue(&[h, sig.gamma], &[sig.s, -c], sig.v);
+        }
+        if batch.verify(rng) {
+            Ok(())
+        } else {
+            Err(SignatureError::InvalidSignature)
+        }
+    }
+}
+
+/// Hash the VRF input to a point of the prime order subgroup.
+fn hash_to_point(input: &[u8]) -> EdwardsAffine {
+    let expander = ExpanderXmdSha256::with_suite(
+        VRF_INPUT_DST,
+        ELL2_MAP_ID,
+        Encoding::Uniform,
+    );
+    hash_to_curve(&expander, input)
+}
+
+/// The challenge `c = H(P || H || Gamma || U || V || m)`.
+fn vrf_challenge(
+    public_key: &EdwardsAffine,
+    h: &EdwardsAffine,
+    gamma: &EdwardsAffine,
+    u: &EdwardsAffine,
+    v: &EdwardsAffine,
+    msg: &[u8],
+) -> Fr {
+    let points: Vec<u8> = to_bytes![public_key, h, gamma, u, v].unwrap();
+    hash_to_scalar(VRF_CHALLENGE_DST, &[&points, msg])
+}
@@ -0,0 +1,247 @@
+//! Serde support for the points and field elements of bandersnatch, behind
+//! the `serde` feature.
+//!
+//! `EdwardsAffine`, `EdwardsProjective`, `Fq` and `Fr` are arkworks types,
+//! for which this crate cannot implement `Serialize` and `Deserialize`;
+//! instead, each of the modules below provides the `serialize` and
+//! `deserialize` functions of `#[serde(with = "...")]`:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct PublicKey {
+//!     #[serde(with = "bandersnatch::serialization::edwards_affine")]
+//!     point: EdwardsAffine,
+//!     #[serde(with = "bandersnatch::serialization::fr")]
+//!     tweak: Fr,
+//! }
+//! ```
+//!
+//! Points are serialized as their 32-byte compressed encoding, see
+//! [`to_compressed_bytes`](crate::to_compressed_bytes), and field elements
+//! as their 32-byte canonical little-endian integers, see
+//! [`FieldBytes`](crate::FieldBytes): as lowercase hexadecimal strings in
+//! human-readable formats, e.g., JSON, and as bytes otherwise, as the
+//! elements of [`banderwagon`](crate::banderwagon). Deserialization checks
+//! that the encoding is canonical and, for points, on the curve.
+
+use crate::hex::{hex_value, HEX_DIGITS};
+use ark_std::{fmt, marker::PhantomData, str};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserializer, Serializer,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// `#[serde(with = "...")]` functions for `EdwardsAffine`.
+pub mod edwards_affine {
+    use crate::{from_compressed_bytes, to_compressed_bytes, EdwardsAffine};
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize the compressed encoding of `p`.
+    pub fn serialize<S: Serializer>(
+        p: &EdwardsAffine,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize_bytes(&to_compressed_bytes(p), serializer)
+    }
+
+    /// Deserialize a compressed encoding.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<EdwardsAffine, D::Error> {
+        super::deserialize_bytes(deserializer, "a compressed point", |bytes| {
+            from_compressed_bytes(bytes).ok_or("not a point of the curve")
+        })
+    }
+}
+
+/// `#[serde(with = "...")]` functions for `EdwardsProjective`, with the
+/// encoding of the affine point.
+pub mod edwards_projective {
+    use crate::EdwardsProjective;
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize the compressed encoding of `p`.
+    pub fn serialize<S: Serializer>(
+        p: &EdwardsProjective,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::edwards_affine::serialize(&p.into_affine(), serializer)
+    }
+
+    /// Deserialize a compressed encoding.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<EdwardsProjective, D::Error> {
+        super::edwards_affine::deserialize(deserializer)
+            .map(|p| p.into_projective())
+    }
+}
+
+/// `#[serde(with = "...")]` functions for `Fq`.
+pub mod fq {
+    use crate::Fq;
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize the canonical little-endian integer of `x`.
+    pub fn serialize<S: Serializer>(
+        x: &Fq,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize_field(x, serializer)
+    }
+
+    /// Deserialize a canonical little-endian integer.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Fq, D::Error> {
+        super::deserialize_field(deserializer)
+    }
+}
+
+/// `#[serde(with = "...")]` functions for `Fr`.
+pub mod fr {
+    use crate::Fr;
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize the canonical little-endian integer of `x`.
+    pub fn serialize<S: Serializer>(
+        x: &Fr,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize_field(x, serializer)
+    }
+
+    /// Deserialize a canonical little-endian integer.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Fr, D::Error> {
+        super::deserialize_field(deserializer)
+    }
+}
+
+fn serialize_field<F: crate::FieldBytes, S: Serializer>(
+    x: &F,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_bytes(&x.to_bytes_le(), serializer)
+}
+
+fn deserialize_field<'de, F: crate::FieldBytes, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<F, D::Error> {
+    deserialize_bytes(deserializer, "a canonical field element", |bytes| {
+        F::from_bytes_le(bytes).ok_or("not smaller than the modulus")
+    })
+}
+
+/// Serialize `bytes` as a lowercase hexadecimal string in human-readable
+/// formats, and as bytes otherwise.
+pub(crate) fn serialize_bytes<S: Serializer, const N: usize>(
+    bytes: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        // two digits per byte, N is at most 32
+        let mut hex = [0u8; 64];
+        let hex = &mut hex[..2 * N];
+        for (digits, byte) in hex.chunks_mut(2).zip(bytes.iter()) {
+            digits[0] = HEX_DIGITS[(byte >> 4) as usize];
+            digits[1] = HEX_DIGITS[(byte & 0xf) as usize];
+        }
+        serializer.serialize_str(str::from_utf8(hex).unwrap())
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Deserialize `N` bytes, as a hexadecimal string, in either case, in
+/// human-readable formats, and as bytes or a sequence otherwise, and
+/// decode them with `decode`.
+pub(crate) fn deserialize_bytes<'de, D, T, E, const N: usize>(
+    deserializer: D,
+    expecting: &'static str,
+    decode: fn(&[u8; N]) -> Result<T, E>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    E: fmt::Display,
+{
+    let visitor = BytesVisitor {
+        expecting,
+        decode,
+        _value: PhantomData,
+    };
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_bytes(visitor)
+    }
+}
+
+struct BytesVisitor<T, E, const N: usize> {
+    expecting: &'static str,
+    decode: fn(&[u8; N]) -> Result<T, E>,
+    _value: PhantomData<T>,
+}
+
+impl<T, E: fmt::Display, const N: usize> BytesVisitor<T, E, N> {
+    fn decode<Error: de::Error>(&self, bytes: &[u8; N]) -> Result<T, Error> {
+        (self.decode)(bytes).map_err(Error::custom)
+    }
+}
+
+impl<'de, T, E: fmt::Display, const N: usize> Visitor<'de>
+    for BytesVisitor<T, E, N>
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {} bytes", self.expecting, N)
+    }
+
+    fn visit_str<Error: de::Error>(self, s: &str) -> Result<T, Error> {
+        let hex = s.as_bytes();
+        if hex.len() != 2 * N {
+            return Err(Error::invalid_length(hex.len() / 2, &self));
+        }
+        let mut bytes = [0u8; N];
+        for (byte, digits) in bytes.iter_mut().zip(hex.chunks(2)) {
+            *byte = match (hex_value(digits[0]), hex_value(digits[1])) {
+                (Some(high), Some(low)) => high << 4 | low,
+                _ => {
+                    return Err(Error::invalid_value(
+                        de::Unexpected::Str(s),
+                        &self,
+                    ))
+                }
+            };
+        }
+        self.decode(&bytes)
+    }
+
+    fn visit_bytes<Error: de::Error>(self, v: &[u8]) -> Result<T, Error> {
+        if v.len() != N {
+            return Err(Error::invalid_length(v.len(), &self));
+        }
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(v);
+        self.decode(&bytes)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut bytes = [0u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+        self.decode(&bytes)
+    }
+}
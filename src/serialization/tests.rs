@@ -0,0 +1,119 @@
+use super::*;
+use crate::{EdwardsAffine, EdwardsProjective, FieldBytes, Fq, Fr};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{One, Zero};
+use ark_std::{test_rng, UniformRand};
+
+/// A newtype serialized with the `with` module, as
+/// `#[serde(with = "...")]` on a field would.
+macro_rules! with_module {
+    ($name:ident, $ty:ty, $module:ident) => {
+        #[derive(Debug, PartialEq)]
+        struct $name($ty);
+
+        impl serde::Serialize for $name {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $module::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                $module::deserialize(deserializer).map($name)
+            }
+        }
+    };
+}
+
+with_module!(Affine, EdwardsAffine, edwards_affine);
+with_module!(Projective, EdwardsProjective, edwards_projective);
+with_module!(BaseField, Fq, fq);
+with_module!(ScalarField, Fr, fr);
+
+#[test]
+fn test_serde_points() {
+    let mut rng = test_rng();
+    let p = EdwardsProjective::rand(&mut rng);
+    let affine = p.into_affine();
+
+    let json = serde_json::to_string(&Affine(affine)).unwrap();
+    assert_eq!(json.len(), 2 * 32 + 2);
+    assert_eq!(serde_json::from_str::<Affine>(&json).unwrap().0, affine);
+    assert_eq!(serde_json::to_string(&Projective(p)).unwrap(), json);
+    assert_eq!(serde_json::from_str::<Projective>(&json).unwrap().0, p);
+    assert_eq!(
+        serde_json::from_str::<Affine>(&json.to_uppercase())
+            .unwrap()
+            .0,
+        affine
+    );
+
+    let cbor = serde_cbor::to_vec(&Affine(affine)).unwrap();
+    assert_eq!(serde_cbor::from_slice::<Affine>(&cbor).unwrap().0, affine);
+    let cbor = serde_cbor::to_vec(&Projective(p)).unwrap();
+    assert_eq!(serde_cbor::from_slice::<Projective>(&cbor).unwrap().0, p);
+
+    // the identity is (0, 1)
+    assert_eq!(
+        serde_json::to_string(&Affine(EdwardsAffine::zero())).unwrap(),
+        "\"0100000000000000000000000000000000000000000000000000000000000000\""
+    );
+
+    // wrong length, invalid digit, and off the curve
+    for invalid in [
+        "\"0100\"",
+        "\"+100000000000000000000000000000000000000000000000000000000000000\"",
+    ]
+    .iter()
+    {
+        assert!(serde_json::from_str::<Affine>(invalid).is_err());
+    }
+    let off_curve = (2u8..)
+        .map(|y| {
+            let mut bytes = [0u8; 32];
+            bytes[0] = y;
+            bytes
+        })
+        .find(|bytes| crate::from_compressed_bytes(bytes).is_none())
+        .unwrap();
+    let cbor =
+        serde_cbor::to_vec(&serde_cbor::Value::Bytes(off_curve.to_vec()))
+            .unwrap();
+    assert!(serde_cbor::from_slice::<Affine>(&cbor).is_err());
+}
+
+#[test]
+fn test_serde_fields() {
+    let mut rng = test_rng();
+    let x = Fq::rand(&mut rng);
+    let s = Fr::rand(&mut rng);
+
+    let json = serde_json::to_string(&BaseField(x)).unwrap();
+    assert_eq!(serde_json::from_str::<BaseField>(&json).unwrap().0, x);
+    let json = serde_json::to_string(&ScalarField(s)).unwrap();
+    assert_eq!(serde_json::from_str::<ScalarField>(&json).unwrap().0, s);
+    assert_eq!(
+        serde_json::to_string(&ScalarField(Fr::one())).unwrap(),
+        "\"0100000000000000000000000000000000000000000000000000000000000000\""
+    );
+
+    let cbor = serde_cbor::to_vec(&BaseField(x)).unwrap();
+    assert_eq!(serde_cbor::from_slice::<BaseField>(&cbor).unwrap().0, x);
+    let cbor = serde_cbor::to_vec(&ScalarField(s)).unwrap();
+    assert_eq!(serde_cbor::from_slice::<ScalarField>(&cbor).unwrap().0, s);
+
+    // q - 1 is canonical in Fq, not in Fr
+    let json = serde_json::to_string(&BaseField(-Fq::one())).unwrap();
+    assert!(serde_json::from_str::<ScalarField>(&json).is_err());
+    let mut max = [0xffu8; 32];
+    max[31] = 0x7f;
+    assert_eq!(Fq::from_bytes_le(&max), None);
+    let cbor =
+        serde_cbor::to_vec(&serde_cbor::Value::Bytes(max.to_vec())).unwrap();
+    assert!(serde_cbor::from_slice::<BaseField>(&cbor).is_err());
+}
@@ -0,0 +1,111 @@
+//! SSZ encodings, behind the `ssz` feature, so that consensus objects
+//! holding bandersnatch data, e.g., the Verkle proofs of Ethereum, can be
+//! encoded with [`ethereum_ssz`](::ssz).
+//!
+//! All the values are fixed-size `Bytes32`, as in the consensus specs:
+//!
+//! - [`Element`], a `BanderwagonGroupElement`, as
+//!   [`Element::to_bytes`];
+//! - [`ValidatedPoint`], a point of the prime order subgroup, as its
+//!   compressed encoding, see [`to_compressed_bytes`](crate::to_compressed_bytes);
+//! - [`SszScalar`], an element of `Fr`, a `BanderwagonFieldElement`, as its
+//!   canonical little-endian integer.
+//!
+//! `Fr` is an arkworks type, for which this crate cannot implement the SSZ
+//! traits, hence the newtype. Decoding checks that encodings are canonical
+//! and, for points, in the prime order subgroup. Lists and vectors of
+//! these values, e.g., `Vec<Element>`, are encoded by `ethereum_ssz`.
+
+use crate::{banderwagon::Element, FieldBytes, Fr, ValidatedPoint};
+use ::ssz::{Decode, DecodeError, Encode};
+use ark_std::{convert::TryInto, string::ToString, vec::Vec};
+
+#[cfg(test)]
+mod tests;
+
+/// The size of every SSZ value of this module, in bytes.
+pub const SSZ_SIZE: usize = 32;
+
+/// An element of `Fr`, with SSZ encoding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SszScalar(pub Fr);
+
+impl From<Fr> for SszScalar {
+    fn from(scalar: Fr) -> Self {
+        Self(scalar)
+    }
+}
+
+impl From<SszScalar> for Fr {
+    fn from(scalar: SszScalar) -> Self {
+        scalar.0
+    }
+}
+
+/// Implement the SSZ traits for a `Bytes32` type, given its encoding and
+/// its fallible decoding.
+macro_rules! impl_ssz_bytes32 {
+    ($type:ty, $encode:expr, $decode:expr) => {
+        impl Encode for $type {
+            fn is_ssz_fixed_len() -> bool {
+                true
+            }
+
+            fn ssz_fixed_len() -> usize {
+                SSZ_SIZE
+            }
+
+            fn ssz_append(&self, buf: &mut Vec<u8>) {
+                let encode: fn(&$type) -> [u8; SSZ_SIZE] = $encode;
+                buf.extend_from_slice(&encode(self));
+            }
+
+            fn ssz_bytes_len(&self) -> usize {
+                SSZ_SIZE
+            }
+        }
+
+        impl Decode for $type {
+            fn is_ssz_fixed_len() -> bool {
+                true
+            }
+
+            fn ssz_fixed_len() -> usize {
+                SSZ_SIZE
+            }
+
+            fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+                let bytes: &[u8; SSZ_SIZE] =
+                    bytes.try_into().map_err(|_| {
+                        DecodeError::InvalidByteLength {
+                            len: bytes.len(),
+                            expected: SSZ_SIZE,
+                        }
+                    })?;
+                let decode: fn(&[u8; SSZ_SIZE]) -> Result<$type, DecodeError> =
+                    $decode;
+                decode(bytes)
+            }
+        }
+    };
+}
+
+impl_ssz_bytes32!(Element, Element::to_bytes, |bytes| {
+    Element::from_bytes(bytes)
+        .map_err(|e| DecodeError::BytesInvalid(e.to_string()))
+});
+
+impl_ssz_bytes32!(
+    ValidatedPoint,
+    ValidatedPoint::to_compressed_bytes,
+    |bytes| {
+        ValidatedPoint::from_compressed_bytes(bytes)
+            .map_err(|e| DecodeError::BytesInvalid(e.to_string()))
+    }
+);
+
+impl_ssz_bytes32!(SszScalar, |s| s.0.to_bytes_le(), |bytes| {
+    Fr::from_bytes_le(bytes)
+        .map(SszScalar)
+        .ok_or_else(|| DecodeError::BytesInvalid("non-canonical scalar".into()))
+});
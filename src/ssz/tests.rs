@@ -0,0 +1,77 @@
+use super::*;
+use crate::{to_compressed_bytes, EdwardsAffine, EdwardsProjective, Fq};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{One, Zero};
+use ark_std::{rand::Rng, test_rng, UniformRand};
+
+#[test]
+fn test_element() {
+    let mut rng = test_rng();
+    let element: Element = rng.gen();
+    let bytes = element.as_ssz_bytes();
+    assert_eq!(bytes, element.to_bytes());
+    assert_eq!(Element::from_ssz_bytes(&bytes), Ok(element));
+    assert_eq!(
+        Element::from_ssz_bytes(&bytes[1..]),
+        Err(DecodeError::InvalidByteLength {
+            len: 31,
+            expected: 32
+        })
+    );
+    assert!(matches!(
+        Element::from_ssz_bytes(&[0xff; 32]),
+        Err(DecodeError::BytesInvalid(_))
+    ));
+}
+
+#[test]
+fn test_point() {
+    let mut rng = test_rng();
+    let p = EdwardsProjective::rand(&mut rng).into_affine();
+    let point = ValidatedPoint::new(p).unwrap();
+    let bytes = point.as_ssz_bytes();
+    assert_eq!(bytes, to_compressed_bytes(&p));
+    assert_eq!(ValidatedPoint::from_ssz_bytes(&bytes), Ok(point));
+
+    // (0, -1) is on the curve, but of order 2
+    let t = EdwardsAffine::new(Fq::zero(), -Fq::one());
+    assert!(matches!(
+        ValidatedPoint::from_ssz_bytes(&to_compressed_bytes(&t)),
+        Err(DecodeError::BytesInvalid(_))
+    ));
+}
+
+#[test]
+fn test_scalar() {
+    let mut rng = test_rng();
+    let s = SszScalar(Fr::rand(&mut rng));
+    let bytes = s.as_ssz_bytes();
+    assert_eq!(bytes, s.0.to_bytes_le());
+    assert_eq!(SszScalar::from_ssz_bytes(&bytes), Ok(s));
+    assert!(matches!(
+        SszScalar::from_ssz_bytes(&[0xff; 32]),
+        Err(DecodeError::BytesInvalid(_))
+    ));
+}
+
+#[test]
+fn test_lists() {
+    let mut rng = test_rng();
+    let elements: Vec<Element> = (0..5).map(|_| rng.gen()).collect();
+    let scalars: Vec<SszScalar> =
+        (0..5).map(|_| SszScalar(Fr::rand(&mut rng))).collect();
+
+    // a list of fixed-size values is their concatenation
+    let bytes = elements.as_ssz_bytes();
+    assert_eq!(bytes.len(), 5 * SSZ_SIZE);
+    assert_eq!(Vec::<Element>::from_ssz_bytes(&bytes), Ok(elements.clone()));
+
+    // a container with a variable-size field starts with its offset
+    let container = (elements.clone(), scalars[0]);
+    let bytes = container.as_ssz_bytes();
+    assert_eq!(bytes[..4], (4u32 + 32).to_le_bytes());
+    assert_eq!(
+        <(Vec<Element>, SszScalar)>::from_ssz_bytes(&bytes),
+        Ok(container)
+    );
+}
@@ -0,0 +1,320 @@
+//! A versioned container format for the values that applications keep on
+//! disk: secret keys, CRSs, lists of points and precomputed tables, so
+//! that they are not each stored as raw serializations in incompatible
+//! layouts.
+//!
+//! A container is
+//!
+//! ```text
+//! magic ("BNDR") || version (u16) || kind (u8) || payload length (u64)
+//!     || payload || checksum
+//! ```
+//!
+//! with integers in little-endian, the payload being the encoding of the
+//! value, see [`Storable`], and the checksum the SHA-256 hash of all the
+//! preceding bytes. The checksum detects corruption, not tampering: a
+//! container is only as trustworthy as the place it is read from, in
+//! particular for [`PrecomputedTable`]s and [`CombTable`]s, whose points
+//! are not checked to be the multiples of their base.
+//!
+//! [`encode`] and [`decode`] work on bytes; with the `std` feature,
+//! [`save`] and [`load`] write and read files.
+
+use crate::{
+    banderwagon::Element, deserialize_batch, ipa::VerifierConfig, ipa::CRS,
+    schnorr::SigningKey, serialize_batch, wipe_bytes, CombTable, EdwardsAffine,
+    FieldBytes, Fr, PrecomputedTable, FIELD_BYTES_SIZE,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{convert::TryInto, fmt, vec::Vec};
+use sha2::{Digest, Sha256};
+
+#[cfg(test)]
+mod tests;
+
+/// The first bytes of every container.
+pub const MAGIC: [u8; 4] = *b"BNDR";
+/// The version of the format written by [`encode`].
+pub const VERSION: u16 = 1;
+/// The size of the header, before the payload.
+pub const HEADER_SIZE: usize = 15;
+/// The size of the checksum, after the payload.
+pub const CHECKSUM_SIZE: usize = 32;
+
+/// The kind of value held by a container.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContainerKind {
+    /// A Schnorr [`SigningKey`].
+    SigningKey = 1,
+    /// An inner product argument [`CRS`].
+    Crs = 2,
+    /// A list of points of the prime order subgroup.
+    Points = 3,
+    /// A list of Banderwagon [`Element`]s.
+    Elements = 4,
+    /// A [`PrecomputedTable`] of fixed-base multiples.
+    PrecomputedTable = 5,
+    /// A [`CombTable`] of fixed-base multiples.
+    CombTable = 6,
+}
+
+impl ContainerKind {
+    fn from_u8(tag: u8) -> Option<Self> {
+        match tag {
+            1 => Some(ContainerKind::SigningKey),
+            2 => Some(ContainerKind::Crs),
+            3 => Some(ContainerKind::Points),
+            4 => Some(ContainerKind::Elements),
+            5 => Some(ContainerKind::PrecomputedTable),
+            6 => Some(ContainerKind::CombTable),
+            _ => None,
+        }
+    }
+}
+
+/// Errors that can occur while reading a container.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageError {
+    /// The bytes do not start with [`MAGIC`].
+    InvalidMagic,
+    /// The container was written by an unknown version of the format.
+    UnsupportedVersion(u16),
+    /// The container holds another kind of value, or an unknown one.
+    UnexpectedKind {
+        /// The kind that was asked for.
+        expected: ContainerKind,
+        /// The tag found in the header.
+        found: u8,
+    },
+    /// The bytes are shorter or longer than the header says.
+    InvalidLength,
+    /// The checksum does not match: the container is corrupted.
+    ChecksumMismatch,
+    /// The payload is not a valid encoding of the value.
+    InvalidPayload,
+    /// The file could not be read or written.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::InvalidMagic => write!(f, "not a container"),
+            StorageError::UnsupportedVersion(version) => {
+                write!(f, "unsupported container version {}", version)
+            }
+            StorageError::UnexpectedKind { expected, found } => write!(
+                f,
+                "expected a container of kind {:?}, found tag {}",
+                expected, found
+            ),
+            StorageError::InvalidLength => {
+                write!(f, "the container length does not match its header")
+            }
+            StorageError::ChecksumMismatch => {
+                write!(f, "the container checksum does not match")
+            }
+            StorageError::InvalidPayload => {
+                write!(f, "invalid container payload")
+            }
+            #[cfg(feature = "std")]
+            StorageError::Io(kind) => write!(f, "i/o error: {:?}", kind),
+        }
+    }
+}
+
+/// Values that can be stored in a container.
+pub trait Storable: Sized {
+    /// The kind written in the header.
+    const KIND: ContainerKind;
+
+    /// Encode the value as a payload.
+    fn to_payload(&self) -> Vec<u8>;
+
+    /// Decode a payload written by [`Storable::to_payload`], validating
+    /// it as the corresponding parser of the type does.
+    fn from_payload(payload: &[u8]) -> Result<Self, StorageError>;
+}
+
+/// The secret scalar, in little-endian.
+impl Storable for SigningKey {
+    const KIND: ContainerKind = ContainerKind::SigningKey;
+
+    fn to_payload(&self) -> Vec<u8> {
+        let mut bytes = self.as_scalar().to_bytes_le();
+        let payload = bytes.to_vec();
+        wipe_bytes(&mut bytes);
+        payload
+    }
+
+    fn from_payload(payload: &[u8]) -> Result<Self, StorageError> {
+        let bytes: &[u8; FIELD_BYTES_SIZE] = payload
+            .try_into()
+            .map_err(|_| StorageError::InvalidPayload)?;
+        Fr::from_bytes_le(bytes)
+            .and_then(SigningKey::from_scalar)
+            .ok_or(StorageError::InvalidPayload)
+    }
+}
+
+/// [`CRS::to_bytes`]. The number of generators is only bounded by the
+/// length of the payload, which is already in memory.
+impl Storable for CRS {
+    const KIND: ContainerKind = ContainerKind::Crs;
+
+    fn to_payload(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+
+    fn from_payload(payload: &[u8]) -> Result<Self, StorageError> {
+        let config = VerifierConfig {
+            max_generators: payload.len() / 64,
+            ..VerifierConfig::default()
+        };
+        CRS::from_bytes(payload, &config)
+            .map_err(|_| StorageError::InvalidPayload)
+    }
+}
+
+/// [`serialize_batch`].
+impl Storable for Vec<EdwardsAffine> {
+    const KIND: ContainerKind = ContainerKind::Points;
+
+    fn to_payload(&self) -> Vec<u8> {
+        serialize_batch(self)
+    }
+
+    fn from_payload(payload: &[u8]) -> Result<Self, StorageError> {
+        deserialize_batch(payload).map_err(|_| StorageError::InvalidPayload)
+    }
+}
+
+/// [`Element::serialize_batch`].
+impl Storable for Vec<Element> {
+    const KIND: ContainerKind = ContainerKind::Elements;
+
+    fn to_payload(&self) -> Vec<u8> {
+        Element::serialize_batch(self)
+    }
+
+    fn from_payload(payload: &[u8]) -> Result<Self, StorageError> {
+        Element::deserialize_batch(payload)
+            .map_err(|_| StorageError::InvalidPayload)
+    }
+}
+
+/// The compressed [`CanonicalSerialize`] encoding.
+impl Storable for PrecomputedTable {
+    const KIND: ContainerKind = ContainerKind::PrecomputedTable;
+
+    fn to_payload(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.serialized_size());
+        self.serialize(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn from_payload(mut payload: &[u8]) -> Result<Self, StorageError> {
+        let table = PrecomputedTable::deserialize(&mut payload)
+            .map_err(|_| StorageError::InvalidPayload)?;
+        if !payload.is_empty() {
+            return Err(StorageError::InvalidPayload);
+        }
+        Ok(table)
+    }
+}
+
+/// The compressed [`CanonicalSerialize`] encoding.
+impl Storable for CombTable {
+    const KIND: ContainerKind = ContainerKind::CombTable;
+
+    fn to_payload(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.serialized_size());
+        self.serialize(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn from_payload(mut payload: &[u8]) -> Result<Self, StorageError> {
+        let table = CombTable::deserialize(&mut payload)
+            .map_err(|_| StorageError::InvalidPayload)?;
+        if !payload.is_empty() {
+            return Err(StorageError::InvalidPayload);
+        }
+        Ok(table)
+    }
+}
+
+/// Write `value` in a container.
+///
+/// For a [`SigningKey`], the container holds the secret: the caller is
+/// responsible for erasing it.
+pub fn encode<T: Storable>(value: &T) -> Vec<u8> {
+    let mut payload = value.to_payload();
+    let mut bytes =
+        Vec::with_capacity(HEADER_SIZE + payload.len() + CHECKSUM_SIZE);
+    bytes.extend_from_slice(&MAGIC);
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.push(T::KIND as u8);
+    bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(&payload);
+    wipe_bytes(&mut payload);
+    let checksum = Sha256::digest(&bytes);
+    bytes.extend_from_slice(&checksum);
+    bytes
+}
+
+/// Read a value written with [`encode`], checking the header and the
+/// checksum before decoding the payload.
+pub fn decode<T: Storable>(bytes: &[u8]) -> Result<T, StorageError> {
+    if bytes.len() < HEADER_SIZE + CHECKSUM_SIZE {
+        return Err(StorageError::InvalidLength);
+    }
+    if bytes[..4] != MAGIC {
+        return Err(StorageError::InvalidMagic);
+    }
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if version != VERSION {
+        return Err(StorageError::UnsupportedVersion(version));
+    }
+    let (content, checksum) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+    if Sha256::digest(content).as_slice() != checksum {
+        return Err(StorageError::ChecksumMismatch);
+    }
+    let tag = bytes[6];
+    if ContainerKind::from_u8(tag) != Some(T::KIND) {
+        return Err(StorageError::UnexpectedKind {
+            expected: T::KIND,
+            found: tag,
+        });
+    }
+    let len = u64::from_le_bytes(bytes[7..HEADER_SIZE].try_into().unwrap());
+    if len != (content.len() - HEADER_SIZE) as u64 {
+        return Err(StorageError::InvalidLength);
+    }
+    T::from_payload(&content[HEADER_SIZE..])
+}
+
+/// Write `value` in a container to the file at `path`, replacing it if it
+/// exists.
+#[cfg(feature = "std")]
+pub fn save<T: Storable, P: AsRef<std::path::Path>>(
+    value: &T,
+    path: P,
+) -> Result<(), StorageError> {
+    let mut bytes = encode(value);
+    let result = std::fs::write(path, &bytes);
+    wipe_bytes(&mut bytes);
+    result.map_err(|e| StorageError::Io(e.kind()))
+}
+
+/// Read a value from a container in the file at `path`, see [`decode`].
+#[cfg(feature = "std")]
+pub fn load<T: Storable, P: AsRef<std::path::Path>>(
+    path: P,
+) -> Result<T, StorageError> {
+    let mut bytes =
+        std::fs::read(path).map_err(|e| StorageError::Io(e.kind()))?;
+    let value = decode(&bytes);
+    wipe_bytes(&mut bytes);
+    value
+}
@@ -0,0 +1,109 @@
+use super::*;
+use crate::EdwardsProjective;
+use ark_ec::ProjectiveCurve;
+use ark_std::{test_rng, UniformRand};
+
+#[test]
+fn test_storage_round_trip() {
+    let mut rng = test_rng();
+
+    let key = SigningKey::generate(&mut rng);
+    let bytes = encode(&key);
+    assert_eq!(&bytes[..4], b"BNDR");
+    assert_eq!(bytes.len(), HEADER_SIZE + 32 + CHECKSUM_SIZE);
+    let decoded: SigningKey = decode(&bytes).unwrap();
+    assert_eq!(decoded.as_scalar(), key.as_scalar());
+
+    let crs = CRS::new(8, b"storage test");
+    assert_eq!(decode::<CRS>(&encode(&crs)), Ok(crs));
+
+    let points: Vec<EdwardsAffine> = (0..5)
+        .map(|_| EdwardsProjective::rand(&mut rng).into_affine())
+        .collect();
+    assert_eq!(decode::<Vec<EdwardsAffine>>(&encode(&points)), Ok(points));
+
+    let elements: Vec<Element> = (0..5)
+        .map(|_| Element::generator() * Fr::rand(&mut rng))
+        .collect();
+    assert_eq!(decode::<Vec<Element>>(&encode(&elements)), Ok(elements));
+
+    let base = EdwardsProjective::rand(&mut rng).into_affine();
+    let table = PrecomputedTable::new(&base, 4);
+    assert_eq!(decode::<PrecomputedTable>(&encode(&table)), Ok(table));
+
+    let table = CombTable::new(&base, 4);
+    assert_eq!(decode::<CombTable>(&encode(&table)), Ok(table.clone()));
+    assert_eq!(
+        decode::<PrecomputedTable>(&encode(&table)),
+        Err(StorageError::UnexpectedKind {
+            expected: ContainerKind::PrecomputedTable,
+            found: ContainerKind::CombTable as u8,
+        })
+    );
+}
+
+#[test]
+fn test_storage_rejects_invalid_containers() {
+    let crs = CRS::new(4, b"storage test");
+    let bytes = encode(&crs);
+
+    let mut bad = bytes.clone();
+    bad[0] ^= 1;
+    assert_eq!(decode::<CRS>(&bad), Err(StorageError::InvalidMagic));
+
+    let mut bad = bytes.clone();
+    bad[4] = 2;
+    assert_eq!(
+        decode::<CRS>(&bad),
+        Err(StorageError::UnsupportedVersion(2))
+    );
+
+    let mut bad = bytes.clone();
+    bad[HEADER_SIZE + 10] ^= 1;
+    assert_eq!(decode::<CRS>(&bad), Err(StorageError::ChecksumMismatch));
+
+    assert_eq!(
+        decode::<Vec<EdwardsAffine>>(&bytes),
+        Err(StorageError::UnexpectedKind {
+            expected: ContainerKind::Points,
+            found: ContainerKind::Crs as u8,
+        })
+    );
+    assert_eq!(
+        decode::<CRS>(&bytes[..bytes.len() - 1]),
+        Err(StorageError::ChecksumMismatch)
+    );
+    assert_eq!(
+        decode::<CRS>(&bytes[..40]),
+        Err(StorageError::InvalidLength)
+    );
+
+    // A consistent header and checksum around an invalid payload.
+    let mut content = bytes[..HEADER_SIZE].to_vec();
+    content[7..HEADER_SIZE].copy_from_slice(&32u64.to_le_bytes());
+    content[6] = ContainerKind::SigningKey as u8;
+    content.extend_from_slice(&[0u8; 32]);
+    let checksum = Sha256::digest(&content);
+    content.extend_from_slice(&checksum);
+    assert_eq!(
+        decode::<SigningKey>(&content).err(),
+        Some(StorageError::InvalidPayload)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_storage_files() {
+    let mut rng = test_rng();
+    let key = SigningKey::generate(&mut rng);
+    let path = std::env::temp_dir()
+        .join(format!("bandersnatch-storage-{}", std::process::id()));
+    save(&key, &path).unwrap();
+    let loaded: SigningKey = load(&path).unwrap();
+    assert_eq!(loaded.as_scalar(), key.as_scalar());
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        load::<SigningKey, _>(&path),
+        Err(StorageError::Io(std::io::ErrorKind::NotFound))
+    ));
+}
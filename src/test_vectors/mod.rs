@@ -0,0 +1,476 @@
+//! Test vectors in JSON, behind the `test-vectors` feature, so that other
+//! implementations, e.g., in Go, Nim or Python, can check themselves
+//! against vectors generated by this crate.
+//!
+//! A file is a [`TestVectors`] object: the name of the curve, the version
+//! of the format and a list of vectors, each an object whose `"type"`
+//! names the operation, with its inputs and outputs:
+//!
+//! ```json
+//! {
+//!   "curve": "bandersnatch",
+//!   "version": 1,
+//!   "vectors": [
+//!     { "type": "scalar_mul", "point": "…", "scalar": "…", "result": "…" },
+//!     { "type": "schnorr", "secret_key": "…", "public_key": "…", … }
+//!   ]
+//! }
+//! ```
+//!
+//! The values use the encodings of [`serialization`](crate::serialization):
+//! points of bandersnatch as their compressed encoding and elements of `Fq`
+//! and `Fr` as their canonical little-endian integers, in hexadecimal;
+//! Banderwagon elements as their encoding, see
+//! [`Element::to_bytes`](crate::banderwagon::Element::to_bytes), and
+//! messages and domains as hexadecimal bytes. [`TestVectors::generate`]
+//! draws vectors of every type from an RNG, and [`TestVectors::check`]
+//! checks vectors, e.g., parsed from a file, against this crate.
+
+use crate::{
+    banderwagon::Element,
+    hash_to_curve::derive_generator,
+    hex::{hex_value, HEX_DIGITS},
+    msm,
+    schnorr::{Signature, SigningKey, VerifyingKey},
+    EdwardsAffine, EdwardsParameters, EdwardsProjective, Fq, Fr, GLVParameters,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::{
+    fmt,
+    rand::Rng,
+    string::{String, ToString},
+    vec::Vec,
+    UniformRand,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(test)]
+mod tests;
+
+/// The version of the format written by this crate.
+pub const TEST_VECTORS_VERSION: u32 = 1;
+
+/// The name of the curve in the files written by this crate.
+pub const CURVE_NAME: &str = "bandersnatch";
+
+/// A set of test vectors.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectors {
+    /// The name of the curve, [`CURVE_NAME`].
+    pub curve: String,
+    /// The version of the format, [`TEST_VECTORS_VERSION`].
+    pub version: u32,
+    /// The vectors.
+    pub vectors: Vec<TestVector>,
+}
+
+/// A test vector: the inputs of an operation and its outputs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TestVector {
+    /// The compressed encoding of the point with affine coordinates
+    /// `(x, y)`.
+    Encoding {
+        /// The `x` coordinate.
+        #[serde(with = "crate::serialization::fq")]
+        x: Fq,
+        /// The `y` coordinate.
+        #[serde(with = "crate::serialization::fq")]
+        y: Fq,
+        /// The point `(x, y)`, compressed.
+        #[serde(with = "crate::serialization::edwards_affine")]
+        compressed: EdwardsAffine,
+    },
+    /// `result = scalar * point`.
+    ScalarMul {
+        /// The point.
+        #[serde(with = "crate::serialization::edwards_affine")]
+        point: EdwardsAffine,
+        /// The scalar.
+        #[serde(with = "crate::serialization::fr")]
+        scalar: Fr,
+        /// The product.
+        #[serde(with = "crate::serialization::edwards_affine")]
+        result: EdwardsAffine,
+    },
+    /// The GLV decomposition `scalar = k1 + lambda * k2`, see
+    /// [`GLVParameters::scalar_decomposition`].
+    GlvDecomposition {
+        /// The scalar.
+        #[serde(with = "crate::serialization::fr")]
+        scalar: Fr,
+        /// The first half, reduced modulo `r`.
+        #[serde(with = "crate::serialization::fr")]
+        k1: Fr,
+        /// The second half, reduced modulo `r`.
+        #[serde(with = "crate::serialization::fr")]
+        k2: Fr,
+    },
+    /// `result = sum_i scalars[i] * points[i]`.
+    Msm {
+        /// The points.
+        #[serde(with = "points")]
+        points: Vec<EdwardsAffine>,
+        /// The scalars.
+        #[serde(with = "scalars")]
+        scalars: Vec<Fr>,
+        /// The sum.
+        #[serde(with = "crate::serialization::edwards_affine")]
+        result: EdwardsAffine,
+    },
+    /// The Banderwagon element of a point, its encoding and its image by
+    /// [`Element::map_to_scalar_field`].
+    Banderwagon {
+        /// A point of bandersnatch.
+        #[serde(with = "crate::serialization::edwards_affine")]
+        point: EdwardsAffine,
+        /// The class of the point.
+        element: Element,
+        /// The element mapped to the scalar field.
+        #[serde(with = "crate::serialization::fr")]
+        scalar: Fr,
+    },
+    /// A Schnorr signature of [`schnorr`](crate::schnorr), which is
+    /// deterministic.
+    Schnorr {
+        /// The secret key.
+        #[serde(with = "crate::serialization::fr")]
+        secret_key: Fr,
+        /// The public key.
+        #[serde(with = "crate::serialization::edwards_affine")]
+        public_key: EdwardsAffine,
+        /// The message.
+        #[serde(with = "bytes")]
+        message: Vec<u8>,
+        /// The commitment of the signature.
+        #[serde(with = "crate::serialization::edwards_affine")]
+        r: EdwardsAffine,
+        /// The response of the signature.
+        #[serde(with = "crate::serialization::fr")]
+        s: Fr,
+    },
+    /// A generator derived with [`derive_generator`].
+    DeriveGenerator {
+        /// The domain.
+        #[serde(with = "bytes")]
+        domain: Vec<u8>,
+        /// The index.
+        index: u64,
+        /// The generator.
+        #[serde(with = "crate::serialization::edwards_affine")]
+        result: EdwardsAffine,
+    },
+}
+
+/// A test vector that does not match this crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestVectorMismatch {
+    /// The index of the vector.
+    pub index: usize,
+    /// The type of the vector.
+    pub kind: &'static str,
+}
+
+impl fmt::Display for TestVectorMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "test vector {} ({}) does not match",
+            self.index, self.kind
+        )
+    }
+}
+
+impl TestVectors {
+    /// An empty set of vectors of the current version.
+    pub fn new() -> Self {
+        Self {
+            curve: CURVE_NAME.to_string(),
+            version: TEST_VECTORS_VERSION,
+            vectors: Vec::new(),
+        }
+    }
+
+    /// Draw `count` vectors of every type.
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R, count: usize) -> Self {
+        let mut vectors = Self::new();
+        for i in 0..count {
+            let p = EdwardsProjective::rand(rng).into_affine();
+            vectors.vectors.extend([
+                TestVector::encoding(&p),
+                TestVector::scalar_mul(&p, &Fr::rand(rng)),
+                TestVector::glv_decomposition(&Fr::rand(rng)),
+            ]);
+
+            let points: Vec<EdwardsAffine> = (0..i + 1)
+                .map(|_| EdwardsProjective::rand(rng).into_affine())
+                .collect();
+            let scalars: Vec<Fr> = (0..i + 1).map(|_| Fr::rand(rng)).collect();
+            let key = SigningKey::generate(rng);
+            let mut message = ark_std::vec![0u8; i];
+            rng.fill_bytes(&mut message);
+            vectors.vectors.extend([
+                TestVector::msm(&points, &scalars),
+                TestVector::banderwagon(&p),
+                TestVector::schnorr(&key, &message),
+                TestVector::derive_generator(b"test vectors", i as u64),
+            ]);
+        }
+        vectors
+    }
+
+    /// Check every vector against this crate, returning the first one that
+    /// does not match.
+    pub fn check(&self) -> Result<(), TestVectorMismatch> {
+        for (index, vector) in self.vectors.iter().enumerate() {
+            if !vector.is_valid() {
+                return Err(TestVectorMismatch {
+                    index,
+                    kind: vector.kind(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse vectors from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Write the vectors as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        // the vectors only contain strings, integers and sequences
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+impl Default for TestVectors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestVector {
+    /// The encoding vector of `point`.
+    pub fn encoding(point: &EdwardsAffine) -> Self {
+        TestVector::Encoding {
+            x: point.x,
+            y: point.y,
+            compressed: *point,
+        }
+    }
+
+    /// The scalar multiplication vector of `point` and `scalar`.
+    pub fn scalar_mul(point: &EdwardsAffine, scalar: &Fr) -> Self {
+        TestVector::ScalarMul {
+            point: *point,
+            scalar: *scalar,
+            result: point.mul(scalar.into_repr()).into_affine(),
+        }
+    }
+
+    /// The GLV decomposition vector of `scalar`.
+    pub fn glv_decomposition(scalar: &Fr) -> Self {
+        let (k1, k2) = EdwardsParameters::scalar_decomposition(scalar);
+        TestVector::GlvDecomposition {
+            scalar: *scalar,
+            k1,
+            k2,
+        }
+    }
+
+    /// The MSM vector of `points` and `scalars`.
+    pub fn msm(points: &[EdwardsAffine], scalars: &[Fr]) -> Self {
+        TestVector::Msm {
+            points: points.to_vec(),
+            scalars: scalars.to_vec(),
+            result: msm(points, scalars).into_affine(),
+        }
+    }
+
+    /// The Banderwagon vector of `point`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `point` is not the representative of a Banderwagon
+    /// element, see [`Element::from_edwards`].
+    pub fn banderwagon(point: &EdwardsAffine) -> Self {
+        let element = Element::from_edwards(point)
+            .expect("the point is not in the subgroup of order 2r");
+        TestVector::Banderwagon {
+            point: *point,
+            element,
+            scalar: element.map_to_scalar_field(),
+        }
+    }
+
+    /// The Schnorr vector of the signature of `message` with `key`.
+    pub fn schnorr(key: &SigningKey, message: &[u8]) -> Self {
+        let Signature { r, s } = key.sign(message);
+        TestVector::Schnorr {
+            secret_key: *key.as_scalar(),
+            public_key: *key.verifying_key().as_affine(),
+            message: message.to_vec(),
+            r,
+            s,
+        }
+    }
+
+    /// The vector of the generator derived from `domain` and `index`.
+    pub fn derive_generator(domain: &[u8], index: u64) -> Self {
+        TestVector::DeriveGenerator {
+            domain: domain.to_vec(),
+            index,
+            result: derive_generator(domain, index),
+        }
+    }
+
+    /// The name of the type of the vector, as in JSON.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            TestVector::Encoding { .. } => "encoding",
+            TestVector::ScalarMul { .. } => "scalar_mul",
+            TestVector::GlvDecomposition { .. } => "glv_decomposition",
+            TestVector::Msm { .. } => "msm",
+            TestVector::Banderwagon { .. } => "banderwagon",
+            TestVector::Schnorr { .. } => "schnorr",
+            TestVector::DeriveGenerator { .. } => "derive_generator",
+        }
+    }
+
+    /// Whether the outputs are the ones computed by this crate.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            TestVector::Encoding { x, y, compressed } => {
+                let point = EdwardsAffine::new(*x, *y);
+                point.is_on_curve() && point == *compressed
+            }
+            TestVector::ScalarMul { point, scalar, .. } => {
+                *self == Self::scalar_mul(point, scalar)
+            }
+            TestVector::GlvDecomposition { scalar, .. } => {
+                *self == Self::glv_decomposition(scalar)
+            }
+            TestVector::Msm {
+                points, scalars, ..
+            } => {
+                points.len() == scalars.len()
+                    && *self == Self::msm(points, scalars)
+            }
+            TestVector::Banderwagon { point, .. } => {
+                Element::from_edwards(point).is_ok()
+                    && *self == Self::banderwagon(point)
+            }
+            TestVector::Schnorr {
+                secret_key,
+                public_key,
+                message,
+                r,
+                s,
+            } => {
+                let signature = Signature { r: *r, s: *s };
+                let valid_signature = VerifyingKey::from_affine(*public_key)
+                    .is_ok_and(|key| key.verify(message, &signature).is_ok());
+                valid_signature
+                    && SigningKey::from_scalar(*secret_key).is_some_and(|key| {
+                        *self == Self::schnorr(&key, message)
+                    })
+            }
+            TestVector::DeriveGenerator { domain, index, .. } => {
+                *self == Self::derive_generator(domain, *index)
+            }
+        }
+    }
+}
+
+/// `#[serde(with = "...")]` functions for lists of points.
+mod points {
+    use crate::EdwardsAffine;
+    use ark_std::vec::Vec;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Point(
+        #[serde(with = "crate::serialization::edwards_affine")] EdwardsAffine,
+    );
+
+    pub fn serialize<S: Serializer>(
+        points: &[EdwardsAffine],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(points.iter().map(|p| Point(*p)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<EdwardsAffine>, D::Error> {
+        let points = Vec::<Point>::deserialize(deserializer)?;
+        Ok(points.into_iter().map(|p| p.0).collect())
+    }
+}
+
+/// `#[serde(with = "...")]` functions for lists of scalars.
+mod scalars {
+    use crate::Fr;
+    use ark_std::vec::Vec;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Scalar(#[serde(with = "crate::serialization::fr")] Fr);
+
+    pub fn serialize<S: Serializer>(
+        scalars: &[Fr],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(scalars.iter().map(|s| Scalar(*s)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Fr>, D::Error> {
+        let scalars = Vec::<Scalar>::deserialize(deserializer)?;
+        Ok(scalars.into_iter().map(|s| s.0).collect())
+    }
+}
+
+/// `#[serde(with = "...")]` functions for byte strings of any length, as
+/// hexadecimal strings.
+mod bytes {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        bytes: &[u8],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let hex: String = bytes
+            .iter()
+            .flat_map(|byte| {
+                [
+                    HEX_DIGITS[(byte >> 4) as usize] as char,
+                    HEX_DIGITS[(byte & 0xf) as usize] as char,
+                ]
+            })
+            .collect();
+        serializer.serialize_str(&hex)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        if hex.len() % 2 != 0 {
+            return Err(serde::de::Error::custom("odd number of digits"));
+        }
+        hex.as_bytes()
+            .chunks(2)
+            .map(|pair| match (hex_value(pair[0]), hex_value(pair[1])) {
+                (Some(high), Some(low)) => Ok(high << 4 | low),
+                _ => Err(serde::de::Error::custom("invalid hexadecimal digit")),
+            })
+            .collect()
+    }
+}
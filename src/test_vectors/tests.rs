@@ -0,0 +1,91 @@
+use super::*;
+use ark_std::test_rng;
+
+#[test]
+fn test_generate_and_check() {
+    let mut rng = test_rng();
+    let vectors = TestVectors::generate(&mut rng, 3);
+    assert_eq!(vectors.vectors.len(), 3 * 7);
+    assert_eq!(vectors.check(), Ok(()));
+
+    let json = vectors.to_json();
+    let parsed = TestVectors::from_json(&json).unwrap();
+    assert_eq!(parsed, vectors);
+    assert_eq!(parsed.check(), Ok(()));
+}
+
+#[test]
+fn test_json_format() {
+    let g = EdwardsAffine::prime_subgroup_generator();
+    let mut vectors = TestVectors::new();
+    vectors
+        .vectors
+        .push(TestVector::scalar_mul(&g, &Fr::from(2u64)));
+    vectors
+        .vectors
+        .push(TestVector::derive_generator(&[0xab, 0x01], 7));
+    let json: serde_json::Value =
+        serde_json::from_str(&vectors.to_json()).unwrap();
+
+    assert_eq!(json["curve"], "bandersnatch");
+    assert_eq!(json["version"], 1);
+    let scalar_mul = &json["vectors"][0];
+    assert_eq!(scalar_mul["type"], "scalar_mul");
+    let mut scalar = String::from("02");
+    scalar.push_str(&"00".repeat(31));
+    assert_eq!(scalar_mul["scalar"], scalar.as_str());
+    let derive = &json["vectors"][1];
+    assert_eq!(derive["type"], "derive_generator");
+    assert_eq!(derive["domain"], "ab01");
+    assert_eq!(derive["index"], 7);
+}
+
+#[test]
+fn test_mismatch() {
+    let mut rng = test_rng();
+    let g = EdwardsAffine::prime_subgroup_generator();
+    let mut vectors = TestVectors::new();
+    vectors
+        .vectors
+        .push(TestVector::glv_decomposition(&Fr::rand(&mut rng)));
+    vectors.vectors.push(TestVector::ScalarMul {
+        point: g,
+        scalar: Fr::from(2u64),
+        result: g,
+    });
+    assert_eq!(
+        vectors.check(),
+        Err(TestVectorMismatch {
+            index: 1,
+            kind: "scalar_mul"
+        })
+    );
+
+    let key = SigningKey::generate(&mut rng);
+    let mut vector = TestVector::schnorr(&key, b"message");
+    assert!(vector.is_valid());
+    if let TestVector::Schnorr { message, .. } = &mut vector {
+        message.push(0);
+    }
+    assert!(!vector.is_valid());
+
+    let mut vector = TestVector::msm(&[g, g], &[Fr::from(1u64); 2]);
+    if let TestVector::Msm { scalars, .. } = &mut vector {
+        scalars.pop();
+    }
+    assert!(!vector.is_valid());
+}
+
+#[test]
+fn test_invalid_json() {
+    let vectors = TestVectors::generate(&mut test_rng(), 1);
+    let json = vectors.to_json();
+    assert!(TestVectors::from_json(&json.replace("\"msm\"", "\"m\"")).is_err());
+    assert!(TestVectors::from_json(&json[..json.len() - 1]).is_err());
+
+    let json = r#"{"curve": "bandersnatch", "version": 1, "vectors": [
+        {"type": "derive_generator", "domain": "abc", "index": 0,
+         "result": "0000000000000000000000000000000000000000000000000000000000000000"}
+    ]}"#;
+    assert!(TestVectors::from_json(json).is_err());
+}
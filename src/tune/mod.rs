@@ -0,0 +1,119 @@
+//! Machine-specific tuning of the MSM dispatcher; requires the `std`
+//! feature for timing.
+//!
+//! [`sweep_msm`] times the Strauss, interleaved wNAF and Pippenger
+//! algorithms on random inputs of the given sizes, and derives the
+//! crossover points of a [`TuningProfile`], which deployments can store
+//! and load instead of relying on the compiled-in defaults.
+
+use crate::{EdwardsAffine, Fr, MsmAlgorithm, TuningProfile};
+use ark_std::{rand::Rng, vec::Vec, UniformRand};
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests;
+
+/// The number of timed runs of each algorithm at each size; the fastest
+/// run is kept to filter out the noise.
+const RUNS: usize = 3;
+
+/// The timings of the MSM algorithms at one size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Measurement {
+    /// The number of terms.
+    pub size: usize,
+    /// The time taken by the Strauss method.
+    pub strauss: Duration,
+    /// The time taken by interleaved wNAFs.
+    pub wnaf: Duration,
+    /// The time taken by Pippenger's method.
+    pub pippenger: Duration,
+}
+
+impl Measurement {
+    /// The fastest algorithm at this size.
+    pub fn fastest(&self) -> MsmAlgorithm {
+        if self.strauss <= self.wnaf && self.strauss <= self.pippenger {
+            MsmAlgorithm::Strauss
+        } else if self.wnaf <= self.pippenger {
+            MsmAlgorithm::Wnaf
+        } else {
+            MsmAlgorithm::Pippenger
+        }
+    }
+}
+
+/// Time the MSM algorithms on random inputs of each of the `sizes`.
+pub fn measure_msm<R: Rng + ?Sized>(
+    sizes: &[usize],
+    rng: &mut R,
+) -> Vec<Measurement> {
+    sizes
+        .iter()
+        .map(|&size| {
+            let bases: Vec<EdwardsAffine> =
+                (0..size).map(|_| EdwardsAffine::rand(rng)).collect();
+            let scalars: Vec<Fr> = (0..size).map(|_| Fr::rand(rng)).collect();
+            let time = |algorithm: MsmAlgorithm| {
+                (0..RUNS)
+                    .map(|_| {
+                        let start = Instant::now();
+                        let _ = algorithm.msm(&bases, &scalars);
+                        start.elapsed()
+                    })
+                    .min()
+                    .unwrap()
+            };
+            Measurement {
+                size,
+                strauss: time(MsmAlgorithm::Strauss),
+                wnaf: time(MsmAlgorithm::Wnaf),
+                pippenger: time(MsmAlgorithm::Pippenger),
+            }
+        })
+        .collect()
+}
+
+/// Derive the crossover points from measurements: going through the sizes
+/// in increasing order, the Strauss method is used up to the last size of
+/// the initial run where it is the fastest, and interleaved wNAFs up to
+/// the last size of the following run where Pippenger's method is not the
+/// fastest.
+///
+/// Without measurements, the default profile is returned.
+pub fn profile_from_measurements(
+    measurements: &[Measurement],
+) -> TuningProfile {
+    if measurements.is_empty() {
+        return TuningProfile::default();
+    }
+    let mut sorted = measurements.to_vec();
+    sorted.sort_by_key(|m| m.size);
+
+    let mut profile = TuningProfile {
+        strauss_max: 0,
+        wnaf_max: 0,
+    };
+    let mut iter = sorted.iter().peekable();
+    while let Some(m) = iter.next_if(|m| m.fastest() == MsmAlgorithm::Strauss) {
+        profile.strauss_max = m.size;
+    }
+    profile.wnaf_max = profile.strauss_max;
+    while let Some(m) = iter.next_if(|m| m.fastest() != MsmAlgorithm::Pippenger)
+    {
+        profile.wnaf_max = m.size;
+    }
+    profile
+}
+
+/// Measure the MSM algorithms at each of the `sizes` and derive a profile
+/// for the current machine, see [`profile_from_measurements`].
+///
+/// The sizes should cover the range of the expected crossover points, e.g.,
+/// `[1, 2, 4, 8, 16, 32, 64, 128]`.
+pub fn sweep_msm<R: Rng + ?Sized>(
+    sizes: &[usize],
+    rng: &mut R,
+) -> TuningProfile {
+    profile_from_measurements(&measure_msm(sizes, rng))
+}
@@ -0,0 +1,54 @@
+use super::*;
+use ark_std::test_rng;
+
+fn measurement(size: usize, timings: [u64; 3]) -> Measurement {
+    Measurement {
+        size,
+        strauss: Duration::from_micros(timings[0]),
+        wnaf: Duration::from_micros(timings[1]),
+        pippenger: Duration::from_micros(timings[2]),
+    }
+}
+
+#[test]
+fn test_profile_from_measurements() {
+    assert_eq!(profile_from_measurements(&[]), TuningProfile::default());
+
+    let measurements = [
+        measurement(8, [30, 20, 40]),
+        measurement(1, [1, 2, 3]),
+        measurement(2, [2, 3, 4]),
+        measurement(4, [6, 5, 9]),
+        measurement(16, [70, 50, 45]),
+        // noise after the crossover is ignored
+        measurement(32, [200, 80, 90]),
+    ];
+    let profile = profile_from_measurements(&measurements);
+    assert_eq!(
+        profile,
+        TuningProfile {
+            strauss_max: 2,
+            wnaf_max: 8,
+        }
+    );
+    assert_eq!(profile.algorithm(1), MsmAlgorithm::Strauss);
+    assert_eq!(profile.algorithm(3), MsmAlgorithm::Wnaf);
+    assert_eq!(profile.algorithm(9), MsmAlgorithm::Pippenger);
+
+    // Pippenger is the fastest everywhere
+    let profile = profile_from_measurements(&[measurement(1, [3, 2, 1])]);
+    assert_eq!(profile.algorithm(1), MsmAlgorithm::Pippenger);
+}
+
+#[test]
+fn test_sweep_msm() {
+    let sizes = [1, 2, 4];
+    let measurements = measure_msm(&sizes, &mut test_rng());
+    assert_eq!(
+        measurements.iter().map(|m| m.size).collect::<Vec<_>>(),
+        sizes
+    );
+    let profile = sweep_msm(&sizes, &mut test_rng());
+    assert!(profile.strauss_max <= profile.wnaf_max);
+    assert!(profile.wnaf_max <= 4);
+}
@@ -0,0 +1,186 @@
+//! JavaScript bindings with `wasm-bindgen`, behind the `wasm` feature, so
+//! that browser light clients can verify bandersnatch-based proofs with
+//! this implementation rather than a separate port.
+//!
+//! The functions take and return byte arrays (`Uint8Array` in
+//! JavaScript): points of bandersnatch as their 32-byte compressed
+//! encodings, scalars as their canonical 32-byte little-endian integers,
+//! and Banderwagon elements as their 32-byte encoding; lists are
+//! concatenations of such arrays. Invalid inputs throw an error with a
+//! message; points used in arithmetic must be in the prime order subgroup.
+//!
+//! Schnorr signatures are 64 bytes: the compressed commitment `R` followed
+//! by the little-endian response `s`, see [`signature_to_bytes`].
+
+use crate::{
+    banderwagon::Element,
+    from_compressed_bytes, msm,
+    schnorr::{Signature, VerifyingKey},
+    to_bytes_uncompressed, to_compressed_bytes, EdwardsAffine, Endianness,
+    FieldBytes, Fr, Validate, COMPRESSED_SIZE,
+};
+use ark_ec::ProjectiveCurve;
+use ark_std::{convert::TryInto, vec::Vec};
+use wasm_bindgen::prelude::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The size of an encoded Schnorr signature, in bytes.
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// Encode a Schnorr signature as expected by [`schnorr_verify`].
+pub fn signature_to_bytes(sig: &Signature) -> [u8; SIGNATURE_SIZE] {
+    let mut bytes = [0u8; SIGNATURE_SIZE];
+    bytes[..32].copy_from_slice(&to_compressed_bytes(&sig.r));
+    bytes[32..].copy_from_slice(&sig.s.to_bytes_le());
+    bytes
+}
+
+/// Decompress a point into its little-endian affine coordinates `x || y`.
+#[wasm_bindgen(js_name = bandersnatchDecompress)]
+pub fn bandersnatch_decompress(compressed: &[u8]) -> Result<Vec<u8>, JsValue> {
+    let p = point(compressed).map_err(js_error)?;
+    Ok(to_bytes_uncompressed(&p, Endianness::Little).to_vec())
+}
+
+/// Whether bytes are the compressed encoding of a point of the prime
+/// order subgroup.
+#[wasm_bindgen(js_name = bandersnatchIsInPrimeSubgroup)]
+pub fn bandersnatch_is_in_prime_subgroup(compressed: &[u8]) -> bool {
+    subgroup_point(compressed).is_ok()
+}
+
+/// `sum_i scalars[i] * points[i]`, compressed.
+#[wasm_bindgen(js_name = bandersnatchMsm)]
+pub fn bandersnatch_msm(
+    points: &[u8],
+    scalars: &[u8],
+) -> Result<Vec<u8>, JsValue> {
+    msm_bytes(points, scalars).map_err(js_error)
+}
+
+/// Whether bytes are the encoding of a Banderwagon element.
+#[wasm_bindgen(js_name = banderwagonIsValid)]
+pub fn banderwagon_is_valid(element: &[u8]) -> bool {
+    self::element(element).is_ok()
+}
+
+/// `sum_i scalars[i] * elements[i]` in Banderwagon.
+#[wasm_bindgen(js_name = banderwagonMsm)]
+pub fn banderwagon_msm(
+    elements: &[u8],
+    scalars: &[u8],
+) -> Result<Vec<u8>, JsValue> {
+    banderwagon_msm_bytes(elements, scalars).map_err(js_error)
+}
+
+/// Map each element to its little-endian scalar, see
+/// [`Element::map_to_scalar_field`], sharing a single inversion.
+#[wasm_bindgen(js_name = banderwagonMapToScalarField)]
+pub fn banderwagon_map_to_scalar_field(
+    elements: &[u8],
+) -> Result<Vec<u8>, JsValue> {
+    map_to_scalar_field_bytes(elements).map_err(js_error)
+}
+
+/// Whether `signature` is a valid Schnorr signature on `msg` under the
+/// compressed public key.
+#[wasm_bindgen(js_name = schnorrVerify)]
+pub fn schnorr_verify(public_key: &[u8], msg: &[u8], signature: &[u8]) -> bool {
+    verify_bytes(public_key, msg, signature).is_ok()
+}
+
+fn js_error(msg: &str) -> JsValue {
+    JsValue::from_str(msg)
+}
+
+fn array(bytes: &[u8]) -> Result<&[u8; COMPRESSED_SIZE], &'static str> {
+    bytes.try_into().map_err(|_| "expected 32 bytes")
+}
+
+fn arrays(bytes: &[u8]) -> Result<Vec<&[u8; COMPRESSED_SIZE]>, &'static str> {
+    if !bytes.len().is_multiple_of(COMPRESSED_SIZE) {
+        return Err("expected a multiple of 32 bytes");
+    }
+    bytes.chunks(COMPRESSED_SIZE).map(array).collect()
+}
+
+fn point(bytes: &[u8]) -> Result<EdwardsAffine, &'static str> {
+    from_compressed_bytes(array(bytes)?).ok_or("invalid point encoding")
+}
+
+fn subgroup_point(bytes: &[u8]) -> Result<EdwardsAffine, &'static str> {
+    let p = point(bytes)?;
+    p.validate()
+        .map_err(|_| "point not in the prime order subgroup")?;
+    Ok(p)
+}
+
+fn scalar(bytes: &[u8]) -> Result<Fr, &'static str> {
+    Fr::from_bytes_le(array(bytes)?).ok_or("non-canonical scalar")
+}
+
+fn element(bytes: &[u8]) -> Result<Element, &'static str> {
+    Element::from_bytes(array(bytes)?).map_err(|_| "invalid element encoding")
+}
+
+fn scalars(bytes: &[u8], count: usize) -> Result<Vec<Fr>, &'static str> {
+    let scalars = arrays(bytes)?;
+    if scalars.len() != count {
+        return Err("mismatched numbers of points and scalars");
+    }
+    scalars.into_iter().map(|s| scalar(s)).collect()
+}
+
+fn msm_bytes(points: &[u8], scalars: &[u8]) -> Result<Vec<u8>, &'static str> {
+    let bases = arrays(points)?
+        .into_iter()
+        .map(|p| subgroup_point(p))
+        .collect::<Result<Vec<_>, _>>()?;
+    let scalars = self::scalars(scalars, bases.len())?;
+    let sum = msm(&bases, &scalars).into_affine();
+    Ok(to_compressed_bytes(&sum).to_vec())
+}
+
+fn banderwagon_msm_bytes(
+    elements: &[u8],
+    scalars: &[u8],
+) -> Result<Vec<u8>, &'static str> {
+    let elements = decode_elements(elements)?;
+    let scalars = self::scalars(scalars, elements.len())?;
+    Ok(Element::msm(&elements, &scalars).to_bytes().to_vec())
+}
+
+fn map_to_scalar_field_bytes(elements: &[u8]) -> Result<Vec<u8>, &'static str> {
+    let elements = decode_elements(elements)?;
+    Ok(Element::batch_map_to_scalar_field(&elements)
+        .iter()
+        .flat_map(|s| s.to_bytes_le())
+        .collect())
+}
+
+fn decode_elements(bytes: &[u8]) -> Result<Vec<Element>, &'static str> {
+    let encodings: Vec<[u8; COMPRESSED_SIZE]> =
+        arrays(bytes)?.into_iter().copied().collect();
+    Element::batch_from_bytes(&encodings)
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| "invalid element encoding")
+}
+
+fn verify_bytes(
+    public_key: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), &'static str> {
+    let key = VerifyingKey::from_affine(point(public_key)?)
+        .map_err(|_| "invalid public key")?;
+    if signature.len() != SIGNATURE_SIZE {
+        return Err("expected 64 bytes");
+    }
+    let r = subgroup_point(&signature[..32])?;
+    let s = scalar(&signature[32..])?;
+    key.verify(msg, &Signature { r, s })
+        .map_err(|_| "invalid signature")
+}
@@ -0,0 +1,91 @@
+use super::*;
+use crate::{banderwagon::Element, schnorr::SigningKey};
+use ark_ec::AffineCurve;
+use ark_std::{rand::Rng, test_rng, UniformRand, Zero};
+
+fn encode_points(points: &[EdwardsAffine]) -> Vec<u8> {
+    points.iter().flat_map(to_compressed_bytes).collect()
+}
+
+fn encode_scalars(scalars: &[Fr]) -> Vec<u8> {
+    scalars.iter().flat_map(|s| s.to_bytes_le()).collect()
+}
+
+#[test]
+fn test_msm_bytes() {
+    let mut rng = test_rng();
+    let points: Vec<EdwardsAffine> = (0..5)
+        .map(|_| crate::EdwardsProjective::rand(&mut rng).into_affine())
+        .collect();
+    let scalars: Vec<Fr> = (0..5).map(|_| Fr::rand(&mut rng)).collect();
+
+    let res =
+        msm_bytes(&encode_points(&points), &encode_scalars(&scalars)).unwrap();
+    let expected = msm(&points, &scalars).into_affine();
+    assert_eq!(res, to_compressed_bytes(&expected).to_vec());
+
+    assert!(
+        msm_bytes(&encode_points(&points), &encode_scalars(&scalars[1..]))
+            .is_err()
+    );
+    assert!(msm_bytes(&encode_points(&points)[1..], &[]).is_err());
+    assert_eq!(
+        msm_bytes(&[], &[]).unwrap(),
+        to_compressed_bytes(&EdwardsAffine::zero()).to_vec()
+    );
+}
+
+#[test]
+fn test_msm_bytes_rejects_small_order_points() {
+    let mut rng = test_rng();
+    let mut points =
+        encode_points(&[EdwardsAffine::prime_subgroup_generator()]);
+    // (0, -1) is of order 2.
+    let mut low_order = [0u8; COMPRESSED_SIZE];
+    low_order.copy_from_slice(&to_compressed_bytes(&EdwardsAffine::new(
+        crate::Fq::from(0u64),
+        -crate::Fq::from(1u64),
+    )));
+    points.extend_from_slice(&low_order);
+    let scalars = encode_scalars(&[Fr::rand(&mut rng), Fr::rand(&mut rng)]);
+
+    assert!(msm_bytes(&points, &scalars).is_err());
+    assert!(!bandersnatch_is_in_prime_subgroup(&low_order));
+    assert!(bandersnatch_is_in_prime_subgroup(&points[..32]));
+}
+
+#[test]
+fn test_banderwagon_bytes() {
+    let mut rng = test_rng();
+    let elements: Vec<Element> = (0..4).map(|_| rng.gen()).collect();
+    let scalars: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+    let encoded: Vec<u8> = elements.iter().flat_map(|e| e.to_bytes()).collect();
+
+    assert!(banderwagon_is_valid(&encoded[..32]));
+    assert!(!banderwagon_is_valid(&encoded[..31]));
+
+    let res =
+        banderwagon_msm_bytes(&encoded, &encode_scalars(&scalars)).unwrap();
+    assert_eq!(res, Element::msm(&elements, &scalars).to_bytes().to_vec());
+
+    let mapped = map_to_scalar_field_bytes(&encoded).unwrap();
+    let expected: Vec<Fr> =
+        elements.iter().map(|e| e.map_to_scalar_field()).collect();
+    assert_eq!(mapped, encode_scalars(&expected));
+}
+
+#[test]
+fn test_schnorr_verify() {
+    let mut rng = test_rng();
+    let key = SigningKey::generate(&mut rng);
+    let public_key = to_compressed_bytes(key.verifying_key().as_affine());
+    let sig = signature_to_bytes(&key.sign(b"message"));
+
+    assert!(schnorr_verify(&public_key, b"message", &sig));
+    assert!(!schnorr_verify(&public_key, b"other message", &sig));
+    assert!(!schnorr_verify(&public_key, b"message", &sig[..63]));
+
+    let mut tampered = sig;
+    tampered[40] ^= 1;
+    assert!(!schnorr_verify(&public_key, b"message", &tampered));
+}